@@ -0,0 +1,57 @@
+#![feature(test)]
+extern crate test;
+
+use pkhexcore::pkm::util::pokecrypto::{
+    decrypt_array8, decrypt_box8, encrypt_array8, encrypt_box8, SIZE_8STORED
+};
+use test::Bencher;
+
+const BOX_SLOTS: usize = 30;
+
+/// A box of encrypted stored Orbeetles.
+fn encrypted_box() -> Vec<u8> {
+    include_bytes!("../src/pkm/util/tests/data/Orbeetle.ek8")[..SIZE_8STORED].repeat(BOX_SLOTS)
+}
+
+// The crypt routines do the same amount of work whatever the input, so each
+// iteration keeps working on the output of the previous one.
+
+#[bench]
+fn decrypt_box8_per_entity(b: &mut Bencher) {
+    let mut data = encrypted_box();
+    b.bytes = data.len() as u64;
+    b.iter(|| {
+        for slot in data.chunks_exact_mut(SIZE_8STORED) {
+            let mut ekm = [0u8; SIZE_8STORED];
+            ekm.copy_from_slice(slot);
+            slot.copy_from_slice(&decrypt_array8(&mut ekm));
+        }
+    });
+}
+
+#[bench]
+fn decrypt_box8_in_place(b: &mut Bencher) {
+    let mut data = encrypted_box();
+    b.bytes = data.len() as u64;
+    b.iter(|| decrypt_box8(&mut data, SIZE_8STORED).unwrap());
+}
+
+#[bench]
+fn encrypt_box8_per_entity(b: &mut Bencher) {
+    let mut data = encrypted_box();
+    b.bytes = data.len() as u64;
+    b.iter(|| {
+        for slot in data.chunks_exact_mut(SIZE_8STORED) {
+            let mut pkm = [0u8; SIZE_8STORED];
+            pkm.copy_from_slice(slot);
+            slot.copy_from_slice(&encrypt_array8(&mut pkm));
+        }
+    });
+}
+
+#[bench]
+fn encrypt_box8_in_place(b: &mut Bencher) {
+    let mut data = encrypted_box();
+    b.bytes = data.len() as u64;
+    b.iter(|| encrypt_box8(&mut data, SIZE_8STORED).unwrap());
+}
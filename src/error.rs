@@ -0,0 +1,76 @@
+use alloc::{format, vec, vec::Vec};
+use core::{convert::TryFrom, fmt};
+use deku::DekuError;
+
+/// Errors that can occur while parsing or building Pokémon entity data.
+#[derive(Debug, PartialEq)]
+pub enum PkmError {
+    /// The data is not any of the accepted sizes, or ends before a value
+    /// that has to be read from it.
+    InvalidLength { expected: Vec<usize>, actual: usize },
+    /// The data is still not valid after decrypting it.
+    DecryptionFailed,
+    /// The stored checksum does not match the one calculated from the data.
+    ChecksumMismatch { stored: u16, calculated: u16 },
+    /// A field holds a value that cannot be represented by its type.
+    InvalidField { field: &'static str, offset: usize, value: u32 },
+    /// An index is past the end of the fixed size list it points into.
+    InvalidIndex { index: usize, count: usize },
+    /// Error reported by deku while reading or writing the data.
+    Deku(DekuError),
+}
+
+impl fmt::Display for PkmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PkmError::InvalidLength { expected, actual } => {
+                write!(f, "invalid data length {}, expected one of {:?}", actual, expected)
+            }
+            PkmError::DecryptionFailed => write!(f, "data could not be decrypted"),
+            PkmError::ChecksumMismatch { stored, calculated } => write!(
+                f,
+                "checksum mismatch: stored {:#06X}, calculated {:#06X}",
+                stored, calculated
+            ),
+            PkmError::InvalidField { field, offset, value } => {
+                write!(f, "invalid {} value {} at offset {:#X}", field, value, offset)
+            }
+            PkmError::InvalidIndex { index, count } => {
+                write!(f, "index {} out of range for {} entries", index, count)
+            }
+            PkmError::Deku(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl From<DekuError> for PkmError {
+    fn from(err: DekuError) -> Self { PkmError::Deku(err) }
+}
+
+/// Lets errors raised while building data surface from deku `update`
+/// expressions.
+impl From<PkmError> for DekuError {
+    fn from(err: PkmError) -> Self {
+        match err {
+            PkmError::Deku(err) => err,
+            err => DekuError::Unexpected(format!("{}", err)),
+        }
+    }
+}
+
+/// Converts built data into a fixed size array, reporting the length on
+/// mismatch.
+pub(crate) fn to_array<const N: usize>(data: Vec<u8>) -> Result<[u8; N], PkmError> {
+    let actual = data.len();
+    <[u8; N]>::try_from(data).map_err(|_| PkmError::InvalidLength { expected: vec![N], actual })
+}
+
+/// Checks that data is made of whole `entry_size` byte entries, reporting the
+/// closest whole sizes on mismatch.
+pub(crate) fn check_entries(actual: usize, entry_size: usize) -> Result<(), PkmError> {
+    let whole = actual - actual % entry_size;
+    if whole != actual {
+        return Err(PkmError::InvalidLength { expected: vec![whole, whole + entry_size], actual });
+    }
+    Ok(())
+}
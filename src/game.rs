@@ -1 +1,13 @@
 pub mod enums;
+
+/// Base species data such as stats, types and abilities.
+pub mod personal_info;
+
+/// Level up moves of a species.
+pub mod learnset;
+
+/// Ways a species can evolve.
+pub mod evolution;
+
+/// Game data tables, along with loaders for the files they come from.
+pub mod game_data;
@@ -43,3 +43,9 @@ pub mod nature;
 
 /// 3DS Console Region Identifiers.
 pub mod region_id;
+
+/// Ribbons and marks, in the order their flags are stored in.
+pub mod ribbon_index;
+
+/// Stats a PKM has, in the order they are stored in.
+pub mod stat;
@@ -1,12 +1,13 @@
 #![allow(non_snake_case)]
 use alloc::format;
 use deku::prelude::*;
+use enumn::N;
 
 #[allow(non_camel_case_types)]
 
 /// Ability IDs for the corresponding English ability name.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite)]
-#[deku(type = "u16", ctx = "_endian: deku::ctx::Endian")]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite, N)]
+#[deku(type = "u16", endian = "endian", ctx = "endian: deku::ctx::Endian")]
 #[non_exhaustive]
 #[repr(u16)]
 pub enum Ability {
@@ -278,6 +279,49 @@ pub enum Ability {
     GrimNeigh,
     AsOneI,
     AsOneG,
+    LingeringAroma,
+    SeedSower,
+    ThermalExchange,
+    AngerShell,
+    PurifyingSalt,
+    WellBakedBody,
+    WindRider,
+    GuardDog,
+    RockyPayload,
+    WindPower,
+    ZeroToHero,
+    Commander,
+    Electromorphosis,
+    Protosynthesis,
+    QuarkDrive,
+    GoodAsGold,
+    VesselOfRuin,
+    SwordOfRuin,
+    TabletsOfRuin,
+    BeadsOfRuin,
+    OrichalcumPulse,
+    HadronEngine,
+    Opportunist,
+    CudChew,
+    Sharpness,
+    SupremeOverlord,
+    Costar,
+    ToxicDebris,
+    ArmorTail,
+    EarthEater,
+    MyceliumMight,
+    MindsEye,
+    SupersweetSyrup,
+    Hospitality,
+    ToxicChain,
+    EmbodyAspect0,
+    EmbodyAspect1,
+    EmbodyAspect2,
+    EmbodyAspect3,
+    TeraShift,
+    TeraShell,
+    TeraformZero,
+    PoisonPuppeteer,
     MAX_COUNT,
 }
 
@@ -1,7 +1,8 @@
 use alloc::format;
 use deku::prelude::*;
+use enumn::N;
 
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite, N)]
 #[deku(type = "u8", ctx = "_endian: deku::ctx::Endian")]
 #[non_exhaustive]
 #[repr(u8)]
@@ -40,6 +41,22 @@ pub enum Ball {
     Sport = 24,
     Dream = 25,
     Beast = 26,
+
+    Strange = 27,
+
+    LAPoke = 28,
+    LAGreat = 29,
+    LAUltra = 30,
+
+    LAFeather = 31,
+    LAWing = 32,
+    LAJet = 33,
+
+    LAHeavy = 34,
+    LALeaden = 35,
+    LAGigaton = 36,
+
+    LAOrigin = 37,
 }
 
 impl Default for Ball {
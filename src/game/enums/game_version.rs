@@ -137,6 +137,21 @@ pub enum GameVersion {
 
     /// Pokémon Shield (NX)
     SH = 45,
+
+    /// Pokémon Legends: Arceus (NX)
+    PLA = 47,
+
+    /// Pokémon Brilliant Diamond (NX)
+    BD = 48,
+
+    /// Pokémon Shining Pearl (NX)
+    SP = 49,
+
+    /// Pokémon Scarlet (NX)
+    SL = 50,
+
+    /// Pokémon Violet (NX)
+    VL = 51,
     // End Nintendo Switch
 
     // The following values are not actually stored values in pkm data,
@@ -222,6 +237,14 @@ pub enum GameVersion {
     // Used to lump data from the associated games as data assets are shared.
     SWSH,
 
+    /// Pokémon Brilliant Diamond & Shining Pearl
+    // Used to lump data from the associated games as data assets are shared.
+    BDSP,
+
+    /// Pokémon Scarlet & Violet
+    // Used to lump data from the associated games as data assets are shared.
+    SLVL,
+
     /// Generation 1 Games
     Gen1,
 
@@ -246,6 +269,9 @@ pub enum GameVersion {
     /// Generation 8 Games
     Gen8,
 
+    /// Generation 9 Games
+    Gen9,
+
     /// Generation 1/2 Game Boy Cartridge Era Only
     // Any special encounters (event data) can only be
     // allowed if the savedata originated from that era.
@@ -275,13 +301,13 @@ impl Default for GameVersion {
 use GameVersion::*;
 
 /// Most recent game ID utilized by official games.
-pub const HIGHEST_GAME_ID: GameVersion = SH;
+pub const HIGHEST_GAME_ID: GameVersion = VL;
 
 /// List of possible [`GameVersion`] values a [`PKM`](crate::pkm::PKM) can have.
 // Ordered roughly by most recent games first.
-pub static GAME_VERSIONS: [GameVersion; 35] = [
-    SH, SW, GE, GP, C, SV, GD, YW, BU, GN, RD, GO, UM, US, MN, SN, OR, AS, Y, X, B2, W2, B, W, CXD,
-    PT, P, D, SS, HG, LG, FR, E, R, S,
+pub static GAME_VERSIONS: [GameVersion; 40] = [
+    VL, SL, SP, BD, PLA, SH, SW, GE, GP, C, SV, GD, YW, BU, GN, RD, GO, UM, US, MN, SN, OR, AS, Y,
+    X, B2, W2, B, W, CXD, PT, P, D, SS, HG, LG, FR, E, R, S,
 ];
 
 /// Indicates if the [`GameVersion`] value is a value used by the games or is an
@@ -320,6 +346,10 @@ pub fn met_location_version_group(game_version: GameVersion) -> GameVersion {
         GP | GE => GG,
         // Gen8
         SW | SH => SWSH,
+        PLA => PLA,
+        BD | SP => BDSP,
+        // Gen9
+        SL | VL => SLVL,
         _ => Invalid,
     }
 }
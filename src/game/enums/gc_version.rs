@@ -1,8 +1,10 @@
 use crate::game::enums::game_version::GameVersion;
+use enumn::N;
 
 /// GameVersion analogues used by Colosseum/XD instead of the main-series
 /// values.
 #[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, N)]
 #[repr(u8)]
 pub enum GCVersion {
     None = 0,
@@ -33,7 +35,7 @@ pub fn get_cxd_version_id(gba_version: GameVersion) -> GCVersion {
 /// Translates a main-series GCVersion to the corresponding GameVersion value.
 /// # Arguments
 ///
-/// * `gc_version` - Version ID while present in the GameCube gamesc
+/// * `gc_version` - Version ID while present in the GameCube games
 pub fn get_g3_version_id(gc_version: GCVersion) -> GameVersion {
     match gc_version {
         GCVersion::S => GameVersion::S,
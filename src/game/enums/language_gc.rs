@@ -1,4 +1,8 @@
+use crate::game::enums::language_id::LanguageID;
+use enumn::N;
+
 #[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone, PartialEq, N)]
 #[repr(u8)]
 /// Game Language IDs
 pub enum LanguageGC {
@@ -29,3 +33,56 @@ pub enum LanguageGC {
     // Was reserved for Korean in Gen3 but never utilized.
     UNUSED_6 = 7,
 }
+
+/// Translates a Colosseum/XD LanguageGC to the corresponding main-series
+/// LanguageID value.
+/// # Arguments
+///
+/// * `value` - Language ID while present in the GameCube games
+pub fn get_main_lang_id(value: LanguageGC) -> LanguageID {
+    match value {
+        LanguageGC::Hacked => LanguageID::Hacked,
+        LanguageGC::Japanese => LanguageID::Japanese,
+        LanguageGC::English => LanguageID::English,
+        LanguageGC::German => LanguageID::German,
+        LanguageGC::French => LanguageID::French,
+        LanguageGC::Italian => LanguageID::Italian,
+        LanguageGC::Spanish => LanguageID::Spanish,
+        LanguageGC::UNUSED_6 => LanguageID::Unused6,
+    }
+}
+
+/// Translates a main-series LanguageID to the corresponding Colosseum/XD
+/// LanguageGC value.
+/// # Arguments
+///
+/// * `value` - Language ID while present in the main-series games
+pub fn get_gc_lang_id(value: LanguageID) -> LanguageGC {
+    match value {
+        LanguageID::Japanese => LanguageGC::Japanese,
+        LanguageID::English => LanguageGC::English,
+        LanguageID::German => LanguageGC::German,
+        LanguageID::French => LanguageGC::French,
+        LanguageID::Italian => LanguageGC::Italian,
+        LanguageID::Spanish => LanguageGC::Spanish,
+        LanguageID::Unused6 => LanguageGC::UNUSED_6,
+        _ => LanguageGC::Hacked,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn language_gc_test() {
+        assert_eq!(LanguageID::German, get_main_lang_id(LanguageGC::German));
+        assert_eq!(LanguageID::Spanish, get_main_lang_id(LanguageGC::Spanish));
+        assert_eq!(LanguageGC::French, get_gc_lang_id(LanguageID::French));
+        assert_eq!(LanguageGC::Hacked, get_gc_lang_id(LanguageID::Korean));
+        for value in 0..=7 {
+            let language = LanguageGC::n(value).unwrap();
+            assert_eq!(language, get_gc_lang_id(get_main_lang_id(language)));
+        }
+    }
+}
@@ -28,6 +28,12 @@ pub enum MoveType {
     Dragon,
     Dark,
     Fairy,
+    /// Terastallization type exclusive to Terapagos.
+    Stellar = 99,
+}
+
+impl Default for MoveType {
+    fn default() -> Self { MoveType::Normal }
 }
 
 impl_from! (MoveType for i8);
@@ -40,6 +46,17 @@ pub fn get_move_type_generation(move_type: MoveType, generation: i32) -> MoveTyp
     }
 }
 
+/// Gets the type stored as a Generation 1 or 2 type byte, which leaves gaps
+/// for unused types.
+pub fn get_move_type_g12(value: u8) -> Option<MoveType> {
+    match value {
+        0..=5 => MoveType::n(value as i8),
+        7..=9 => MoveType::n(value as i8 - 1),
+        0x14..=0x1B => MoveType::n(value as i8 - 0x14 + MoveType::Fire as i8),
+        _ => None,
+    }
+}
+
 fn get_move_type_from_g12(mut move_type: MoveType) -> MoveType {
     if move_type <= MoveType::Rock {
         return move_type;
@@ -1,11 +1,12 @@
 #![allow(non_snake_case)]
 use alloc::format;
 use deku::prelude::*;
+use enumn::N;
 
 #[allow(non_camel_case_types)]
 /// Elemental type a move has; additionally, types a PKM can have.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite)]
-#[deku(type = "u16", ctx = "_endian: deku::ctx::Endian")]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite, N)]
+#[deku(type = "u16", endian = "endian", ctx = "endian: deku::ctx::Endian")]
 #[non_exhaustive]
 #[repr(u16)]
 pub enum Move {
@@ -836,6 +837,99 @@ pub enum Move {
     GlacialLance,
     AstralBarrage,
     EerieSpell,
+    DireClaw,
+    PsyshieldBash,
+    PowerShift,
+    StoneAxe,
+    SpringtideStorm,
+    MysticalPower,
+    RagingFury,
+    WaveCrash,
+    Chloroblast,
+    MountainGale,
+    VictoryDance,
+    HeadlongRush,
+    BarbBarrage,
+    EsperWing,
+    BitterMalice,
+    Shelter,
+    TripleArrows,
+    InfernalParade,
+    CeaselessEdge,
+    BleakwindStorm,
+    WildboltStorm,
+    SandsearStorm,
+    LunarBlessing,
+    TakeHeart,
+    TeraBlast,
+    SilkTrap,
+    AxeKick,
+    LastRespects,
+    LuminaCrash,
+    OrderUp,
+    JetPunch,
+    SpicyExtract,
+    SpinOut,
+    PopulationBomb,
+    IceSpinner,
+    GlaiveRush,
+    RevivalBlessing,
+    SaltCure,
+    TripleDive,
+    MortalSpin,
+    Doodle,
+    FilletAway,
+    KowtowCleave,
+    FlowerTrick,
+    TorchSong,
+    AquaStep,
+    RagingBull,
+    MakeItRain,
+    Psyblade,
+    HydroSteam,
+    Ruination,
+    CollisionCourse,
+    ElectroDrift,
+    ShedTail,
+    ChillyReception,
+    TidyUp,
+    Snowscape,
+    Pounce,
+    Trailblaze,
+    ChillingWater,
+    HyperDrill,
+    TwinBeam,
+    RageFist,
+    ArmorCannon,
+    BitterBlade,
+    DoubleShock,
+    GigatonHammer,
+    Comeuppance,
+    AquaCutter,
+    BlazingTorque,
+    WickedTorque,
+    NoxiousTorque,
+    CombatTorque,
+    MagicalTorque,
+    BloodMoon,
+    MatchaGotcha,
+    SyrupBomb,
+    IvyCudgel,
+    ElectroShot,
+    TeraStarstorm,
+    FickleBeam,
+    BurningBulwark,
+    Thunderclap,
+    MightyCleave,
+    TachyonCutter,
+    HardPress,
+    DragonCheer,
+    AlluringVoice,
+    TemperFlare,
+    SupercellSlam,
+    PsychicNoise,
+    UpperHand,
+    MalignantChain,
     MAX_COUNT,
 }
 
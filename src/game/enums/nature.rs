@@ -1,9 +1,11 @@
 #![allow(non_snake_case)]
+use super::stat::Stat;
 use alloc::format;
 use deku::prelude::*;
+use enumn::N;
 
 /// Nature ID values for the corresponding English nature name.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite)]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite, N)]
 #[deku(type = "u8", ctx = "_endian: deku::ctx::Endian")]
 #[repr(u8)]
 pub enum Nature {
@@ -41,6 +43,69 @@ impl Default for Nature {
 
 impl_from! (Nature for u8, i32);
 
+/// Berry and Poffin flavors, liked or disliked depending on nature.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Flavor {
+    Spicy,
+    Sour,
+    Sweet,
+    Dry,
+    Bitter,
+}
+
+/// Stats natures affect, in the order natures are grouped by.
+const NATURE_STATS: [Stat; 5] = [Stat::Atk, Stat::Def, Stat::Spe, Stat::SpA, Stat::SpD];
+
+/// Flavors matching each of the [`NATURE_STATS`].
+const FLAVORS: [Flavor; 5] =
+    [Flavor::Spicy, Flavor::Sour, Flavor::Sweet, Flavor::Dry, Flavor::Bitter];
+
+impl Nature {
+    /// Gets the nature increasing `up` and decreasing `down`. Passing the same
+    /// stat twice gives the neutral nature of that stat.
+    pub fn from_stats(up: Stat, down: Stat) -> Option<Nature> {
+        let index = |stat| NATURE_STATS.iter().position(|&s| s == stat);
+        Nature::n((index(up)? * 5 + index(down)?) as u8)
+    }
+
+    /// Whether the nature leaves every stat unchanged.
+    pub fn is_neutral(self) -> bool { self as u8 / 5 == self as u8 % 5 }
+
+    /// Gets the stat increased by the nature, if any.
+    pub fn increased_stat(self) -> Option<Stat> {
+        if self.is_neutral() || self == Nature::Random {
+            return None;
+        }
+        Some(NATURE_STATS[self as usize / 5])
+    }
+
+    /// Gets the stat decreased by the nature, if any.
+    pub fn decreased_stat(self) -> Option<Stat> {
+        if self.is_neutral() || self == Nature::Random {
+            return None;
+        }
+        Some(NATURE_STATS[self as usize % 5])
+    }
+
+    /// Gets the amplification the nature applies to a stat: `1` if
+    /// increased, `-1` if decreased, `0` otherwise.
+    pub fn stat_amp(self, stat: Stat) -> i8 {
+        match Some(stat) {
+            s if s == self.increased_stat() => 1,
+            s if s == self.decreased_stat() => -1,
+            _ => 0,
+        }
+    }
+
+    /// Gets the flavor liked by the nature, if any.
+    pub fn liked_flavor(self) -> Option<Flavor> { self.increased_stat().map(Self::flavor) }
+
+    /// Gets the flavor disliked by the nature, if any.
+    pub fn disliked_flavor(self) -> Option<Flavor> { self.decreased_stat().map(Self::flavor) }
+
+    fn flavor(stat: Stat) -> Flavor { FLAVORS[stat as usize - 1] }
+}
+
 #[allow(unused_macros)]
 macro_rules! nature {
     ($nature:tt) => {
@@ -56,4 +121,50 @@ mod test {
     fn nature_macro_test() {
         assert_eq!(Nature::Jolly, nature!(Jolly));
     }
+
+    #[test]
+    fn nature_stats_test() {
+        assert_eq!(Some(Stat::Spe), Nature::Jolly.increased_stat());
+        assert_eq!(Some(Stat::SpA), Nature::Jolly.decreased_stat());
+        assert_eq!(Some(Stat::SpD), Nature::Calm.increased_stat());
+        assert_eq!(Some(Stat::Atk), Nature::Calm.decreased_stat());
+        assert_eq!(1, Nature::Modest.stat_amp(Stat::SpA));
+        assert_eq!(-1, Nature::Modest.stat_amp(Stat::Atk));
+        assert_eq!(0, Nature::Modest.stat_amp(Stat::HP));
+
+        for nature in
+            [Nature::Hardy, Nature::Docile, Nature::Serious, Nature::Bashful, Nature::Quirky]
+        {
+            assert!(nature.is_neutral());
+            assert_eq!(None, nature.increased_stat());
+            assert_eq!(None, nature.decreased_stat());
+            assert_eq!(None, nature.liked_flavor());
+        }
+        assert!(!Nature::Random.is_neutral());
+        assert_eq!(None, Nature::Random.increased_stat());
+        assert_eq!(0, Nature::Random.stat_amp(Stat::Atk));
+    }
+
+    #[test]
+    fn nature_from_stats_test() {
+        assert_eq!(Some(Nature::Adamant), Nature::from_stats(Stat::Atk, Stat::SpA));
+        assert_eq!(Some(Nature::Timid), Nature::from_stats(Stat::Spe, Stat::Atk));
+        assert_eq!(Some(Nature::Serious), Nature::from_stats(Stat::Spe, Stat::Spe));
+        assert_eq!(None, Nature::from_stats(Stat::HP, Stat::Atk));
+        for value in 0..25 {
+            let nature = Nature::n(value).unwrap();
+            if let (Some(up), Some(down)) = (nature.increased_stat(), nature.decreased_stat()) {
+                assert_eq!(Some(nature), Nature::from_stats(up, down));
+            }
+        }
+    }
+
+    #[test]
+    fn nature_flavor_test() {
+        assert_eq!(Some(Flavor::Spicy), Nature::Adamant.liked_flavor());
+        assert_eq!(Some(Flavor::Dry), Nature::Adamant.disliked_flavor());
+        assert_eq!(Some(Flavor::Sweet), Nature::Timid.liked_flavor());
+        assert_eq!(Some(Flavor::Bitter), Nature::Naughty.disliked_flavor());
+        assert_eq!(Some(Flavor::Sour), Nature::Relaxed.liked_flavor());
+    }
 }
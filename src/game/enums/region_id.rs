@@ -1,3 +1,8 @@
+use alloc::format;
+use deku::prelude::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite)]
+#[deku(type = "u8", ctx = "_endian: deku::ctx::Endian")]
 #[repr(u8)]
 /// 3DS Console Region Identifiers
 pub enum RegionID {
@@ -9,3 +14,7 @@ pub enum RegionID {
     Korea = 5,
     Taiwan = 6,
 }
+
+impl Default for RegionID {
+    fn default() -> Self { RegionID::None }
+}
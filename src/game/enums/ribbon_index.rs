@@ -0,0 +1,106 @@
+use enumn::N;
+
+/// Ribbons and marks, valued by the index of their flag in the ribbon bits of
+/// the Gen 8 formats.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, N)]
+#[repr(u8)]
+pub enum RibbonIndex {
+    ChampionKalos = 0,
+    ChampionG3Hoenn = 1,
+    ChampionSinnoh = 2,
+    BestFriends = 3,
+    Training = 4,
+    BattlerSkillful = 5,
+    BattlerExpert = 6,
+    Effort = 7,
+    Alert = 8,
+    Shock = 9,
+    Downcast = 10,
+    Careless = 11,
+    Relax = 12,
+    Snooze = 13,
+    Smile = 14,
+    Gorgeous = 15,
+    Royal = 16,
+    GorgeousRoyal = 17,
+    Artist = 18,
+    Footprint = 19,
+    Record = 20,
+    Legend = 21,
+    Country = 22,
+    National = 23,
+    Earth = 24,
+    World = 25,
+    Classic = 26,
+    Premier = 27,
+    Event = 28,
+    Birthday = 29,
+    Special = 30,
+    Souvenir = 31,
+    Wishing = 32,
+    ChampionBattle = 33,
+    ChampionRegional = 34,
+    ChampionNational = 35,
+    ChampionWorld = 36,
+    ContestMemory = 37,
+    BattleMemory = 38,
+    ChampionG6Hoenn = 39,
+    ContestStar = 40,
+    MasterCoolness = 41,
+    MasterBeauty = 42,
+    MasterCuteness = 43,
+    MasterCleverness = 44,
+    MasterToughness = 45,
+    ChampionAlola = 46,
+    BattleRoyale = 47,
+    BattleTreeGreat = 48,
+    BattleTreeMaster = 49,
+    ChampionGalar = 50,
+    TowerMaster = 51,
+    MasterRank = 52,
+    MarkLunchtime = 53,
+    MarkSleepyTime = 54,
+    MarkDusk = 55,
+    MarkDawn = 56,
+    MarkCloudy = 57,
+    MarkRainy = 58,
+    MarkStormy = 59,
+    MarkSnowy = 60,
+    MarkBlizzard = 61,
+    MarkDry = 62,
+    MarkSandstorm = 63,
+    MarkMisty = 64,
+    MarkDestiny = 65,
+    MarkFishing = 66,
+    MarkCurry = 67,
+    MarkUncommon = 68,
+    MarkRare = 69,
+    MarkRowdy = 70,
+    MarkAbsentMinded = 71,
+    MarkJittery = 72,
+    MarkExcited = 73,
+    MarkCharismatic = 74,
+    MarkCalmness = 75,
+    MarkIntense = 76,
+    MarkZonedOut = 77,
+    MarkJoyful = 78,
+    MarkAngry = 79,
+    MarkSmiley = 80,
+    MarkTeary = 81,
+    MarkUpbeat = 82,
+    MarkPeeved = 83,
+    MarkIntellectual = 84,
+    MarkFerocious = 85,
+    MarkCrafty = 86,
+    MarkScowling = 87,
+    MarkKindly = 88,
+    MarkFlustered = 89,
+    MarkPumpedUp = 90,
+    MarkZeroEnergy = 91,
+    MarkPrideful = 92,
+    MarkUnsure = 93,
+    MarkHumble = 94,
+    MarkThorny = 95,
+    MarkVigor = 96,
+    MarkSlump = 97,
+}
@@ -2,11 +2,12 @@
 
 use alloc::format;
 use deku::prelude::*;
+use enumn::N;
 
 #[allow(non_camel_case_types)]
 /// Species IDs for the corresponding English species name.
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite)]
-#[deku(type = "u16", ctx = "_endian: deku::ctx::Endian")]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, DekuRead, DekuWrite, N)]
+#[deku(type = "u16", endian = "endian", ctx = "endian: deku::ctx::Endian")]
 #[non_exhaustive]
 #[repr(u16)]
 pub enum Species {
@@ -909,6 +910,133 @@ pub enum Species {
     Glastrier,
     Spectrier,
     Calyrex,
+    Wyrdeer,
+    Kleavor,
+    Ursaluna,
+    Basculegion,
+    Sneasler,
+    Overqwil,
+    Enamorus,
+    Sprigatito,
+    Floragato,
+    Meowscarada,
+    Fuecoco,
+    Crocalor,
+    Skeledirge,
+    Quaxly,
+    Quaxwell,
+    Quaquaval,
+    Lechonk,
+    Oinkologne,
+    Tarountula,
+    Spidops,
+    Nymble,
+    Lokix,
+    Pawmi,
+    Pawmo,
+    Pawmot,
+    Tandemaus,
+    Maushold,
+    Fidough,
+    Dachsbun,
+    Smoliv,
+    Dolliv,
+    Arboliva,
+    Squawkabilly,
+    Nacli,
+    Naclstack,
+    Garganacl,
+    Charcadet,
+    Armarouge,
+    Ceruledge,
+    Tadbulb,
+    Bellibolt,
+    Wattrel,
+    Kilowattrel,
+    Maschiff,
+    Mabosstiff,
+    Shroodle,
+    Grafaiai,
+    Bramblin,
+    Brambleghast,
+    Toedscool,
+    Toedscruel,
+    Klawf,
+    Capsakid,
+    Scovillain,
+    Rellor,
+    Rabsca,
+    Flittle,
+    Espathra,
+    Tinkatink,
+    Tinkatuff,
+    Tinkaton,
+    Wiglett,
+    Wugtrio,
+    Bombirdier,
+    Finizen,
+    Palafin,
+    Varoom,
+    Revavroom,
+    Cyclizar,
+    Orthworm,
+    Glimmet,
+    Glimmora,
+    Greavard,
+    Houndstone,
+    Flamigo,
+    Cetoddle,
+    Cetitan,
+    Veluza,
+    Dondozo,
+    Tatsugiri,
+    Annihilape,
+    Clodsire,
+    Farigiraf,
+    Dudunsparce,
+    Kingambit,
+    GreatTusk,
+    ScreamTail,
+    BruteBonnet,
+    FlutterMane,
+    SlitherWing,
+    SandyShocks,
+    IronTreads,
+    IronBundle,
+    IronHands,
+    IronJugulis,
+    IronMoth,
+    IronThorns,
+    Frigibax,
+    Arctibax,
+    Baxcalibur,
+    Gimmighoul,
+    Gholdengo,
+    WoChien,
+    ChienPao,
+    TingLu,
+    ChiYu,
+    RoaringMoon,
+    IronValiant,
+    Koraidon,
+    Miraidon,
+    WalkingWake,
+    IronLeaves,
+    Dipplin,
+    Poltchageist,
+    Sinistcha,
+    Okidogi,
+    Munkidori,
+    Fezandipiti,
+    Ogerpon,
+    Archaludon,
+    Hydrapple,
+    GougingFire,
+    RagingBolt,
+    IronBoulder,
+    IronCrown,
+    Terapagos,
+    Pecharunt,
     MAX_COUNT,
 }
 
@@ -0,0 +1,14 @@
+use enumn::N;
+
+/// Stats a PKM has, valued by their index in the stat arrays used throughout
+/// the crate.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, N)]
+#[repr(u8)]
+pub enum Stat {
+    HP = 0,
+    Atk = 1,
+    Def = 2,
+    Spe = 3,
+    SpA = 4,
+    SpD = 5,
+}
@@ -0,0 +1,118 @@
+use crate::{error::PkmError, game::enums::species::Species};
+use alloc::vec::Vec;
+use bitconv::{endian::Little, to_uint16};
+
+/// Size of a single Gen 6 evolution method.
+pub const SIZE_METHOD_6: usize = 6;
+/// Size of a single Gen 7 and Sword/Shield evolution method.
+pub const SIZE_METHOD_7: usize = 8;
+
+/// A way a species can evolve.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EvolutionMethod {
+    /// Game specific evolution method ID.
+    pub method: u16,
+    /// Item, move, species or other value the method depends on.
+    pub argument: u16,
+    pub species: Species,
+    /// Form evolved into, or -1 to keep the current form.
+    pub form: i8,
+    /// Minimum level required, or 0 if the method has no level requirement.
+    pub level: u8,
+}
+
+/// Every way a species can evolve, in the order the game checks them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EvolutionSet {
+    pub methods: Vec<EvolutionMethod>,
+}
+
+impl EvolutionSet {
+    /// Reads a Gen 6 evolution file, made of six byte methods.
+    pub fn from_gen6(data: &[u8]) -> Result<Self, PkmError> {
+        Self::read(data, SIZE_METHOD_6, |_| (-1, 0))
+    }
+
+    /// Reads a Gen 7 or Sword/Shield evolution file, made of eight byte
+    /// methods.
+    pub fn from_gen7(data: &[u8]) -> Result<Self, PkmError> {
+        Self::read(data, SIZE_METHOD_7, |method| (method[6] as i8, method[7]))
+    }
+
+    fn read(
+        data: &[u8],
+        size: usize,
+        form_level: impl Fn(&[u8]) -> (i8, u8),
+    ) -> Result<Self, PkmError> {
+        let mut methods = Vec::new();
+        for (i, method) in data.chunks_exact(size).enumerate() {
+            let id = to_uint16::<Little>(method, 0);
+            if id == 0 {
+                continue;
+            }
+            let value = to_uint16::<Little>(method, 4);
+            let species = Species::n(value).ok_or(PkmError::InvalidField {
+                field: "species",
+                offset: i * size + 4,
+                value: value as u32,
+            })?;
+            let (form, level) = form_level(method);
+            methods.push(EvolutionMethod {
+                method: id,
+                argument: to_uint16::<Little>(method, 2),
+                species,
+                form,
+                level,
+            });
+        }
+        Ok(EvolutionSet { methods })
+    }
+
+    pub fn is_empty(&self) -> bool { self.methods.is_empty() }
+
+    /// Iterates over the species this species can evolve into.
+    pub fn species(&self) -> impl Iterator<Item = Species> + '_ {
+        self.methods.iter().map(|method| method.species)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn evolution_gen6_test() {
+        // Level up to 16, then an empty method
+        let data = [4, 0, 16, 0, 5, 0, 0, 0, 0, 0, 0, 0];
+        let set = EvolutionSet::from_gen6(&data).unwrap();
+        assert_eq!(
+            vec![EvolutionMethod {
+                method: 4,
+                argument: 16,
+                species: Species::Charmeleon,
+                form: -1,
+                level: 0
+            }],
+            set.methods
+        );
+    }
+
+    #[test]
+    fn evolution_gen7_test() {
+        let mut data = [0u8; SIZE_METHOD_7 * 8];
+        data[..8].copy_from_slice(&[4, 0, 0, 0, 0x2B, 0x03, 0xFF, 16]);
+        data[16..24].copy_from_slice(&[4, 0, 0, 0, 0x2C, 0x03, 1, 35]);
+        let set = EvolutionSet::from_gen7(&data).unwrap();
+        assert_eq!(vec![Species::Thwackey, Species::Rillaboom], set.species().collect::<Vec<_>>());
+        assert_eq!((-1, 16), (set.methods[0].form, set.methods[0].level));
+        assert_eq!((1, 35), (set.methods[1].form, set.methods[1].level));
+        assert!(EvolutionSet::from_gen7(&[0; SIZE_METHOD_7]).unwrap().is_empty());
+
+        data[4..6].copy_from_slice(&0xFFFFu16.to_le_bytes());
+        assert_eq!(
+            Err(PkmError::InvalidField { field: "species", offset: 4, value: 0xFFFF }),
+            EvolutionSet::from_gen7(&data)
+        );
+    }
+}
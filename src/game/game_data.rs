@@ -0,0 +1,233 @@
+use crate::{
+    error::{check_entries, PkmError}, game::{
+        enums::species::Species, evolution::EvolutionSet, learnset::{self, Learnset}, personal_info::PersonalTable
+    }, util::garc::Garc
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use conquer_once::spin::Lazy;
+use core::{
+    ptr, sync::atomic::{AtomicPtr, Ordering}
+};
+
+/// Data set used until [`set_game_data`] replaces it. No game tables are
+/// bundled with the crate yet, so it holds no entries.
+static STOCK: Lazy<GameData> = Lazy::new(GameData::default);
+
+/// Data set passed to [`set_game_data`], or null to use [`STOCK`].
+static CURRENT: AtomicPtr<GameData> = AtomicPtr::new(ptr::null_mut());
+
+/// Gets the data set in use, either the one last passed to [`set_game_data`]
+/// or the stock one.
+pub fn game_data() -> &'static GameData {
+    let current = CURRENT.load(Ordering::Acquire);
+    if current.is_null() {
+        &STOCK
+    } else {
+        // Data sets are leaked when set and never freed, so they live for the
+        // rest of the program.
+        unsafe { &*current }
+    }
+}
+
+/// Replaces the data set in use, for example with one read from the files of
+/// a modified game.
+///
+/// References handed out by [`game_data`] may outlive the replacement, so the
+/// previous data set is kept alive instead of being freed. Every call leaks
+/// the data set passed to it for the rest of the program, so this is meant to
+/// be called once at start up rather than every time a game is loaded.
+pub fn set_game_data(data: GameData) {
+    CURRENT.store(Box::leak(Box::new(data)), Ordering::Release);
+}
+
+/// Personal, learnset and evolution tables of a game.
+///
+/// Everything that needs game data either takes it by reference or reads the
+/// data set in use, see [`game_data`], so a data set read from the files of a
+/// modified game can be used in place of the stock one.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GameData {
+    pub personal: PersonalTable,
+    /// Learnsets, indexed like the personal table.
+    pub learnsets: Vec<Learnset>,
+    /// Evolutions, indexed like the personal table.
+    pub evolutions: Vec<EvolutionSet>,
+}
+
+impl GameData {
+    /// Reads the Sword/Shield `personal_total.bin` and `wazaoboe_total.bin`
+    /// tables, along with the `evo_*.bin` files in personal table order.
+    pub fn from_swsh(
+        personal: &[u8],
+        wazaoboe: &[u8],
+        evolutions: &[&[u8]],
+    ) -> Result<Self, PkmError> {
+        check_entries(wazaoboe.len(), learnset::SIZE_SWSH)?;
+        GameData::new(
+            PersonalTable::from_swsh(personal)?,
+            wazaoboe
+                .chunks_exact(learnset::SIZE_SWSH)
+                .map(Learnset::read)
+                .collect::<Result<_, _>>()?,
+            evolutions
+                .iter()
+                .map(|data| EvolutionSet::from_gen7(data))
+                .collect::<Result<_, _>>()?,
+        )
+    }
+
+    /// Reads the personal, level up learnset and evolution GARC archives of a
+    /// Gen 6 game.
+    pub fn from_gen6_garc(
+        personal: &[u8],
+        learnsets: &[u8],
+        evolutions: &[u8],
+        max_species: Species,
+    ) -> Result<Self, PkmError> {
+        Self::from_garc(personal, learnsets, evolutions, max_species, EvolutionSet::from_gen6)
+    }
+
+    /// Reads the personal, level up learnset and evolution GARC archives of a
+    /// Gen 7 game.
+    pub fn from_gen7_garc(
+        personal: &[u8],
+        learnsets: &[u8],
+        evolutions: &[u8],
+        max_species: Species,
+    ) -> Result<Self, PkmError> {
+        Self::from_garc(personal, learnsets, evolutions, max_species, EvolutionSet::from_gen7)
+    }
+
+    /// The personal archive holds one file per entry, followed by a file with
+    /// all of them together, which is the one read.
+    fn from_garc(
+        personal: &[u8],
+        learnsets: &[u8],
+        evolutions: &[u8],
+        max_species: Species,
+        read_evolutions: fn(&[u8]) -> Result<EvolutionSet, PkmError>,
+    ) -> Result<Self, PkmError> {
+        let personal = Garc::new(personal)?;
+        if personal.len() < 2 {
+            return Err(PkmError::InvalidLength { expected: vec![2], actual: personal.len() });
+        }
+        let entry_size = personal.file(0).unwrap().len();
+        let table = personal.file(personal.len() - 1).unwrap();
+        GameData::new(
+            PersonalTable::from_gen67(table, entry_size, max_species)?,
+            Garc::new(learnsets)?.files().map(Learnset::read).collect::<Result<_, _>>()?,
+            Garc::new(evolutions)?.files().map(read_evolutions).collect::<Result<_, _>>()?,
+        )
+    }
+
+    /// Checks that there is a learnset and an evolution set for each personal
+    /// table entry, as they are looked up by the same index.
+    fn new(
+        personal: PersonalTable,
+        learnsets: Vec<Learnset>,
+        evolutions: Vec<EvolutionSet>,
+    ) -> Result<Self, PkmError> {
+        for actual in [learnsets.len(), evolutions.len()] {
+            if actual != personal.len() {
+                return Err(PkmError::InvalidLength { expected: vec![personal.len()], actual });
+            }
+        }
+        Ok(GameData { personal, learnsets, evolutions })
+    }
+
+    /// Gets the level up learnset of a species and form.
+    pub fn learnset(&self, species: Species, form: u16) -> Option<&Learnset> {
+        self.learnsets.get(self.personal.form_index(species as u16, form)?)
+    }
+
+    /// Gets the evolutions of a species and form.
+    pub fn evolutions(&self, species: Species, form: u16) -> Option<&EvolutionSet> {
+        self.evolutions.get(self.personal.form_index(species as u16, form)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        game::{
+            enums::moves::Move, personal_info::{SIZE_SWSH, SIZE_XY}
+        }, util::garc::pack
+    };
+    use alloc::vec;
+
+    fn personal_entry(hp: u8, form_stats_index: u16, form_count: u8) -> [u8; SIZE_XY] {
+        let mut data = [0u8; SIZE_XY];
+        data[0] = hp;
+        data[0x1C..0x1E].copy_from_slice(&form_stats_index.to_le_bytes());
+        data[0x20] = form_count;
+        data
+    }
+
+    #[test]
+    fn game_data_gen6_garc_test() {
+        // Entries for species 0 and 1, then a second form of species 1
+        let entries = [personal_entry(0, 0, 1), personal_entry(45, 2, 2), personal_entry(60, 0, 1)];
+        let total = entries.concat();
+        let personal = pack(&[&entries[0], &entries[1], &entries[2], &total]);
+        let learnsets = pack(&[
+            &[0xFF; 4],
+            &[0x21, 0, 1, 0, 0xFF, 0xFF, 0xFF, 0xFF],
+            &[0x2D, 0, 1, 0, 0xFF, 0xFF, 0xFF, 0xFF],
+        ]);
+        let evolutions = pack(&[&[0; 0x30], &[4, 0, 16, 0, 2, 0], &[0; 0x30]]);
+        let data = GameData::from_gen6_garc(&personal, &learnsets, &evolutions, Species::Bulbasaur)
+            .unwrap();
+
+        assert_eq!(3, data.personal.len());
+        assert_eq!(1, data.personal.max_species());
+        assert_eq!(
+            [60, 0, 0, 0, 0, 0],
+            data.personal.get(Species::Bulbasaur, 1).unwrap().base_stats
+        );
+        assert_eq!(vec![Move::Tackle], data.learnset(Species::Bulbasaur, 0).unwrap().moves);
+        assert_eq!(vec![Move::Growl], data.learnset(Species::Bulbasaur, 1).unwrap().moves);
+        assert_eq!(
+            vec![Species::Ivysaur],
+            data.evolutions(Species::Bulbasaur, 0).unwrap().species().collect::<Vec<_>>()
+        );
+        assert!(data.evolutions(Species::Bulbasaur, 1).unwrap().is_empty());
+        assert_eq!(None, data.learnset(Species::Ivysaur, 0));
+
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![2], actual: 1 }),
+            GameData::from_gen6_garc(&pack(&[&total]), &learnsets, &evolutions, Species::Bulbasaur)
+        );
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![3], actual: 2 }),
+            GameData::from_gen6_garc(&personal, &learnsets, &pack(&[&[], &[]]), Species::Bulbasaur)
+        );
+    }
+
+    #[test]
+    fn game_data_swsh_test() {
+        let mut personal = vec![0u8; SIZE_SWSH * 2];
+        personal[SIZE_SWSH] = 45;
+        let mut wazaoboe = vec![0xFFu8; learnset::SIZE_SWSH * 2];
+        wazaoboe[learnset::SIZE_SWSH..learnset::SIZE_SWSH + 4].copy_from_slice(&[0x21, 0, 1, 0]);
+        let evolution = [4, 0, 0, 0, 2, 0, 0xFF, 16];
+
+        let data = GameData::from_swsh(&personal, &wazaoboe, &[&[], &evolution]).unwrap();
+        assert_eq!(45, data.personal.get(Species::Bulbasaur, 0).unwrap().base_stats[0]);
+        assert_eq!(vec![Move::Tackle], data.learnset(Species::Bulbasaur, 0).unwrap().moves);
+        assert_eq!(16, data.evolutions(Species::Bulbasaur, 0).unwrap().methods[0].level);
+
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![0, learnset::SIZE_SWSH], actual: 1 }),
+            GameData::from_swsh(&personal, &[0xFF], &[])
+        );
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![2], actual: 1 }),
+            GameData::from_swsh(&personal, &wazaoboe[..learnset::SIZE_SWSH], &[&[], &evolution])
+        );
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![2], actual: 1 }),
+            GameData::from_swsh(&personal, &wazaoboe, &[&evolution])
+        );
+    }
+}
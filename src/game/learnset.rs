@@ -0,0 +1,122 @@
+use crate::{error::PkmError, game::enums::moves::Move};
+use alloc::vec::Vec;
+use bitconv::{endian::Little, to_uint16};
+
+/// Size of a single Sword/Shield `wazaoboe_total.bin` entry.
+pub const SIZE_SWSH: usize = 0x104;
+
+/// Moves a species learns by leveling up, in the order they are learned.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Learnset {
+    pub moves: Vec<Move>,
+    pub levels: Vec<u8>,
+}
+
+impl Learnset {
+    /// Reads a learnset stored as move and level pairs of two bytes each,
+    /// ended by `0xFFFF`, the format shared by the Gen 6, Gen 7 and
+    /// Sword/Shield files.
+    pub fn read(data: &[u8]) -> Result<Self, PkmError> {
+        let mut learnset = Learnset::default();
+        for offset in (0..data.len() & !3).step_by(4) {
+            let id = to_uint16::<Little>(data, offset);
+            if id == 0xFFFF {
+                break;
+            }
+            let move_id = Move::n(id).ok_or(PkmError::InvalidField {
+                field: "move",
+                offset,
+                value: id as u32,
+            })?;
+            learnset.moves.push(move_id);
+            learnset.levels.push(to_uint16::<Little>(data, offset + 2) as u8);
+        }
+        Ok(learnset)
+    }
+
+    /// Gets the moves learned at exactly `level`.
+    pub fn moves_at(&self, level: u8) -> Vec<Move> {
+        self.iter().filter(|&(_, l)| l == level).map(|(move_id, _)| move_id).collect()
+    }
+
+    /// Gets the four most recent moves learned by `level`, as an entity
+    /// encountered at that level knows them.
+    pub fn current_moves(&self, level: u8) -> [Move; 4] {
+        let mut moves = [Move::None; 4];
+        let mut learned = 0;
+        for (move_id, _) in self.iter().filter(|&(_, l)| l <= level) {
+            if moves.contains(&move_id) {
+                continue;
+            }
+            moves[learned % 4] = move_id;
+            learned += 1;
+        }
+        if learned > 4 {
+            moves.rotate_left(learned % 4);
+        }
+        moves
+    }
+
+    /// Iterates over the moves along with the level they are learned at.
+    pub fn iter(&self) -> impl Iterator<Item = (Move, u8)> + '_ {
+        self.moves.iter().copied().zip(self.levels.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    fn pairs(entries: &[(Move, u16)]) -> Vec<u8> {
+        let mut data = Vec::new();
+        for &(move_id, level) in entries {
+            data.extend_from_slice(&(move_id as u16).to_le_bytes());
+            data.extend_from_slice(&level.to_le_bytes());
+        }
+        data.extend_from_slice(&[0xFF; 4]);
+        data
+    }
+
+    #[test]
+    fn learnset_read_test() {
+        let data = pairs(&[(Move::Scratch, 1), (Move::Growl, 1), (Move::BranchPoke, 3)]);
+        let learnset = Learnset::read(&data).unwrap();
+        assert_eq!(vec![Move::Scratch, Move::Growl, Move::BranchPoke], learnset.moves);
+        assert_eq!(vec![1, 1, 3], learnset.levels);
+        assert_eq!(vec![Move::Scratch, Move::Growl], learnset.moves_at(1));
+
+        let mut padded = data.clone();
+        padded.resize(SIZE_SWSH, 0);
+        assert_eq!(learnset, Learnset::read(&padded).unwrap());
+
+        let bad = pairs(&[(Move::Scratch, 1)]);
+        let bad = [&[0xFE, 0xFF, 1, 0][..], &bad[..]].concat();
+        assert_eq!(
+            Err(PkmError::InvalidField { field: "move", offset: 0, value: 0xFFFE }),
+            Learnset::read(&bad)
+        );
+    }
+
+    #[test]
+    fn learnset_current_moves_test() {
+        let data = pairs(&[
+            (Move::Scratch, 1),
+            (Move::Growl, 1),
+            (Move::BranchPoke, 3),
+            (Move::Taunt, 6),
+            (Move::RazorLeaf, 8),
+            (Move::Scratch, 10),
+        ]);
+        let learnset = Learnset::read(&data).unwrap();
+        assert_eq!([Move::Scratch, Move::Growl, Move::None, Move::None], learnset.current_moves(2));
+        assert_eq!(
+            [Move::Growl, Move::BranchPoke, Move::Taunt, Move::RazorLeaf],
+            learnset.current_moves(9)
+        );
+        assert_eq!(
+            [Move::BranchPoke, Move::Taunt, Move::RazorLeaf, Move::Scratch],
+            learnset.current_moves(10)
+        );
+    }
+}
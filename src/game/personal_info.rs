@@ -0,0 +1,321 @@
+use crate::{
+    error::{check_entries, PkmError}, game::enums::{ability::Ability, move_type::MoveType, species::Species}
+};
+use alloc::vec::Vec;
+use bitconv::{endian::Little, to_uint16};
+use core::convert::TryInto;
+
+/// Size of a single X/Y personal entry.
+pub const SIZE_XY: usize = 0x40;
+/// Size of a single Omega Ruby/Alpha Sapphire personal entry.
+pub const SIZE_AO: usize = 0x50;
+/// Size of a single Sun/Moon and Ultra Sun/Ultra Moon personal entry.
+pub const SIZE_SM: usize = 0x54;
+/// Size of a single Sword/Shield personal entry.
+pub const SIZE_SWSH: usize = 0xB0;
+
+/// Gender ratio of species that can only be male.
+pub const RATIO_MAGIC_MALE: u8 = 0;
+/// Gender ratio of species that can only be female.
+pub const RATIO_MAGIC_FEMALE: u8 = 254;
+/// Gender ratio of species that have no gender.
+pub const RATIO_MAGIC_GENDERLESS: u8 = 255;
+
+/// Base data shared by every entity of a species and form.
+///
+/// Stats are ordered HP, Atk, Def, Spe, SpA, SpD, matching the order entities
+/// store them in.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PersonalInfo {
+    pub base_stats: [u8; 6],
+    pub types: [MoveType; 2],
+    pub catch_rate: u8,
+    pub evo_stage: u8,
+    pub ev_yield: [u8; 6],
+    pub items: [u16; 3],
+    /// Chance out of 254 of being female, or one of the `RATIO_MAGIC_*`
+    /// values.
+    pub gender: u8,
+    pub hatch_cycles: u8,
+    pub base_friendship: u8,
+    /// Growth rate used with [`crate::pkm::util::experience`].
+    pub exp_growth: u8,
+    pub egg_groups: [u8; 2],
+    /// First, second and hidden ability.
+    pub abilities: [Ability; 3],
+    /// Index of the first alternate form entry, or 0 if the species has none.
+    pub form_stats_index: u16,
+    pub form_count: u8,
+    pub color: u8,
+    pub is_present_in_game: bool,
+    pub base_exp: u16,
+    pub height: u16,
+    pub weight: u16,
+    /// Galar Pokédex number, or 0 if absent.
+    pub galar_dex: u16,
+    /// Isle of Armor Pokédex number, or 0 if absent.
+    pub armor_dex: u16,
+    /// Crown Tundra Pokédex number, or 0 if absent.
+    pub crown_dex: u16,
+}
+
+impl PersonalInfo {
+    /// Reads an entry of the Sword/Shield `personal_total.bin` table.
+    pub fn from_swsh(data: &[u8; SIZE_SWSH]) -> Self {
+        let u16_at = |offset| to_uint16::<Little>(&data[..], offset);
+        let ability = |offset| Ability::n(u16_at(offset)).unwrap_or_default();
+        PersonalInfo {
+            abilities: [ability(0x18), ability(0x1A), ability(0x1C)],
+            form_stats_index: u16_at(0x1E),
+            is_present_in_game: (data[0x21] >> 6) & 1 == 1,
+            galar_dex: u16_at(0x5C),
+            armor_dex: u16_at(0xAC),
+            crown_dex: u16_at(0xAE),
+            ..Self::read_common(&data[..])
+        }
+    }
+
+    /// Reads an entry of a Gen 6 or Gen 7 personal table. Every game of both
+    /// generations shares the layout of the first [`SIZE_XY`] bytes, and
+    /// stores abilities in a single byte each.
+    pub fn from_gen67(data: &[u8; SIZE_XY]) -> Self {
+        let ability = |offset: usize| Ability::n(data[offset] as u16).unwrap_or_default();
+        PersonalInfo {
+            abilities: [ability(0x18), ability(0x19), ability(0x1A)],
+            form_stats_index: to_uint16::<Little>(&data[..], 0x1C),
+            is_present_in_game: true,
+            ..Self::read_common(&data[..])
+        }
+    }
+
+    /// Reads the fields every personal format stores at the same offsets.
+    fn read_common(data: &[u8]) -> Self {
+        let u16_at = |offset| to_uint16::<Little>(data, offset);
+        let move_type = |offset: usize| MoveType::n(data[offset] as i8).unwrap_or_default();
+        let ev_yield = u16_at(0x0A);
+        let mut evs = [0; 6];
+        for (stat, ev) in evs.iter_mut().enumerate() {
+            *ev = ((ev_yield >> (stat * 2)) & 0b11) as u8;
+        }
+
+        PersonalInfo {
+            base_stats: [data[0x00], data[0x01], data[0x02], data[0x03], data[0x04], data[0x05]],
+            types: [move_type(0x06), move_type(0x07)],
+            catch_rate: data[0x08],
+            evo_stage: data[0x09],
+            ev_yield: evs,
+            items: [u16_at(0x0C), u16_at(0x0E), u16_at(0x10)],
+            gender: data[0x12],
+            hatch_cycles: data[0x13],
+            base_friendship: data[0x14],
+            exp_growth: data[0x15],
+            egg_groups: [data[0x16], data[0x17]],
+            form_count: data[0x20],
+            color: data[0x21] & 0x3F,
+            base_exp: u16_at(0x22),
+            height: u16_at(0x24),
+            weight: u16_at(0x26),
+            ..PersonalInfo::default()
+        }
+    }
+
+    pub fn is_genderless(&self) -> bool { self.gender == RATIO_MAGIC_GENDERLESS }
+    pub fn only_male(&self) -> bool { self.gender == RATIO_MAGIC_MALE }
+    pub fn only_female(&self) -> bool { self.gender == RATIO_MAGIC_FEMALE }
+    pub fn is_dual_gender(&self) -> bool { self.gender.wrapping_sub(1) < RATIO_MAGIC_FEMALE - 1 }
+
+    /// Whether `form` has an entry of its own in the table.
+    pub fn has_form(&self, form: u16) -> bool {
+        form != 0 && self.form_stats_index != 0 && form < self.form_count as u16
+    }
+}
+
+/// Personal entries of a game, indexable by species and form.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PersonalTable {
+    table: Vec<PersonalInfo>,
+    max_species: u16,
+}
+
+impl PersonalTable {
+    /// Creates a table from its entries, where species entries come first and
+    /// are followed by the alternate form entries.
+    pub fn new(table: Vec<PersonalInfo>, max_species: u16) -> Self {
+        PersonalTable { table, max_species }
+    }
+
+    /// Reads the Sword/Shield `personal_total.bin` table.
+    pub fn from_swsh(data: &[u8]) -> Result<Self, PkmError> {
+        Self::read(data, SIZE_SWSH, Species::Calyrex, |entry| {
+            PersonalInfo::from_swsh(entry.try_into().unwrap())
+        })
+    }
+
+    /// Reads a Gen 6 or Gen 7 personal table made of `entry_size` byte
+    /// entries, whose last species entry is `max_species`.
+    pub fn from_gen67(
+        data: &[u8],
+        entry_size: usize,
+        max_species: Species,
+    ) -> Result<Self, PkmError> {
+        const SIZES: &[usize] = &[SIZE_XY, SIZE_AO, SIZE_SM];
+        if !SIZES.contains(&entry_size) {
+            return Err(PkmError::InvalidLength { expected: SIZES.to_vec(), actual: entry_size });
+        }
+        Self::read(data, entry_size, max_species, |entry| {
+            PersonalInfo::from_gen67(entry[..SIZE_XY].try_into().unwrap())
+        })
+    }
+
+    fn read(
+        data: &[u8],
+        entry_size: usize,
+        max_species: Species,
+        parse: impl Fn(&[u8]) -> PersonalInfo,
+    ) -> Result<Self, PkmError> {
+        check_entries(data.len(), entry_size)?;
+        let table: Vec<PersonalInfo> = data.chunks_exact(entry_size).map(parse).collect();
+        let max_species = (table.len().min(max_species as usize + 1) as u16).saturating_sub(1);
+        Ok(PersonalTable::new(table, max_species))
+    }
+
+    pub fn len(&self) -> usize { self.table.len() }
+    pub fn is_empty(&self) -> bool { self.table.is_empty() }
+    pub fn max_species(&self) -> u16 { self.max_species }
+
+    /// Gets the index of the entry holding the data of a species and form,
+    /// falling back to the base species entry for forms without their own.
+    pub fn form_index(&self, species: u16, form: u16) -> Option<usize> {
+        if species > self.max_species {
+            return None;
+        }
+        let entry = self.table.get(species as usize)?;
+        if !entry.has_form(form) {
+            return Some(species as usize);
+        }
+        Some((entry.form_stats_index + form - 1) as usize)
+    }
+
+    /// Gets the entry of a species and form.
+    pub fn get(&self, species: Species, form: u16) -> Option<&PersonalInfo> {
+        self.table.get(self.form_index(species as u16, form)?)
+    }
+
+    /// Gets an entry by its index in the table.
+    pub fn entry(&self, index: usize) -> Option<&PersonalInfo> { self.table.get(index) }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    fn entry(stats: [u8; 6], form_stats_index: u16, form_count: u8) -> [u8; SIZE_SWSH] {
+        let mut data = [0u8; SIZE_SWSH];
+        data[..6].copy_from_slice(&stats);
+        data[0x1E..0x20].copy_from_slice(&form_stats_index.to_le_bytes());
+        data[0x20] = form_count;
+        data
+    }
+
+    #[test]
+    fn personal_info_swsh_test() {
+        // Dracovish
+        let mut data = entry([90, 90, 100, 75, 70, 80], 0, 1);
+        data[0x06] = MoveType::Water as u8;
+        data[0x07] = MoveType::Dragon as u8;
+        data[0x08] = 45;
+        data[0x0A..0x0C].copy_from_slice(&0b0000_0000_0000_1000u16.to_le_bytes());
+        data[0x12] = RATIO_MAGIC_GENDERLESS;
+        data[0x15] = 5;
+        data[0x18..0x1A].copy_from_slice(&(Ability::WaterAbsorb as u16).to_le_bytes());
+        data[0x1A..0x1C].copy_from_slice(&(Ability::StrongJaw as u16).to_le_bytes());
+        data[0x1C..0x1E].copy_from_slice(&(Ability::SandRush as u16).to_le_bytes());
+        data[0x21] = 0b0100_0001;
+        data[0x5C..0x5E].copy_from_slice(&391u16.to_le_bytes());
+
+        let info = PersonalInfo::from_swsh(&data);
+        assert_eq!([90, 90, 100, 75, 70, 80], info.base_stats);
+        assert_eq!([MoveType::Water, MoveType::Dragon], info.types);
+        assert_eq!(45, info.catch_rate);
+        assert_eq!([0, 2, 0, 0, 0, 0], info.ev_yield);
+        assert!(info.is_genderless());
+        assert!(!info.is_dual_gender());
+        assert_eq!(5, info.exp_growth);
+        assert_eq!([Ability::WaterAbsorb, Ability::StrongJaw, Ability::SandRush], info.abilities);
+        assert_eq!(1, info.color);
+        assert!(info.is_present_in_game);
+        assert_eq!(391, info.galar_dex);
+        assert_eq!(0, info.armor_dex);
+    }
+
+    #[test]
+    fn personal_info_gen67_test() {
+        // Rowlet
+        let mut data = [0u8; SIZE_SM];
+        data[..6].copy_from_slice(&[68, 55, 55, 42, 50, 50]);
+        data[0x06] = MoveType::Grass as u8;
+        data[0x07] = MoveType::Flying as u8;
+        data[0x12] = 31;
+        data[0x18] = Ability::Overgrow as u8;
+        data[0x19] = Ability::Overgrow as u8;
+        data[0x1A] = Ability::LongReach as u8;
+        data[0x20] = 1;
+
+        let table = PersonalTable::from_gen67(&data, SIZE_SM, Species::Melmetal).unwrap();
+        let info = table.entry(0).unwrap();
+        assert_eq!([68, 55, 55, 42, 50, 50], info.base_stats);
+        assert_eq!([MoveType::Grass, MoveType::Flying], info.types);
+        assert!(info.is_dual_gender());
+        assert_eq!([Ability::Overgrow, Ability::Overgrow, Ability::LongReach], info.abilities);
+        assert!(info.is_present_in_game);
+        assert_eq!(0, table.max_species());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_XY, SIZE_AO, SIZE_SM],
+                actual: 0x20
+            }),
+            PersonalTable::from_gen67(&data, 0x20, Species::Melmetal)
+        );
+    }
+
+    #[test]
+    fn personal_info_gender_test() {
+        let mut info = PersonalInfo::default();
+        assert!(info.only_male());
+        info.gender = RATIO_MAGIC_FEMALE;
+        assert!(info.only_female());
+        assert!(!info.is_dual_gender());
+        for gender in [1, 31, 127, 253] {
+            info.gender = gender;
+            assert!(info.is_dual_gender());
+        }
+    }
+
+    #[test]
+    fn personal_table_form_test() {
+        let mut data = vec![0u8; SIZE_SWSH * 4];
+        data[SIZE_SWSH..SIZE_SWSH * 2].copy_from_slice(&entry([1, 1, 1, 1, 1, 1], 2, 3));
+        data[SIZE_SWSH * 2..SIZE_SWSH * 3].copy_from_slice(&entry([2, 2, 2, 2, 2, 2], 0, 1));
+        data[SIZE_SWSH * 3..].copy_from_slice(&entry([3, 3, 3, 3, 3, 3], 2, 3));
+        let table = PersonalTable::from_swsh(&data).unwrap();
+        assert_eq!(4, table.len());
+        assert_eq!(3, table.max_species());
+
+        assert_eq!(Some(1), table.form_index(1, 0));
+        assert_eq!(Some(2), table.form_index(1, 1));
+        assert_eq!(Some(3), table.form_index(1, 2));
+        assert_eq!(Some(1), table.form_index(1, 3));
+        assert_eq!(Some(2), table.form_index(2, 1));
+        assert_eq!(None, table.form_index(4, 0));
+        assert_eq!([2; 6], table.get(Species::Bulbasaur, 1).unwrap().base_stats);
+        assert_eq!([1; 6], table.get(Species::Bulbasaur, 5).unwrap().base_stats);
+        assert_eq!(None, table.get(Species::Calyrex, 0));
+
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![0, SIZE_SWSH], actual: 1 }),
+            PersonalTable::from_swsh(&[0])
+        );
+    }
+}
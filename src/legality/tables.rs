@@ -1,5 +1,11 @@
 /// Decoration and logic for Met Location IDs.
 pub mod location;
 
+/// Gen 1 and 2 Legality Table
+pub mod tables_1;
+
 /// Gen 8 Legality Table
 pub mod tables_8;
+
+/// Gen 8 (Brilliant Diamond and Shining Pearl) Legality Table
+pub mod tables_8b;
@@ -86,6 +86,15 @@ pub const TRANSFER_4_CROWN_USED: i32 = 30013;
 /// Generation 6 Gift from Pokémon Link
 pub const LINKGIFT_6: i32 = 30011;
 
+/// Twinleaf Town in
+/// [GameVersion::BDSP][`crate::game::enums::game_version::GameVersion::BDSP`]
+pub const HATCH_LOCATION_8B: i32 = 60010;
+
+/// Egg location of entities in
+/// [GameVersion::BDSP][`crate::game::enums::game_version::GameVersion::BDSP`]
+/// that were never an egg
+pub const DEFAULT_8B_NONE: i32 = 65535;
+
 /// Generation 7 Transfer from GO to Pokémon LGP/E's GO Park
 pub const GO_7: i32 = 50;
 
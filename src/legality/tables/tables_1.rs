@@ -0,0 +1,165 @@
+use crate::game::enums::move_type::MoveType::{self, *};
+
+pub(crate) const MAX_MOVE_ID_1: u16 = 165; // Struggle
+pub(crate) const MAX_SPECIES_ID_2: u16 = 251; // Celebi
+pub(crate) const MAX_MOVE_ID_2: u16 = 251; // Beat Up
+
+/// Highest PP a move can have in Generations 1 and 2, once all PP Ups are used.
+pub(crate) const MAX_PP_12: u8 = 61;
+
+/// Types of each species in Red, Blue and Yellow, indexed by National Dex
+/// number. Single typed species have the same type twice.
+pub(crate) const TYPES_RBY: [[MoveType; 2]; 152] = [
+    [Normal, Normal],     // None
+    [Grass, Poison],      // Bulbasaur
+    [Grass, Poison],      // Ivysaur
+    [Grass, Poison],      // Venusaur
+    [Fire, Fire],         // Charmander
+    [Fire, Fire],         // Charmeleon
+    [Fire, Flying],       // Charizard
+    [Water, Water],       // Squirtle
+    [Water, Water],       // Wartortle
+    [Water, Water],       // Blastoise
+    [Bug, Bug],           // Caterpie
+    [Bug, Bug],           // Metapod
+    [Bug, Flying],        // Butterfree
+    [Bug, Poison],        // Weedle
+    [Bug, Poison],        // Kakuna
+    [Bug, Poison],        // Beedrill
+    [Normal, Flying],     // Pidgey
+    [Normal, Flying],     // Pidgeotto
+    [Normal, Flying],     // Pidgeot
+    [Normal, Normal],     // Rattata
+    [Normal, Normal],     // Raticate
+    [Normal, Flying],     // Spearow
+    [Normal, Flying],     // Fearow
+    [Poison, Poison],     // Ekans
+    [Poison, Poison],     // Arbok
+    [Electric, Electric], // Pikachu
+    [Electric, Electric], // Raichu
+    [Ground, Ground],     // Sandshrew
+    [Ground, Ground],     // Sandslash
+    [Poison, Poison],     // NidoranF
+    [Poison, Poison],     // Nidorina
+    [Poison, Ground],     // Nidoqueen
+    [Poison, Poison],     // NidoranM
+    [Poison, Poison],     // Nidorino
+    [Poison, Ground],     // Nidoking
+    [Normal, Normal],     // Clefairy
+    [Normal, Normal],     // Clefable
+    [Fire, Fire],         // Vulpix
+    [Fire, Fire],         // Ninetales
+    [Normal, Normal],     // Jigglypuff
+    [Normal, Normal],     // Wigglytuff
+    [Poison, Flying],     // Zubat
+    [Poison, Flying],     // Golbat
+    [Grass, Poison],      // Oddish
+    [Grass, Poison],      // Gloom
+    [Grass, Poison],      // Vileplume
+    [Bug, Grass],         // Paras
+    [Bug, Grass],         // Parasect
+    [Bug, Poison],        // Venonat
+    [Bug, Poison],        // Venomoth
+    [Ground, Ground],     // Diglett
+    [Ground, Ground],     // Dugtrio
+    [Normal, Normal],     // Meowth
+    [Normal, Normal],     // Persian
+    [Water, Water],       // Psyduck
+    [Water, Water],       // Golduck
+    [Fighting, Fighting], // Mankey
+    [Fighting, Fighting], // Primeape
+    [Fire, Fire],         // Growlithe
+    [Fire, Fire],         // Arcanine
+    [Water, Water],       // Poliwag
+    [Water, Water],       // Poliwhirl
+    [Water, Fighting],    // Poliwrath
+    [Psychic, Psychic],   // Abra
+    [Psychic, Psychic],   // Kadabra
+    [Psychic, Psychic],   // Alakazam
+    [Fighting, Fighting], // Machop
+    [Fighting, Fighting], // Machoke
+    [Fighting, Fighting], // Machamp
+    [Grass, Poison],      // Bellsprout
+    [Grass, Poison],      // Weepinbell
+    [Grass, Poison],      // Victreebel
+    [Water, Poison],      // Tentacool
+    [Water, Poison],      // Tentacruel
+    [Rock, Ground],       // Geodude
+    [Rock, Ground],       // Graveler
+    [Rock, Ground],       // Golem
+    [Fire, Fire],         // Ponyta
+    [Fire, Fire],         // Rapidash
+    [Water, Psychic],     // Slowpoke
+    [Water, Psychic],     // Slowbro
+    [Electric, Electric], // Magnemite
+    [Electric, Electric], // Magneton
+    [Normal, Flying],     // Farfetchd
+    [Normal, Flying],     // Doduo
+    [Normal, Flying],     // Dodrio
+    [Water, Water],       // Seel
+    [Water, Ice],         // Dewgong
+    [Poison, Poison],     // Grimer
+    [Poison, Poison],     // Muk
+    [Water, Water],       // Shellder
+    [Water, Ice],         // Cloyster
+    [Ghost, Poison],      // Gastly
+    [Ghost, Poison],      // Haunter
+    [Ghost, Poison],      // Gengar
+    [Rock, Ground],       // Onix
+    [Psychic, Psychic],   // Drowzee
+    [Psychic, Psychic],   // Hypno
+    [Water, Water],       // Krabby
+    [Water, Water],       // Kingler
+    [Electric, Electric], // Voltorb
+    [Electric, Electric], // Electrode
+    [Grass, Psychic],     // Exeggcute
+    [Grass, Psychic],     // Exeggutor
+    [Ground, Ground],     // Cubone
+    [Ground, Ground],     // Marowak
+    [Fighting, Fighting], // Hitmonlee
+    [Fighting, Fighting], // Hitmonchan
+    [Normal, Normal],     // Lickitung
+    [Poison, Poison],     // Koffing
+    [Poison, Poison],     // Weezing
+    [Ground, Rock],       // Rhyhorn
+    [Ground, Rock],       // Rhydon
+    [Normal, Normal],     // Chansey
+    [Grass, Grass],       // Tangela
+    [Normal, Normal],     // Kangaskhan
+    [Water, Water],       // Horsea
+    [Water, Water],       // Seadra
+    [Water, Water],       // Goldeen
+    [Water, Water],       // Seaking
+    [Water, Water],       // Staryu
+    [Water, Psychic],     // Starmie
+    [Psychic, Psychic],   // MrMime
+    [Bug, Flying],        // Scyther
+    [Ice, Psychic],       // Jynx
+    [Electric, Electric], // Electabuzz
+    [Fire, Fire],         // Magmar
+    [Bug, Bug],           // Pinsir
+    [Normal, Normal],     // Tauros
+    [Water, Water],       // Magikarp
+    [Water, Flying],      // Gyarados
+    [Water, Ice],         // Lapras
+    [Normal, Normal],     // Ditto
+    [Normal, Normal],     // Eevee
+    [Water, Water],       // Vaporeon
+    [Electric, Electric], // Jolteon
+    [Fire, Fire],         // Flareon
+    [Normal, Normal],     // Porygon
+    [Rock, Water],        // Omanyte
+    [Rock, Water],        // Omastar
+    [Rock, Water],        // Kabuto
+    [Rock, Water],        // Kabutops
+    [Rock, Flying],       // Aerodactyl
+    [Normal, Normal],     // Snorlax
+    [Ice, Flying],        // Articuno
+    [Electric, Flying],   // Zapdos
+    [Fire, Flying],       // Moltres
+    [Dragon, Dragon],     // Dratini
+    [Dragon, Dragon],     // Dragonair
+    [Dragon, Flying],     // Dragonite
+    [Psychic, Psychic],   // Mewtwo
+    [Psychic, Psychic],   // Mew
+];
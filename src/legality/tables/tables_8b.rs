@@ -0,0 +1,106 @@
+use crate::game::enums::moves::Move::{self, *};
+
+/// Moves taught by TMs in Brilliant Diamond and Shining Pearl, ordered by TM
+/// number. The former HMs were reassigned to TM93 through TM100.
+pub(crate) const TMHM_BDSP: [Move; 100] = [
+    FocusPunch,
+    DragonClaw,
+    WaterPulse,
+    CalmMind,
+    Roar,
+    Toxic,
+    Hail,
+    BulkUp,
+    BulletSeed,
+    HiddenPower,
+    SunnyDay,
+    Taunt,
+    IceBeam,
+    Blizzard,
+    HyperBeam,
+    LightScreen,
+    Protect,
+    RainDance,
+    GigaDrain,
+    Safeguard,
+    Frustration,
+    SolarBeam,
+    IronTail,
+    Thunderbolt,
+    Thunder,
+    Earthquake,
+    Return,
+    Dig,
+    Psychic,
+    ShadowBall,
+    BrickBreak,
+    DoubleTeam,
+    Reflect,
+    ShockWave,
+    Flamethrower,
+    SludgeBomb,
+    Sandstorm,
+    FireBlast,
+    RockTomb,
+    AerialAce,
+    Torment,
+    Facade,
+    SecretPower,
+    Rest,
+    Attract,
+    Thief,
+    SteelWing,
+    SkillSwap,
+    Snatch,
+    Overheat,
+    Roost,
+    FocusBlast,
+    EnergyBall,
+    FalseSwipe,
+    Brine,
+    Fling,
+    ChargeBeam,
+    Endure,
+    DragonPulse,
+    DrainPunch,
+    WillOWisp,
+    SilverWind,
+    Embargo,
+    Explosion,
+    ShadowClaw,
+    Payback,
+    Recycle,
+    GigaImpact,
+    RockPolish,
+    Flash,
+    StoneEdge,
+    Avalanche,
+    ThunderWave,
+    GyroBall,
+    SwordsDance,
+    StealthRock,
+    PsychUp,
+    Captivate,
+    DarkPulse,
+    RockSlide,
+    XScissor,
+    SleepTalk,
+    NaturalGift,
+    PoisonJab,
+    DreamEater,
+    GrassKnot,
+    Swagger,
+    Pluck,
+    Uturn,
+    Substitute,
+    FlashCannon,
+    TrickRoom,
+    Cut,
+    Fly,
+    Surf,
+    Strength,
+    Defog,
+    RockSmash,
+    Waterfall,
+    RockClimb,
+];
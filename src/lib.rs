@@ -4,6 +4,9 @@
 
 extern crate alloc;
 
+/// Module containing the crate-wide error type.
+pub mod error;
+
 /// Module containing utilities to manipulate Pokemon data files.
 #[macro_use]
 pub mod util;
@@ -1,4 +1,8 @@
-use crate::{game::enums::game_version::GameVersion, legality::tables::location};
+use crate::{
+    error::PkmError, game::enums::{
+        ball::Ball, game_version::GameVersion, language_id::LanguageID, moves::Move, species::Species
+    }, legality::tables::location
+};
 use alloc::{string::String, vec::Vec};
 use deku::DekuUpdate;
 
@@ -9,10 +13,12 @@ pub trait PKM {
 
     fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16>;
 
+    fn is_rs(version: GameVersion) -> bool { [GameVersion::S, GameVersion::R].contains(&version) }
     fn is_e(version: GameVersion) -> bool { version == GameVersion::E }
     fn is_frlg(version: GameVersion) -> bool {
         [GameVersion::FR, GameVersion::LG].contains(&version)
     }
+    fn is_dp(version: GameVersion) -> bool { [GameVersion::D, GameVersion::P].contains(&version) }
     fn is_pt(version: GameVersion) -> bool { version == GameVersion::PT }
     fn is_hgss(version: GameVersion) -> bool {
         [GameVersion::HG, GameVersion::SS].contains(&version)
@@ -40,7 +46,13 @@ pub trait PKM {
     fn is_swsh(version: GameVersion) -> bool {
         [GameVersion::SW, GameVersion::SH].contains(&version)
     }
+    fn is_sv(version: GameVersion) -> bool { [GameVersion::SL, GameVersion::VL].contains(&version) }
+    fn is_pla(version: GameVersion) -> bool { version == GameVersion::PLA }
+    fn is_bdsp(version: GameVersion) -> bool {
+        [GameVersion::BD, GameVersion::SP].contains(&version)
+    }
 
+    fn is_dppt(version: GameVersion) -> bool { Self::is_dp(version) || Self::is_pt(version) }
     fn is_pt_hgss(version: GameVersion) -> bool { Self::is_pt(version) || Self::is_hgss(version) }
     fn is_go_lgpe(version: GameVersion, met_location: u16) -> bool {
         Self::is_go(version) && met_location == location::GO_7 as u16
@@ -52,8 +64,11 @@ pub trait PKM {
     fn is_gg(version: GameVersion, met_location: u16) -> bool {
         Self::is_lgpe(version) || Self::is_go_lgpe(version, met_location)
     }
+    fn is_gen9(version: GameVersion) -> bool {
+        version >= GameVersion::SL && version <= GameVersion::VL
+    }
     fn is_gen8(version: GameVersion, met_location: u16) -> bool {
-        version >= GameVersion::SW && version <= GameVersion::SH
+        version >= GameVersion::SW && version <= GameVersion::SP
             || Self::is_go_home(version, met_location)
     }
     fn is_gen7(version: GameVersion, met_location: u16) -> bool {
@@ -84,6 +99,7 @@ pub trait PKM {
 
     fn get_generation(version: GameVersion, met_location: u16) -> i32 {
         match true {
+            _ if Self::is_gen9(version) => 9,
             _ if Self::is_gen8(version, met_location) => 8,
             _ if Self::is_gen7(version, met_location) => 7,
             _ if Self::is_gen6(version) => 6,
@@ -105,7 +121,78 @@ pub trait PKM {
         let _ = self.update();
     }
 
-    fn build(&mut self) -> Self::RawVariant;
+    fn build(&mut self) -> Result<Self::RawVariant, PkmError>;
+}
+
+/// Accessors shared by every entity format.
+///
+/// Unlike [`PKM`], this trait is object safe, so tools that work across
+/// formats can take `&dyn Entity` values. Stats are ordered HP, Atk, Def,
+/// Spe, SpA, SpD, matching the order they are stored in.
+pub trait Entity {
+    fn species(&self) -> Species;
+    fn set_species(&mut self, species: Species);
+    fn form(&self) -> u16;
+    fn set_form(&mut self, form: u16);
+
+    fn nickname(&self) -> &str;
+    fn set_nickname(&mut self, nickname: &str);
+    fn ot_name(&self) -> &str;
+    fn set_ot_name(&mut self, ot_name: &str);
+    fn tid(&self) -> u16;
+    fn set_tid(&mut self, tid: u16);
+    fn sid(&self) -> u16;
+    fn set_sid(&mut self, sid: u16);
+
+    fn pid(&self) -> u32;
+    fn set_pid(&mut self, pid: u32);
+    fn encryption_constant(&self) -> u32;
+    fn set_encryption_constant(&mut self, ec: u32);
+
+    fn ivs(&self) -> [u8; 6];
+    fn set_ivs(&mut self, ivs: [u8; 6]);
+    fn evs(&self) -> [u8; 6];
+    fn set_evs(&mut self, evs: [u8; 6]);
+
+    fn moves(&self) -> [Move; 4];
+    fn set_moves(&mut self, moves: [Move; 4]);
+
+    fn ball(&self) -> Ball;
+    fn set_ball(&mut self, ball: Ball);
+    fn met_location(&self) -> u16;
+    fn set_met_location(&mut self, location: u16);
+    fn met_level(&self) -> u8;
+    fn set_met_level(&mut self, level: u8);
+    /// Met date as years since 2000, month and day.
+    fn met_date(&self) -> (u8, u8, u8);
+    fn set_met_date(&mut self, year: u8, month: u8, day: u8);
+
+    fn language(&self) -> LanguageID;
+    fn set_language(&mut self, language: LanguageID);
+    fn version(&self) -> GameVersion;
+    fn set_version(&mut self, version: GameVersion);
+}
+
+/// How a stored checksum that disagrees with the data is handled on load.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChecksumMode {
+    /// Fail with [`PkmError::ChecksumMismatch`].
+    Reject,
+    /// Load the entity anyway, leaving the mismatch to be found by the
+    /// format's `is_checksum_valid`.
+    Flag,
+}
+
+/// Contents of a box or party slot.
+#[derive(Debug, PartialEq)]
+pub enum Slot<T> {
+    /// The slot holds no entity.
+    Empty,
+    /// The slot holds a valid entity.
+    Entity(T),
+    /// The slot data does not decrypt to a valid entity, shown in game as a
+    /// Bad Egg.
+    BadEgg(PkmError),
 }
 
 /// Module containing utilities to manipulate Pokemon data files
@@ -115,5 +202,51 @@ pub mod util;
 /// String utilities
 pub mod strings;
 
+/// Generation 1
+pub mod pk1;
+
+/// Generation 2
+pub mod pk2;
+
+/// Generation 3
+pub mod pk3;
+
+/// Generation 3 (Colosseum)
+pub mod ck3;
+
+/// Generation 3 (XD: Gale of Darkness)
+pub mod xk3;
+
+/// Generation 4
+pub mod pk4;
+
+/// Generation 4 (Battle Revolution)
+pub mod bk4;
+
+/// Generation 5
+pub mod pk5;
+
+/// Generation 6
+pub mod pk6;
+
+/// Generation 7
+pub mod pk7;
+
+/// Generation 7 (Let's Go, Pikachu! and Let's Go, Eevee!)
+pub mod pb7;
+
 /// Generation 8
 pub mod pk8;
+
+/// Generation 8 (Brilliant Diamond and Shining Pearl)
+pub mod pb8;
+
+/// Generation 8 (Legends: Arceus)
+pub mod pa8;
+
+/// Generation 9
+pub mod pk9;
+
+/// Automatic format detection
+mod detect;
+pub use detect::{detect, AnyPkm};
@@ -0,0 +1,398 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species, stat::Stat
+    }, legality::tables::location::POKEWALKER_4, pkm::{
+        pk4::{get_ball4, get_location4, split_ball4, split_location4}, strings::string_converter4::{get_string4, set_string4}, util::pokecrypto::SIZE_4STORED
+    }, util::{
+        bigendian, custom_read_write::{read, write}
+    }
+};
+
+pub use crate::pkm::pk4::{FORMAT, MAX_EV, MAX_IV, NICK_LENGTH, OT_LENGTH};
+
+/// Calculates the checksum of a big endian Generation 4 entity.
+pub(crate) fn get_chk_bk4(data: &[u8]) -> u16 {
+    (8..SIZE_4STORED)
+        .step_by(2)
+        .fold(0u16, |chk, i| chk.wrapping_add(bigendian::to_uint16(data, i)))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawBK4 {
+    data: [u8; SIZE_4STORED],
+}
+
+impl RawBK4 {
+    pub fn to_bytes(&self) -> [u8; SIZE_4STORED] { self.data }
+}
+
+/// Generation 4 entity as stored by Pokémon Battle Revolution.
+///
+/// Shares the [`PK4`][`crate::pkm::pk4::PK4`] layout, but is big endian,
+/// unencrypted and only ever stored in box format.
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "big", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct BK4 {
+    pub pid: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk_bk4(&to_array::<SIZE_4STORED>(self.to_bytes()?)?)")]
+    pub checksum: u16,
+
+    // Block A
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    pub ot_friendship: u8,
+    #[deku(
+        reader = "read::read_ability_u8(deku::rest)",
+        writer = "write::write_ability_u8(deku::output, self.ability)"
+    )]
+    pub ability: Ability,
+    pub mark_value: u8,
+    pub language: LanguageID,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+    // 0x24-0x27 Sinnoh Ribbons (Set 1)
+    pub raw_ribbons_sinnoh_1: [u8; 4],
+
+    // Block B
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub iv32: u32,
+    // 0x3C-0x3F Hoenn Ribbons
+    pub raw_ribbons_hoenn: [u8; 4],
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 5)]
+    pub form: u8,
+    #[deku(bits = 2)]
+    pub unk_41: u8,
+    #[deku(bits = 1)]
+    pub shiny_leaf_crown: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_e: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_d: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_c: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_b: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_a: bool,
+    pub unk_42: u16,
+    pub egg_location_pt_hgss: u16,
+    pub met_location_pt_hgss: u16,
+
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub unk_5e: u8,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    // 0x60-0x63 Sinnoh Ribbons (Set 2)
+    pub raw_ribbons_sinnoh_2: [u8; 4],
+    pub unk_64: [u8; 4],
+
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    pub egg_location_dp: u16,
+    pub met_location_dp: u16,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    pub ball_dppt: Ball,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    pub encounter_type: u8,
+    pub ball_hgss: Ball,
+    pub pokeathlon_stat: u8,
+}
+
+impl BK4 {
+    /// Gets the nature, which Generation 4 derives from the PID.
+    pub fn nature(&self) -> Nature { Nature::n((self.pid % 25) as u8).unwrap() }
+
+    /// Checks if the entity originated from Pokémon Diamond, Pearl or
+    /// Platinum.
+    pub fn is_dppt_origin(&self) -> bool { Self::is_dppt(self.version) }
+
+    /// Checks if the entity originated from Pokémon HeartGold or SoulSilver.
+    pub fn is_hgss_origin(&self) -> bool { Self::is_hgss(self.version) }
+
+    /// Checks if the entity was received from the Pokéwalker.
+    pub fn is_pokewalker_origin(&self) -> bool {
+        self.is_hgss_origin() && self.met_location() == POKEWALKER_4 as u16
+    }
+
+    /// Gets the egg location as displayed by the game of origin.
+    pub fn egg_location(&self) -> u16 {
+        get_location4(self.egg_location_pt_hgss, self.egg_location_dp)
+    }
+
+    /// Sets the egg location, keeping the DP and Pt/HGSS fields consistent.
+    pub fn set_egg_location(&mut self, value: u16) {
+        let (pt_hgss, dp) = split_location4(value, Self::is_pt_hgss(self.version));
+        self.egg_location_pt_hgss = pt_hgss;
+        self.egg_location_dp = dp;
+    }
+
+    /// Gets the met location as displayed by the game of origin.
+    pub fn met_location(&self) -> u16 {
+        get_location4(self.met_location_pt_hgss, self.met_location_dp)
+    }
+
+    /// Sets the met location, keeping the DP and Pt/HGSS fields consistent.
+    pub fn set_met_location(&mut self, value: u16) {
+        let (pt_hgss, dp) = split_location4(value, Self::is_pt_hgss(self.version));
+        self.met_location_pt_hgss = pt_hgss;
+        self.met_location_dp = dp;
+    }
+
+    /// Gets the ball the entity was caught in.
+    pub fn ball(&self) -> Ball { get_ball4(self.ball_dppt, self.ball_hgss) }
+
+    /// Sets the ball the entity was caught in, keeping the DPPt and HGSS
+    /// fields consistent.
+    pub fn set_ball(&mut self, value: Ball) {
+        let hgss_capture = self.is_hgss_origin() && !self.fateful_encounter;
+        let (dppt, hgss) = split_ball4(value, hgss_capture);
+        self.ball_dppt = dppt;
+        self.ball_hgss = hgss;
+    }
+
+    /// Gets the IV of a stat, stored past the nickname and egg flags.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (2 + stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = 2 + stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 1) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 1)) | ((value as u32) << 1);
+    }
+    pub fn is_nicknamed(&self) -> bool { self.iv32 & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) { self.iv32 = (self.iv32 & !1) | value as u32; }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location()) }
+
+    /// Reads an entity from stored data.
+    fn read_bytes(data: &[u8; SIZE_4STORED]) -> Result<Self, DekuError> {
+        let (_rest, file) = BK4::read(data.view_bits::<Msb0>(), Endian::Big)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into stored data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Big)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for BK4 {
+    type RawVariant = RawBK4;
+
+    fn get_string(data: &[u16]) -> String { get_string4(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string4(data.as_ref(), max_length)
+    }
+
+    fn build(&mut self) -> Result<RawBK4, PkmError> {
+        self.refresh_checksum();
+        Ok(RawBK4 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_4STORED]> for BK4 {
+    fn from(data: &[u8; SIZE_4STORED]) -> Self { BK4::read_bytes(data).unwrap() }
+}
+
+impl TryFrom<&[u8]> for BK4 {
+    type Error = PkmError;
+
+    /// Parses stored data, rejecting data whose stored checksum does not
+    /// match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let array: [u8; SIZE_4STORED] = to_array(data.to_vec())?;
+        let stored = bigendian::to_uint16(&array, 0x06);
+        let calculated = get_chk_bk4(&array);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(BK4::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::{pk4::PK4, util::testutil::write_string};
+    use alloc::vec;
+
+    fn chimchar() -> [u8; SIZE_4STORED] {
+        let mut data = [0u8; SIZE_4STORED];
+        data[0x00..0x04].copy_from_slice(&0x2E4A_91C3u32.to_be_bytes());
+        data[0x08..0x0A].copy_from_slice(&(Species::Chimchar as u16).to_be_bytes());
+        data[0x0C..0x0E].copy_from_slice(&13579u16.to_be_bytes());
+        data[0x10..0x14].copy_from_slice(&135u32.to_be_bytes());
+        data[0x15] = Ability::Blaze as u8;
+        data[0x17] = LanguageID::English as u8;
+        data[0x28..0x2A].copy_from_slice(&(Move::Scratch as u16).to_be_bytes());
+        data[0x38..0x3C].copy_from_slice(&0xF800_007Du32.to_be_bytes());
+        data[0x40] = 0x80 | (1 << 5) | 2; // Fateful, Female, Form 2
+        write_string(&mut data, 0x48, set_string4("Chimchar", 10), Endian::Big);
+        data[0x5F] = GameVersion::PT as u8;
+        write_string(&mut data, 0x68, set_string4("Dawn", 10), Endian::Big);
+        data[0x7E..0x80].copy_from_slice(&2011u16.to_be_bytes());
+        data[0x80..0x82].copy_from_slice(&16u16.to_be_bytes());
+        data[0x82] = 0x21;
+        data[0x83] = Ball::Premier as u8;
+        data[0x84] = (5 << 1) | 1;
+        let chk = get_chk_bk4(&data);
+        data[0x06..0x08].copy_from_slice(&chk.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn bk4_get_test() {
+        let chimchar = BK4::from(&chimchar());
+        assert_eq!(Species::Chimchar, chimchar.species);
+        assert_eq!(13579, chimchar.tid);
+        assert_eq!(135, chimchar.exp);
+        assert_eq!(Ability::Blaze, chimchar.ability);
+        assert_eq!(Move::Scratch, chimchar.move1);
+        assert!(chimchar.is_nicknamed());
+        assert!(!chimchar.is_egg());
+        assert_eq!(
+            [31, 0, 0, 0, 0, 31],
+            [Stat::HP, Stat::Atk, Stat::Def, Stat::Spe, Stat::SpA, Stat::SpD]
+                .map(|stat| chimchar.iv(stat))
+        );
+        assert!(chimchar.fateful_encounter);
+        assert_eq!(Gender::Female, chimchar.gender);
+        assert_eq!(Nature::Modest, chimchar.nature());
+        assert_eq!(2, chimchar.form);
+        assert_eq!("Chimchar", chimchar.nickname);
+        assert_eq!("Dawn", chimchar.ot_name);
+        assert_eq!(GameVersion::PT, chimchar.version);
+        assert_eq!(2011, chimchar.egg_location());
+        assert_eq!(16, chimchar.met_location());
+        assert_eq!((1, 2), (chimchar.pkrs_strain, chimchar.pkrs_days));
+        assert_eq!(Ball::Premier, chimchar.ball());
+        assert_eq!(5, chimchar.met_level);
+        assert_eq!(Gender::Female, chimchar.ot_gender);
+        assert_eq!(4, chimchar.generation());
+        assert!(chimchar.is_dppt_origin());
+        assert!(!chimchar.is_pokewalker_origin());
+    }
+
+    #[test]
+    fn bk4_sample_test() {
+        let bk4 = BK4::from(include_bytes!("util/tests/data/Sample.bk4"));
+        let pk4 = PK4::from(include_bytes!("util/tests/data/Sample.pk4"));
+        assert_eq!(Species::Cyndaquil, bk4.species);
+        assert_eq!(pk4.pid, bk4.pid);
+        assert_eq!(pk4.tid, bk4.tid);
+        assert_eq!(pk4.exp, bk4.exp);
+        assert_eq!(pk4.ability, bk4.ability);
+        assert_eq!(pk4.move1, bk4.move1);
+        for stat in [Stat::HP, Stat::Atk, Stat::Def, Stat::Spe, Stat::SpA, Stat::SpD] {
+            assert_eq!(pk4.iv(stat), bk4.iv(stat));
+        }
+        assert_eq!((pk4.is_egg(), pk4.is_nicknamed()), (bk4.is_egg(), bk4.is_nicknamed()));
+        assert_eq!(pk4.gender, bk4.gender);
+        assert_eq!(pk4.nickname, bk4.nickname);
+        assert_eq!(pk4.ot_name, bk4.ot_name);
+        assert_eq!(pk4.version, bk4.version);
+        assert_eq!(pk4.met_location(), bk4.met_location());
+        assert_eq!(pk4.egg_location(), bk4.egg_location());
+        assert_eq!(pk4.ball(), bk4.ball());
+        assert_eq!(pk4.met_level, bk4.met_level);
+        assert_eq!(pk4.ot_gender, bk4.ot_gender);
+        assert_eq!((pk4.pkrs_strain, pk4.pkrs_days), (bk4.pkrs_strain, bk4.pkrs_days));
+        assert_eq!(get_chk_bk4(include_bytes!("util/tests/data/Sample.bk4")), bk4.checksum);
+    }
+
+    #[test]
+    fn bk4_try_from_test() {
+        let mut data = chimchar();
+        assert_eq!(BK4::from(&data), BK4::try_from(&data[..]).unwrap());
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![SIZE_4STORED], actual: 0x100 }),
+            BK4::try_from(&[0u8; 0x100][..])
+        );
+        data[0x09] ^= 1;
+        assert!(matches!(BK4::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn bk4_round_trip_test() {
+        let data = chimchar();
+        let mut chimchar = BK4::from(&data);
+        assert_eq!(data, chimchar.build().unwrap().to_bytes());
+
+        chimchar.set_iv(Stat::Atk, 31);
+        chimchar.set_met_location(1000);
+        let rebuilt = chimchar.build().unwrap().to_bytes();
+        assert_eq!(0xF800_0FFD, bigendian::to_uint32(&rebuilt, 0x38));
+        assert_eq!(1000, bigendian::to_uint16(&rebuilt, 0x46));
+        assert_eq!(3002, bigendian::to_uint16(&rebuilt, 0x80));
+        assert_eq!(get_chk_bk4(&rebuilt), bigendian::to_uint16(&rebuilt, 0x06));
+    }
+}
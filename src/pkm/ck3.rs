@@ -0,0 +1,300 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{format, string::String, vec::Vec};
+use deku::prelude::*;
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ball::Ball, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species
+    }, pkm::{
+        strings::string_converter3::{get_string3_gc, set_string3_gc}, util::{
+            pokecrypto::SIZE_3CSTORED, species_converter::{get_g3_species, get_g4_species}
+        }
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 3;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 255;
+pub const OT_LENGTH: usize = 10;
+pub const NICK_LENGTH: usize = 10;
+
+/// Heart gauge value of a Shadow Pokémon that has been purified.
+pub const PURIFIED: i32 = -100;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawCK3 {
+    data: [u8; SIZE_3CSTORED],
+}
+
+impl RawCK3 {
+    pub fn to_bytes(&self) -> [u8; SIZE_3CSTORED] { self.data }
+}
+
+/// Generation 3 entity as stored by Pokémon Colosseum.
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "big")]
+#[repr(C)]
+pub struct CK3 {
+    #[deku(update = "get_g3_species(self.species)")]
+    pub species_id3: u16,
+    #[deku(skip, default = "get_g4_species(*species_id3)")]
+    pub species: Species,
+    pub unk_02: u16,
+    pub pid: u32,
+    #[deku(
+        reader = "read::read_gc_version(deku::rest)",
+        writer = "write::write_gc_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    pub current_region: u8,
+    pub original_region: u8,
+    #[deku(
+        reader = "read::read_language_gc(deku::rest)",
+        writer = "write::write_language_gc(deku::output, self.language)"
+    )]
+    pub language: LanguageID,
+    pub met_location: u16,
+    pub met_level: u8,
+    pub ball: Ball,
+    pub ot_gender: Gender,
+    pub unk_11: [u8; 3],
+    pub sid: u16,
+    pub tid: u16,
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.nickname_copy, NICK_LENGTH))"
+    )]
+    pub nickname_copy: String,
+    pub unk_5a: u16,
+    pub exp: u32,
+    pub stat_level: u8,
+    pub unk_61: [u8; 23],
+
+    // Moves
+    pub move1: Move,
+    pub move1_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move2: Move,
+    pub move2_pp: u8,
+    pub move_2_pp_ups: u8,
+    pub move3: Move,
+    pub move3_pp: u8,
+    pub move_3_pp_ups: u8,
+    pub move4: Move,
+    pub move4_pp: u8,
+    pub move_4_pp_ups: u8,
+    /// Item index in the Generation 3 item list.
+    pub held_item: u16,
+
+    // Party Stats
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    pub stat_spe: u16,
+
+    // EVs, IVs & Friendship
+    pub ev_hp: u16,
+    pub ev_atk: u16,
+    pub ev_def: u16,
+    pub ev_spa: u16,
+    pub ev_spd: u16,
+    pub ev_spe: u16,
+    pub iv_hp: u16,
+    pub iv_atk: u16,
+    pub iv_def: u16,
+    pub iv_spa: u16,
+    pub iv_spd: u16,
+    pub iv_spe: u16,
+    pub ot_friendship: u16,
+
+    // Contest Stats & Ribbons
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub ribbon_count_g3_cool: u8,
+    pub ribbon_count_g3_beauty: u8,
+    pub ribbon_count_g3_cute: u8,
+    pub ribbon_count_g3_smart: u8,
+    pub ribbon_count_g3_tough: u8,
+    pub cnt_sheen: u8,
+    pub ribbon_champion_g3: bool,
+    pub ribbon_winning: bool,
+    pub ribbon_victory: bool,
+    pub ribbon_artist: bool,
+    pub ribbon_effort: bool,
+    pub ribbon_champion_battle: bool,
+    pub ribbon_champion_regional: bool,
+    pub ribbon_champion_national: bool,
+    pub ribbon_country: bool,
+    pub ribbon_national: bool,
+    pub ribbon_earth: bool,
+    pub ribbon_world: bool,
+    #[deku(bits = 3)]
+    pub unk_c9: u8,
+    /// Obedience flag for event-only species.
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    #[deku(bits = 4)]
+    pub unused_c9: u8,
+    pub pkrs_strain: u8,
+    pub is_egg: bool,
+    pub ability_bit: bool,
+    pub invalid: u8,
+    pub unk_ce: u8,
+    pub mark_value: u8,
+    pub pkrs_days: i8,
+    pub unk_d1: [u8; 7],
+
+    // Shadow Data
+    pub shadow_id: u16,
+    pub unk_da: u16,
+    /// Heart gauge, counting down to [`PURIFIED`].
+    pub purification: i32,
+    #[deku(count = "SIZE_3CSTORED - 0xE0")]
+    pub unk_e0: Vec<u8>,
+    #[deku(skip, default = "Self::get_generation(*version, *met_location)")]
+    pub generation: i32,
+}
+
+impl CK3 {
+    /// Gets the nature, which Generation 3 derives from the PID.
+    pub fn nature(&self) -> Nature { Nature::n((self.pid % 25) as u8).unwrap() }
+
+    /// Checks if the entity is a Shadow Pokémon that has not been purified.
+    pub fn is_shadow(&self) -> bool { self.shadow_id != 0 && self.purification != PURIFIED }
+
+    /// Checks if the entity originated from Pokémon Colosseum or XD.
+    pub fn is_cxd_origin(&self) -> bool { self.version == GameVersion::CXD }
+}
+
+impl PKM for CK3 {
+    type RawVariant = RawCK3;
+
+    fn get_string(data: &[u16]) -> String { get_string3_gc(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string3_gc(data.as_ref(), max_length)
+    }
+
+    fn build(&mut self) -> Result<RawCK3, PkmError> {
+        self.refresh_checksum();
+        Ok(RawCK3 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_3CSTORED]> for CK3 {
+    fn from(data: &[u8; SIZE_3CSTORED]) -> Self {
+        let (_rest, file) = CK3::from_bytes((data.as_ref(), 0)).unwrap();
+        file
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        game::enums::{gc_version::GCVersion, language_gc::LanguageGC}, pkm::util::testutil::write_string
+    };
+    use core::convert::TryFrom;
+    use deku::ctx::Endian;
+
+    fn makuhita() -> [u8; SIZE_3CSTORED] {
+        let mut data = [0u8; SIZE_3CSTORED];
+        data[0x00..0x02].copy_from_slice(&335u16.to_be_bytes()); // Makuhita
+        data[0x04..0x08].copy_from_slice(&0x1234_5678u32.to_be_bytes());
+        data[0x08] = GCVersion::CXD as u8;
+        data[0x0B] = LanguageGC::German as u8;
+        data[0x0C..0x0E].copy_from_slice(&30u16.to_be_bytes());
+        data[0x0E] = 30;
+        data[0x0F] = Ball::Poke as u8;
+        data[0x14..0x16].copy_from_slice(&54321u16.to_be_bytes());
+        data[0x16..0x18].copy_from_slice(&12345u16.to_be_bytes());
+        write_string(&mut data, 0x18, set_string3_gc("Wes", 10), Endian::Big);
+        write_string(&mut data, 0x2E, set_string3_gc("Makuhita", 10), Endian::Big);
+        write_string(&mut data, 0x44, set_string3_gc("Makuhita", 10), Endian::Big);
+        data[0x5C..0x60].copy_from_slice(&23000u32.to_be_bytes());
+        data[0x60] = 30;
+        data[0x78..0x7A].copy_from_slice(&(Move::BellyDrum as u16).to_be_bytes());
+        data[0x7A] = 10;
+        data[0x7B] = 3;
+        data[0x8A..0x8C].copy_from_slice(&90u16.to_be_bytes());
+        data[0xA4..0xA6].copy_from_slice(&31u16.to_be_bytes());
+        data[0xB0..0xB2].copy_from_slice(&70u16.to_be_bytes());
+        data[0xBD] = 1;
+        data[0xC9] = 1 << 4;
+        data[0xD8..0xDA].copy_from_slice(&7u16.to_be_bytes());
+        data[0xDC..0xE0].copy_from_slice(&3000i32.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn ck3_get_test() {
+        let makuhita = CK3::from(&makuhita());
+        assert_eq!(Species::Makuhita, makuhita.species);
+        assert_eq!(0x1234_5678, makuhita.pid);
+        assert_eq!(GameVersion::CXD, makuhita.version);
+        assert_eq!(LanguageID::German, makuhita.language);
+        assert_eq!(30, makuhita.met_location);
+        assert_eq!((54321, 12345), (makuhita.sid, makuhita.tid));
+        assert_eq!("Wes", makuhita.ot_name);
+        assert_eq!("Makuhita", makuhita.nickname);
+        assert_eq!("Makuhita", makuhita.nickname_copy);
+        assert_eq!(23000, makuhita.exp);
+        assert_eq!(Move::BellyDrum, makuhita.move1);
+        assert_eq!((10, 3), (makuhita.move1_pp, makuhita.move_1_pp_ups));
+        assert_eq!(90, makuhita.stat_hp_current);
+        assert_eq!(31, makuhita.iv_hp);
+        assert_eq!(70, makuhita.ot_friendship);
+        assert!(makuhita.ribbon_champion_g3);
+        assert!(makuhita.fateful_encounter);
+        assert_eq!(7, makuhita.shadow_id);
+        assert_eq!(3000, makuhita.purification);
+        assert!(makuhita.is_shadow());
+        assert!(makuhita.is_cxd_origin());
+        assert_eq!(3, makuhita.generation);
+    }
+
+    #[test]
+    fn ck3_round_trip_test() {
+        let data = makuhita();
+        let mut makuhita = CK3::from(&data);
+        assert_eq!(data, makuhita.build().unwrap().to_bytes());
+
+        makuhita.purification = PURIFIED;
+        makuhita.version = GameVersion::R;
+        makuhita.language = LanguageID::Spanish;
+        let rebuilt = makuhita.build().unwrap().to_bytes();
+        assert_eq!(GCVersion::R as u8, rebuilt[0x08]);
+        assert_eq!(LanguageGC::Spanish as u8, rebuilt[0x0B]);
+        assert!(!CK3::from(&rebuilt).is_shadow());
+    }
+
+    #[test]
+    fn ck3_unknown_origin_test() {
+        let mut data = makuhita();
+        data[0x08] = 0x0C;
+        assert!(matches!(CK3::try_from(&data[..]), Err(DekuError::Parse(_))));
+
+        let mut data = makuhita();
+        data[0x0B] = 0x08;
+        assert!(matches!(CK3::try_from(&data[..]), Err(DekuError::Parse(_))));
+    }
+}
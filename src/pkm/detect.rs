@@ -0,0 +1,414 @@
+use crate::{
+    game::enums::{
+        game_version::GameVersion, move_type::get_move_type_g12, moves::Move, species::Species
+    }, legality::tables::tables_1::{
+        MAX_MOVE_ID_1, MAX_MOVE_ID_2, MAX_PP_12, MAX_SPECIES_ID_2, TYPES_RBY
+    }, pkm::{
+        bk4::{self, BK4}, ck3::{self, CK3}, pa8::{self, PA8}, pb7::{self, PB7}, pb8::{self, PB8}, pk1::{self, LIST_TERMINATOR, PK1}, pk2::{self, PK2}, pk3::{self, PK3}, pk4::{self, PK4}, pk5::{self, PK5}, pk6::{self, PK6}, pk7::{self, PK7}, pk8::{self, PK8}, pk9::{self, PK9}, util::{experience::get_level, pokecrypto::*, species_converter::get_national9}, xk3::{self, XK3}, PKM
+    }
+};
+use bitconv::{endian::Little, to_uint16};
+use core::convert::TryFrom;
+use deku::DekuContainerRead;
+
+/// An entity of any of the supported formats.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnyPkm {
+    PK1(PK1),
+    PK2(PK2),
+    PK3(PK3),
+    CK3(CK3),
+    XK3(XK3),
+    PK4(PK4),
+    BK4(BK4),
+    PK5(PK5),
+    PK6(PK6),
+    PK7(PK7),
+    PB7(PB7),
+    PK8(PK8),
+    PB8(PB8),
+    PA8(PA8),
+    PK9(PK9),
+}
+
+impl AnyPkm {
+    /// Gets the generation of the format the entity is stored in.
+    pub fn format(&self) -> i32 {
+        match self {
+            AnyPkm::PK1(_) => pk1::FORMAT,
+            AnyPkm::PK2(_) => pk2::FORMAT,
+            AnyPkm::PK3(_) => pk3::FORMAT,
+            AnyPkm::CK3(_) => ck3::FORMAT,
+            AnyPkm::XK3(_) => xk3::FORMAT,
+            AnyPkm::PK4(_) => pk4::FORMAT,
+            AnyPkm::BK4(_) => bk4::FORMAT,
+            AnyPkm::PK5(_) => pk5::FORMAT,
+            AnyPkm::PK6(_) => pk6::FORMAT,
+            AnyPkm::PK7(_) => pk7::FORMAT,
+            AnyPkm::PB7(_) => pb7::FORMAT,
+            AnyPkm::PK8(_) => pk8::FORMAT,
+            AnyPkm::PB8(_) => pb8::FORMAT,
+            AnyPkm::PA8(_) => pa8::FORMAT,
+            AnyPkm::PK9(_) => pk9::FORMAT,
+        }
+    }
+
+    /// Gets the species of the entity.
+    pub fn species(&self) -> Species {
+        match self {
+            AnyPkm::PK1(pkm) => pkm.species,
+            AnyPkm::PK2(pkm) => pkm.species,
+            AnyPkm::PK3(pkm) => pkm.species,
+            AnyPkm::CK3(pkm) => pkm.species,
+            AnyPkm::XK3(pkm) => pkm.species,
+            AnyPkm::PK4(pkm) => pkm.species,
+            AnyPkm::BK4(pkm) => pkm.species,
+            AnyPkm::PK5(pkm) => pkm.species,
+            AnyPkm::PK6(pkm) => pkm.species,
+            AnyPkm::PK7(pkm) => pkm.species,
+            AnyPkm::PB7(pkm) => pkm.species,
+            AnyPkm::PK8(pkm) => pkm.species,
+            AnyPkm::PB8(pkm) => pkm.species,
+            AnyPkm::PA8(pkm) => pkm.species,
+            AnyPkm::PK9(pkm) => pkm.species,
+        }
+    }
+}
+
+/// Detects the format of raw entity data, either encrypted or decrypted, and
+/// stored or party sized.
+///
+/// The length of the data narrows down the candidate formats, which are then
+/// told apart by their checksum, version and species. Data whose checksum is
+/// wrong, that cannot be parsed, or that holds no species, such as an empty
+/// slot, is not detected. Generation 1 and 2 data has no checksum, so its
+/// level, types and moves have to be plausible instead.
+pub fn detect(data: &[u8]) -> Option<AnyPkm> {
+    let pkm = match data.len() {
+        SIZE_1PARTY | SIZE_1STORED => parse::<PK1, SIZE_1PARTY>(data)
+            .filter(|pkm| is_plausible1(pkm, data.len() == SIZE_1PARTY))
+            .map(AnyPkm::PK1),
+        SIZE_1ULIST | SIZE_1JLIST => detect_list1(data),
+        SIZE_2PARTY | SIZE_2STORED => {
+            parse::<PK2, SIZE_2PARTY>(data).filter(is_plausible2).map(AnyPkm::PK2)
+        }
+        SIZE_2ULIST | SIZE_2JLIST => detect_list2(data),
+        SIZE_3PARTY | SIZE_3STORED => PK3::try_from(data).ok().map(AnyPkm::PK3),
+        SIZE_3CSTORED => parse::<CK3, SIZE_3CSTORED>(data).map(AnyPkm::CK3),
+        SIZE_3XSTORED => parse::<XK3, SIZE_3XSTORED>(data).map(AnyPkm::XK3),
+        SIZE_4PARTY | SIZE_4STORED | SIZE_5PARTY => detect45(data),
+        SIZE_6PARTY | SIZE_6STORED => detect67(data),
+        SIZE_8PARTY | SIZE_8STORED => detect8(data),
+        SIZE_8APARTY | SIZE_8ASTORED => detect8a(data),
+        _ => None,
+    };
+    pkm.filter(|pkm| pkm.species() != Species::None)
+}
+
+/// Parses decrypted data, padding stored data to the party size `P`.
+fn parse<T, const P: usize>(data: &[u8]) -> Option<T>
+where
+    T: for<'a> DekuContainerRead<'a>,
+{
+    let mut party = [0u8; P];
+    party[..data.len()].copy_from_slice(data);
+    T::from_bytes((&party, 0)).ok().map(|(_rest, file)| file)
+}
+
+fn is_checksum_valid<const P: usize>(data: &[u8; P], party_start: usize) -> bool {
+    get_chk(data, party_start) == to_uint16::<Little>(data, 0x06)
+}
+
+/// Decrypts stored (`S`) or party (`P`) sized data into a party sized buffer.
+fn decrypt<const S: usize, const P: usize>(
+    data: &[u8],
+    decrypt_stored: fn(&mut [u8; S]),
+    decrypt_party: fn(&mut [u8; P]),
+) -> [u8; P] {
+    let mut party = [0u8; P];
+    if data.len() == P {
+        party.copy_from_slice(data);
+        decrypt_party(&mut party);
+    } else {
+        let mut stored = [0u8; S];
+        stored.copy_from_slice(data);
+        decrypt_stored(&mut stored);
+        party[..S].copy_from_slice(&stored);
+    }
+    party
+}
+
+/// Growth rates used by Generations 1 and 2, see [`get_level`].
+const GROWTH_RATES_12: [u32; 4] = [0, 3, 4, 5];
+
+/// Checks Generation 1 data against what the games could have written, as
+/// it has no checksum and nearly any bytes parse.
+fn is_plausible1(pkm: &PK1, party: bool) -> bool {
+    let types = [get_move_type_g12(pkm.type_a), get_move_type_g12(pkm.type_b)];
+    let types_match = TYPES_RBY
+        .get(pkm.species as usize)
+        .is_some_and(|&[a, b]| types == [Some(a), Some(b)] || types == [Some(b), Some(a)]);
+    types_match
+        && is_level_plausible12(pkm.box_level, pkm.exp)
+        && (!party || pkm.stat_level == pkm.box_level)
+        && are_moves_plausible12(
+            &[
+                (pkm.move1, pkm.move_1_pp_ups, pkm.move1_pp),
+                (pkm.move2, pkm.move_2_pp_ups, pkm.move2_pp),
+                (pkm.move3, pkm.move_3_pp_ups, pkm.move3_pp),
+                (pkm.move4, pkm.move_4_pp_ups, pkm.move4_pp),
+            ],
+            MAX_MOVE_ID_1,
+        )
+}
+
+/// Checks Generation 2 data against what the games could have written, as
+/// it has no checksum and nearly any bytes parse.
+fn is_plausible2(pkm: &PK2) -> bool {
+    pkm.species as u16 <= MAX_SPECIES_ID_2
+        && is_level_plausible12(pkm.stat_level, pkm.exp)
+        && are_moves_plausible12(
+            &[
+                (pkm.move1, pkm.move_1_pp_ups, pkm.move1_pp),
+                (pkm.move2, pkm.move_2_pp_ups, pkm.move2_pp),
+                (pkm.move3, pkm.move_3_pp_ups, pkm.move3_pp),
+                (pkm.move4, pkm.move_4_pp_ups, pkm.move4_pp),
+            ],
+            MAX_MOVE_ID_2,
+        )
+}
+
+/// The species' growth rate is not known without Generation 1 and 2 personal
+/// data, so the level only has to match the experience for one of them.
+fn is_level_plausible12(level: u8, exp: u32) -> bool {
+    (1..=100).contains(&level)
+        && GROWTH_RATES_12.iter().any(|&growth| get_level(exp, growth) == Some(level as u32))
+}
+
+/// Empty move slots hold no PP, and the others stay within the game's moves
+/// and the highest PP any of them can reach.
+fn are_moves_plausible12(moves: &[(Move, u8, u8); 4], max_move: u16) -> bool {
+    moves.iter().all(|&(move_id, pp_ups, pp)| match move_id {
+        Move::None => pp_ups == 0 && pp == 0,
+        _ => move_id as u16 <= max_move && pp <= MAX_PP_12,
+    })
+}
+
+fn is_list(data: &[u8]) -> bool { data[0] == 1 && data[2] == LIST_TERMINATOR }
+
+fn detect_list1(data: &[u8]) -> Option<AnyPkm> {
+    parse::<PK1, SIZE_1PARTY>(&data[3..][..SIZE_1PARTY])
+        .filter(|pkm| is_list(data) && is_plausible1(pkm, true))?;
+    let file = match data.len() {
+        SIZE_1ULIST => PK1::from(&<[u8; SIZE_1ULIST]>::try_from(data).ok()?),
+        _ => PK1::from(&<[u8; SIZE_1JLIST]>::try_from(data).ok()?),
+    };
+    Some(AnyPkm::PK1(file))
+}
+
+fn detect_list2(data: &[u8]) -> Option<AnyPkm> {
+    parse::<PK2, SIZE_2PARTY>(&data[3..][..SIZE_2PARTY])
+        .filter(|pkm| is_list(data) && is_plausible2(pkm))?;
+    let file = match data.len() {
+        SIZE_2ULIST => PK2::from(&<[u8; SIZE_2ULIST]>::try_from(data).ok()?),
+        _ => PK2::from(&<[u8; SIZE_2JLIST]>::try_from(data).ok()?),
+    };
+    Some(AnyPkm::PK2(file))
+}
+
+fn detect45(data: &[u8]) -> Option<AnyPkm> {
+    if data.len() == SIZE_5PARTY {
+        return PK5::try_from(data).ok().map(AnyPkm::PK5);
+    }
+
+    let party =
+        decrypt::<SIZE_4STORED, SIZE_4PARTY>(data, decrypt_if_encrypted45, decrypt_if_encrypted45);
+    if !is_checksum_valid(&party, SIZE_4STORED) {
+        // Battle Revolution data is big endian and never encrypted.
+        return BK4::try_from(data).ok().map(AnyPkm::BK4);
+    }
+    // Gen 5 data has a later version, or a met location past 0x3000 when it
+    // was transferred from Gen 4.
+    if data.len() == SIZE_4PARTY
+        || (party[0x5F] < GameVersion::W as u8 && to_uint16::<Little>(&party, 0x80) < 0x3000)
+    {
+        PK4::try_from(data).ok().map(AnyPkm::PK4)
+    } else {
+        PK5::try_from(data).ok().map(AnyPkm::PK5)
+    }
+}
+
+fn detect67(data: &[u8]) -> Option<AnyPkm> {
+    let party =
+        decrypt::<SIZE_6STORED, SIZE_6PARTY>(data, decrypt_if_encrypted67, decrypt_if_encrypted67);
+    if !is_checksum_valid(&party, SIZE_6STORED) {
+        return None;
+    }
+    let version = GameVersion::n(party[0xDF] as i32)?;
+    let met_location = to_uint16::<Little>(&party, 0xDA);
+    if data.len() == SIZE_6PARTY && PB7::is_gg(version, met_location) {
+        PB7::try_from(data).ok().map(AnyPkm::PB7)
+    } else if PK7::is_gen7(version, met_location)
+        || to_uint16::<Little>(&party, 0x08) > Species::Volcanion as u16
+    {
+        PK7::try_from(data).ok().map(AnyPkm::PK7)
+    } else {
+        PK6::try_from(data).ok().map(AnyPkm::PK6)
+    }
+}
+
+fn detect8(data: &[u8]) -> Option<AnyPkm> {
+    let party =
+        decrypt::<SIZE_8STORED, SIZE_8PARTY>(data, decrypt_if_encrypted8, decrypt_if_encrypted8);
+    if !is_checksum_valid(&party, SIZE_8STORED) {
+        return None;
+    }
+    let version = GameVersion::n(party[0xDE] as i32)?;
+    if PB8::is_bdsp(version) {
+        PB8::try_from(data).ok().map(AnyPkm::PB8)
+    } else if PK9::is_sv(version)
+        || get_national9(to_uint16::<Little>(&party, 0x08)) > Species::Enamorus
+    {
+        PK9::try_from(data).ok().map(AnyPkm::PK9)
+    } else {
+        PK8::try_from(data).ok().map(AnyPkm::PK8)
+    }
+}
+
+fn detect8a(data: &[u8]) -> Option<AnyPkm> { PA8::try_from(data).ok().map(AnyPkm::PA8) }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Writes a valid little endian checksum over decrypted data.
+    fn with_checksum<const N: usize>(mut data: [u8; N], party_start: usize) -> [u8; N] {
+        let chk = get_chk(&data, party_start);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn detect8_test() {
+        let pk8 = include_bytes!("util/tests/data/Orbeetle.pk8");
+        let orbeetle = PK8::from(pk8);
+        assert_eq!(Some(AnyPkm::PK8(orbeetle.clone())), detect(pk8));
+        assert_eq!(
+            Some(AnyPkm::PK8(orbeetle)),
+            detect(include_bytes!("util/tests/data/Orbeetle.ek8"))
+        );
+        match detect(&pk8[..SIZE_8STORED]) {
+            Some(AnyPkm::PK8(stored)) => assert!(!stored.has_party_stats),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let mut data = *pk8;
+        data[0xDE] = GameVersion::BD as u8;
+        assert!(matches!(detect(&with_checksum(data, SIZE_8STORED)), Some(AnyPkm::PB8(_))));
+        data[0xDE] = GameVersion::SL as u8;
+        let data = with_checksum(data, SIZE_8STORED);
+        assert!(matches!(detect(&data), Some(AnyPkm::PK9(_))));
+        assert_eq!(9, detect(&data).unwrap().format());
+
+        // Paldea species are stored by their internal index
+        let mut data = *pk8;
+        data[0x08..0x0A].copy_from_slice(&917u16.to_le_bytes());
+        match detect(&with_checksum(data, SIZE_8STORED)) {
+            Some(AnyPkm::PK9(pk9)) => assert_eq!(Species::Dudunsparce, pk9.species),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let mut corrupted = *pk8;
+        corrupted[0x0C] ^= 0xFF;
+        assert_eq!(None, detect(&corrupted));
+    }
+
+    #[test]
+    fn detect67_test() {
+        let mut data = [0u8; SIZE_6PARTY];
+        data[0x08..0x0A].copy_from_slice(&(Species::Froakie as u16).to_le_bytes());
+        data[0xDF] = GameVersion::X as u8;
+        assert!(matches!(detect(&with_checksum(data, SIZE_6STORED)), Some(AnyPkm::PK6(_))));
+        data[0x08..0x0A].copy_from_slice(&(Species::Rowlet as u16).to_le_bytes());
+        assert!(matches!(detect(&with_checksum(data, SIZE_6STORED)), Some(AnyPkm::PK7(_))));
+        data[0xDF] = GameVersion::GP as u8;
+        let data = with_checksum(data, SIZE_6STORED);
+        assert!(matches!(detect(&data), Some(AnyPkm::PB7(_))));
+        // Let's Go data only exists party sized
+        assert!(matches!(detect(&data[..SIZE_6STORED]), Some(AnyPkm::PK7(_))));
+
+        let mut encrypted = data;
+        assert_eq!(detect(&data), detect(&encrypt_array67(&mut encrypted)));
+    }
+
+    #[test]
+    fn detect45_test() {
+        let mut data = [0u8; SIZE_4STORED];
+        data[0x08..0x0A].copy_from_slice(&(Species::Cyndaquil as u16).to_le_bytes());
+        data[0x5F] = GameVersion::HG as u8;
+        assert!(matches!(detect(&with_checksum(data, SIZE_4STORED)), Some(AnyPkm::PK4(_))));
+        data[0x5F] = GameVersion::W as u8;
+        assert!(matches!(detect(&with_checksum(data, SIZE_4STORED)), Some(AnyPkm::PK5(_))));
+        data[0x5F] = GameVersion::HG as u8;
+        data[0x80..0x82].copy_from_slice(&30001u16.to_le_bytes()); // Poké Transfer
+        assert!(matches!(detect(&with_checksum(data, SIZE_4STORED)), Some(AnyPkm::PK5(_))));
+
+        let mut data = [0u8; SIZE_4STORED];
+        data[0x08..0x0C].copy_from_slice(&[0x00, 0xFF, 0x00, 0x01]);
+        let chk = bk4::get_chk_bk4(&data);
+        data[0x06..0x08].copy_from_slice(&chk.to_be_bytes());
+        assert!(matches!(detect(&data), Some(AnyPkm::BK4(_))));
+    }
+
+    #[test]
+    fn detect12_test() {
+        let mut pk1 = [0u8; SIZE_1PARTY];
+        pk1[0x00] = 0x99; // Bulbasaur
+        pk1[0x03] = 5;
+        pk1[0x05..0x07].copy_from_slice(&[0x16, 0x03]); // Grass, Poison
+        pk1[0x08] = Move::Tackle as u8;
+        pk1[0x0E..0x11].copy_from_slice(&135u32.to_be_bytes()[1..]);
+        pk1[0x1D] = 35;
+        pk1[0x21] = 5;
+        assert!(matches!(detect(&pk1), Some(AnyPkm::PK1(_))));
+        assert!(matches!(detect(&pk1[..SIZE_1STORED]), Some(AnyPkm::PK1(_))));
+
+        let mut pk2 = [0u8; SIZE_2PARTY];
+        pk2[0x00] = Species::Chikorita as u8;
+        pk2[0x02] = Move::Tackle as u8;
+        pk2[0x08..0x0B].copy_from_slice(&135u32.to_be_bytes()[1..]);
+        pk2[0x17] = 35;
+        pk2[0x1F] = 5;
+        assert!(matches!(detect(&pk2), Some(AnyPkm::PK2(_))));
+
+        // Nearly any bytes parse, so implausible values are rejected
+        assert_eq!(None, detect(&[0x99; SIZE_1PARTY]));
+        assert_eq!(None, detect(&[0x99; SIZE_2PARTY]));
+        for (offset, value) in [(0x03, 6), (0x06, 0x14), (0x1D, 62), (0x1E, 1), (0x21, 6)] {
+            let mut data = pk1;
+            data[offset] = value;
+            assert_eq!(None, detect(&data), "PK1 offset {:#04X}", offset);
+        }
+        for (offset, value) in [(0x00, 252), (0x03, 252), (0x17, 62), (0x1F, 101)] {
+            let mut data = pk2;
+            data[offset] = value;
+            assert_eq!(None, detect(&data), "PK2 offset {:#04X}", offset);
+        }
+    }
+
+    #[test]
+    fn detect_length_test() {
+        // Empty slots hold no species
+        assert_eq!(None, detect(&[0u8; SIZE_3STORED]));
+        assert_eq!(None, detect(&[0u8; SIZE_2PARTY]));
+        assert_eq!(None, detect(&[0u8; SIZE_8STORED]));
+        assert_eq!(None, detect(&with_checksum([0u8; SIZE_6PARTY], SIZE_6STORED)));
+
+        let mut pk2 = [0u8; SIZE_2PARTY];
+        pk2[0] = Species::Chikorita as u8;
+        pk2[0x1F] = 1;
+        assert!(matches!(detect(&pk2), Some(AnyPkm::PK2(_))));
+        assert_eq!(None, detect(&[0u8; 0x100]));
+        assert_eq!(None, detect(&[]));
+    }
+}
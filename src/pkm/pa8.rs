@@ -0,0 +1,853 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species, stat::Stat
+    }, pkm::{
+        strings::string_converter::{get_string7, set_string7b}, util::pokecrypto::{decrypt_if_encrypted8a, get_chk, SIZE_8APARTY, SIZE_8ASTORED}
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 8;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 252;
+pub const MAX_GV: i32 = 10;
+pub const OT_LENGTH: usize = 12;
+pub const NICK_LENGTH: usize = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPA8 {
+    data: [u8; SIZE_8APARTY],
+}
+
+impl RawPA8 {
+    pub fn to_bytes(&self) -> [u8; SIZE_8APARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PA8 {
+    pub encryption_constant: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk::<SIZE_8APARTY>(&to_array(self.to_bytes()?)?, SIZE_8ASTORED)")]
+    pub checksum: u16,
+
+    // Block A
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    pub ability: Ability,
+    #[deku(bits = 1)]
+    pub unk_16: u8,
+    #[deku(bits = 1)]
+    pub is_noble: bool,
+    #[deku(bits = 1)]
+    pub is_alpha: bool,
+    #[deku(bits = 1)]
+    pub can_gigantamax: bool,
+    #[deku(bits = 1)]
+    pub favourite: bool,
+    #[deku(bits = 3)]
+    pub ability_number: u8,
+    pub unk_17: u8,
+    pub mark_value: u16,
+    pub unk_1a: [u8; 2],
+    pub pid: u32,
+
+    pub nature: Nature,
+    pub stat_nature: Nature,
+    #[deku(bits = 4)]
+    pub unk_22: u8,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 1)]
+    pub flag2: Flag,
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    pub unk_23: u8,
+    pub form: u16,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    pub unk_33: u8,
+    #[deku(bits = 1)]
+    pub ribbon_effort: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battler_expert: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battler_skillful: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_training: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_best_friends: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_sinnoh: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_g3_hoenn: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_kalos: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_gorgeous: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_smile: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_snooze: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_relax: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_careless: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_downcast: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_shock: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_alert: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_national: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_country: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_legend: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_record: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_footprint: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_artist: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_gorgeous_royal: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_royal: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_souvenir: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_special: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_birthday: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_event: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_premier: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_classic: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_world: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_earth: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_champion_g6_hoenn: Flag,
+    #[deku(bits = 1)]
+    pub has_battle_memory_ribbon: Flag,
+    #[deku(bits = 1)]
+    pub has_contest_memory_ribbon: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_world: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_national: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_regional: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_battle: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_wishing: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_battle_royale: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_alola: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_toughness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_cleverness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_cuteness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_beauty: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_coolness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_contest_star: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_dusk: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_sleepy_time: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_lunchtime: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_rank: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_tower_master: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_galar: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battle_tree_master: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battle_tree_great: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_sandstorm: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_dry: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_blizzard: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_snowy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_stormy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_rainy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_cloudy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_dawn: Flag,
+    pub ribbon_count_memory_contest: u8,
+    pub ribbon_count_memory_battle: u8,
+    pub alpha_move: Move,
+
+    // 0x40 Ribbon 1
+    #[deku(bits = 1)]
+    pub ribbon_mark_absent_minded: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_rowdy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_rare: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_uncommon: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_curry: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_fishing: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_destiny: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_misty: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_angry: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_joyful: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_zoned_out: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_intense: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_calmness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_charismatic: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_excited: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_jittery: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_scowling: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_crafty: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_ferocious: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_intellectual: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_peeved: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_upbeat: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_teary: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_smiley: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_thorny: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_humble: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_unsure: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_prideful: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_zero_energy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_pumped_up: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_flustered: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_kindly: Flag,
+    // 0x44 Ribbon 2
+    #[deku(bits = 1)]
+    pub rib44_7: Flag,
+    #[deku(bits = 1)]
+    pub rib44_6: Flag,
+    #[deku(bits = 1)]
+    pub rib44_5: Flag,
+    #[deku(bits = 1)]
+    pub rib44_4: Flag,
+    #[deku(bits = 1)]
+    pub rib44_3: Flag,
+    #[deku(bits = 1)]
+    pub rib44_2: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_slump: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_vigor: Flag,
+
+    #[deku(bits = 1)]
+    pub rib45_7: Flag,
+    #[deku(bits = 1)]
+    pub rib45_6: Flag,
+    #[deku(bits = 1)]
+    pub rib45_5: Flag,
+    #[deku(bits = 1)]
+    pub rib45_4: Flag,
+    #[deku(bits = 1)]
+    pub rib45_3: Flag,
+    #[deku(bits = 1)]
+    pub rib45_2: Flag,
+    #[deku(bits = 1)]
+    pub rib45_1: Flag,
+    #[deku(bits = 1)]
+    pub rib45_0: Flag,
+
+    #[deku(bits = 1)]
+    pub rib46_7: Flag,
+    #[deku(bits = 1)]
+    pub rib46_6: Flag,
+    #[deku(bits = 1)]
+    pub rib46_5: Flag,
+    #[deku(bits = 1)]
+    pub rib46_4: Flag,
+    #[deku(bits = 1)]
+    pub rib46_3: Flag,
+    #[deku(bits = 1)]
+    pub rib46_2: Flag,
+    #[deku(bits = 1)]
+    pub rib46_1: Flag,
+    #[deku(bits = 1)]
+    pub rib46_0: Flag,
+
+    #[deku(bits = 1)]
+    pub rib47_7: Flag,
+    #[deku(bits = 1)]
+    pub rib47_6: Flag,
+    #[deku(bits = 1)]
+    pub rib47_5: Flag,
+    #[deku(bits = 1)]
+    pub rib47_4: Flag,
+    #[deku(bits = 1)]
+    pub rib47_3: Flag,
+    #[deku(bits = 1)]
+    pub rib47_2: Flag,
+    #[deku(bits = 1)]
+    pub rib47_1: Flag,
+    #[deku(bits = 1)]
+    pub rib47_0: Flag,
+    pub u48: u32,
+    pub unk_4c: [u8; 4],
+    pub height_scalar: u8,
+    pub weight_scalar: u8,
+    pub height_scalar_copy: u8,
+    pub unk_53: u8,
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+
+    // Block B
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub unk_7a: [u8; 12],
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub relearn_move1: Move,
+    pub relearn_move2: Move,
+    pub relearn_move3: Move,
+    pub relearn_move4: Move,
+    pub stat_hp_current: u16,
+    pub iv32: u32,
+    pub dynamax_level: u8,
+    pub unk_99: [u8; 3],
+    pub status_condition: i32,
+    pub unk_a0: i32,
+    pub gv_hp: u8,
+    pub gv_atk: u8,
+    pub gv_def: u8,
+    pub gv_spe: u8,
+    pub gv_spa: u8,
+    pub gv_spd: u8,
+    pub unk_aa: [u8; 2],
+    pub height_absolute: f32,
+    pub weight_absolute: f32,
+    pub unk_b4: [u8; 4],
+
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ht_name, OT_LENGTH))"
+    )]
+    pub ht_name: String,
+    pub ht_gender: Gender,
+    pub ht_language: LanguageID,
+    pub current_handler: u8,
+    pub unk_d5: u8,
+    pub ht_trainer_id: u16,
+    pub ht_friendship: u8,
+    pub ht_intensity: u8,
+    pub ht_memory: u8,
+    pub ht_feeling: u8,
+    pub ht_text_var: u16,
+    pub unk_de: [u8; 14],
+    pub fullness: u8,
+    pub enjoyment: u8,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.battle_version)"
+    )]
+    pub battle_version: GameVersion,
+    pub unk_f0: [u8; 2],
+    pub language: LanguageID,
+    pub unk_f3: u8,
+    pub form_argument: u32,
+    pub affixed_ribbon: i8,
+    pub unk_f9: [u8; 23],
+
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub ot_friendship: u8,
+    pub ot_intensity: u8,
+    pub ot_memory: u8,
+    pub unk_12d: u8,
+    pub ot_text_var: u16,
+    pub ot_feeling: u8,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    pub unk_137: u8,
+    pub egg_location: u16,
+    pub met_location: u16,
+    pub ball: Ball,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    #[deku(bits = "2")]
+    pub unk_13e: u8,
+    #[deku(bits = "1")]
+    pub ht_spe: Flag,
+    #[deku(bits = "1")]
+    pub ht_spd: Flag,
+    #[deku(bits = "1")]
+    pub ht_spa: Flag,
+    #[deku(bits = "1")]
+    pub ht_def: Flag,
+    #[deku(bits = "1")]
+    pub ht_atk: Flag,
+    #[deku(bits = "1")]
+    pub ht_hp: Flag,
+    pub raw_move_record: [u8; 14],
+    pub tracker: u64,
+    /// Moves bought from the move shop, one bit per shop entry.
+    pub raw_purchased_record: [u8; 8],
+    /// Moves mastered, one bit per shop entry.
+    pub raw_mastered_record: [u8; 8],
+    pub unk_165: [u8; 3],
+
+    // Party Stats
+    pub stat_level: u8,
+    pub unk_169: u8,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    pub unk_176: [u8; 2],
+}
+
+fn get_record_flag(record: &[u8], index: usize) -> bool {
+    record.get(index >> 3).is_some_and(|&b| (b >> (index & 7)) & 1 == 1)
+}
+
+fn set_record_flag(record: &mut [u8], index: usize, value: bool) {
+    if let Some(b) = record.get_mut(index >> 3) {
+        *b = (*b & !(1 << (index & 7))) | ((value as u8) << (index & 7));
+    }
+}
+
+impl PA8 {
+    /// Checks if the entity originated from Pokémon Legends: Arceus.
+    pub fn is_pla_origin(&self) -> bool { Self::is_pla(self.version) }
+
+    /// Gets whether the move shop entry at `index` has been purchased.
+    pub fn get_purchased_record_flag(&self, index: usize) -> bool {
+        get_record_flag(&self.raw_purchased_record, index)
+    }
+
+    /// Sets whether the move shop entry at `index` has been purchased.
+    pub fn set_purchased_record_flag(&mut self, index: usize, value: bool) {
+        set_record_flag(&mut self.raw_purchased_record, index, value)
+    }
+
+    /// Gets whether the move shop entry at `index` has been mastered.
+    pub fn get_mastered_record_flag(&self, index: usize) -> bool {
+        get_record_flag(&self.raw_mastered_record, index)
+    }
+
+    /// Sets whether the move shop entry at `index` has been mastered.
+    pub fn set_mastered_record_flag(&mut self, index: usize, value: bool) {
+        set_record_flag(&mut self.raw_mastered_record, index, value)
+    }
+
+    /// Gets the sum of all Ganbaru Values.
+    pub fn gv_total(&self) -> i32 {
+        [self.gv_hp, self.gv_atk, self.gv_def, self.gv_spe, self.gv_spa, self.gv_spd]
+            .iter()
+            .map(|&gv| gv as i32)
+            .sum()
+    }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    /// Shiny value of the PID, compared against the [`PA8::tsv`].
+    pub fn psv(&self) -> i32 { ((self.pid >> 16 ^ (self.pid & 0xFFFF)) >> 4) as i32 }
+    /// Shiny value of the trainer IDs.
+    pub fn tsv(&self) -> i32 { ((self.tid ^ self.sid) >> 4) as i32 }
+    pub fn is_shiny(&self) -> bool { self.psv() == self.tsv() }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location) }
+    /// Whether the entity originates from this format and has never been
+    /// handled by another trainer.
+    pub fn is_untraded(&self) -> bool { self.ht_name.is_empty() && FORMAT == self.generation() }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_8APARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PA8::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PA8 {
+    type RawVariant = RawPA8;
+
+    fn get_string(data: &[u16]) -> String { get_string7(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string7b(data.as_ref(), max_length, self.language, 0, 0, false)
+    }
+
+    fn build(&mut self) -> Result<RawPA8, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPA8 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_8APARTY]> for PA8 {
+    fn from(data: &[u8; SIZE_8APARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted8a(&mut array);
+        PA8::read_bytes(&array).unwrap()
+    }
+}
+
+impl From<&[u8; SIZE_8ASTORED]> for PA8 {
+    fn from(data: &[u8; SIZE_8ASTORED]) -> Self {
+        let mut stored = *data;
+        decrypt_if_encrypted8a(&mut stored);
+        let mut array = [0u8; SIZE_8APARTY];
+        array[..SIZE_8ASTORED].copy_from_slice(&stored);
+        PA8::from(&array)
+    }
+}
+
+impl TryFrom<&[u8]> for PA8 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, rejecting data
+    /// whose stored checksum does not match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array = [0u8; SIZE_8APARTY];
+        match data.len() {
+            SIZE_8APARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted8a(&mut array);
+            }
+            SIZE_8ASTORED => {
+                let mut stored: [u8; SIZE_8ASTORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted8a(&mut stored);
+                array[..SIZE_8ASTORED].copy_from_slice(&stored);
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_8ASTORED, SIZE_8APARTY],
+                    actual,
+                })
+            }
+        }
+
+        let stored = u16::from_le_bytes([array[0x06], array[0x07]]);
+        let calculated = get_chk::<SIZE_8APARTY>(&array, SIZE_8ASTORED);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PA8::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::util::{pokecrypto::encrypt_array8a, testutil::write_string};
+
+    fn kleavor() -> [u8; SIZE_8APARTY] {
+        let mut data = [0u8; SIZE_8APARTY];
+        data[0x00..0x04].copy_from_slice(&0x8A8A_1234u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&(Species::Kleavor as u16).to_le_bytes());
+        data[0x0C..0x0E].copy_from_slice(&11111u16.to_le_bytes());
+        data[0x0E..0x10].copy_from_slice(&22222u16.to_le_bytes());
+        data[0x14..0x16].copy_from_slice(&(Ability::Swarm as u16).to_le_bytes());
+        data[0x16] = 0x20 | 1; // Alpha, first ability
+        data[0x1C..0x20].copy_from_slice(&0x1357_9BDFu32.to_le_bytes());
+        data[0x20] = Nature::Adamant as u8;
+        data[0x21] = Nature::Adamant as u8;
+        data[0x3E..0x40].copy_from_slice(&(Move::StoneAxe as u16).to_le_bytes());
+        data[0x50] = 255;
+        data[0x52] = 255;
+        data[0x54..0x56].copy_from_slice(&(Move::StoneAxe as u16).to_le_bytes());
+        data[0x5C] = 15;
+        write_string(&mut data, 0x60, "Kleavor".encode_utf16(), Endian::Little);
+        data[0x86] = 1;
+        data[0x8A..0x8C].copy_from_slice(&(Move::StoneAxe as u16).to_le_bytes());
+        data[0x92..0x94].copy_from_slice(&150u16.to_le_bytes());
+        data[0x94..0x98].copy_from_slice(&0x3FFF_FFFFu32.to_le_bytes());
+        data[0xA4] = 10;
+        data[0xA9] = 3;
+        data[0xAC..0xB0].copy_from_slice(&1.8f32.to_le_bytes());
+        data[0xB0..0xB4].copy_from_slice(&89.0f32.to_le_bytes());
+        data[0xEE] = GameVersion::PLA as u8;
+        data[0xF2] = LanguageID::English as u8;
+        write_string(&mut data, 0x110, "Rei".encode_utf16(), Endian::Little);
+        data[0x13A..0x13C].copy_from_slice(&6u16.to_le_bytes());
+        data[0x13C] = Ball::LAOrigin as u8;
+        data[0x13D] = 70;
+        data[0x155] = 0b0000_0011;
+        data[0x15D] = 0b0000_0010;
+        data[0x168] = 70;
+        data[0x16A..0x16C].copy_from_slice(&150u16.to_le_bytes());
+        data[0x174..0x176].copy_from_slice(&80u16.to_le_bytes());
+        let chk = get_chk::<SIZE_8APARTY>(&data, SIZE_8ASTORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pa8_get_test() {
+        let kleavor = PA8::from(&kleavor());
+        assert_eq!(0x8A8A1234, kleavor.encryption_constant);
+        assert_eq!(Species::Kleavor, kleavor.species);
+        assert_eq!(11111, kleavor.tid);
+        assert_eq!(22222, kleavor.sid);
+        assert_eq!(Ability::Swarm, kleavor.ability);
+        assert_eq!(1, kleavor.ability_number);
+        assert!(kleavor.is_alpha);
+        assert!(!kleavor.is_noble);
+        assert_eq!(Move::StoneAxe, kleavor.alpha_move);
+        assert_eq!(Nature::Adamant, kleavor.nature);
+        assert_eq!((255, 255), (kleavor.height_scalar, kleavor.height_scalar_copy));
+        assert_eq!(Move::StoneAxe, kleavor.move1);
+        assert_eq!(15, kleavor.move1_pp);
+        assert_eq!("Kleavor", kleavor.nickname);
+        assert_eq!(1, kleavor.move_1_pp_ups);
+        assert_eq!(Move::StoneAxe, kleavor.relearn_move1);
+        assert_eq!(150, kleavor.stat_hp_current);
+        assert_eq!(31, kleavor.iv(Stat::SpD));
+        assert_eq!((10, 3), (kleavor.gv_hp, kleavor.gv_spd));
+        assert_eq!(13, kleavor.gv_total());
+        assert_eq!(1.8, kleavor.height_absolute);
+        assert_eq!(89.0, kleavor.weight_absolute);
+        assert_eq!(GameVersion::PLA, kleavor.version);
+        assert_eq!(LanguageID::English, kleavor.language);
+        assert_eq!("Rei", kleavor.ot_name);
+        assert_eq!(6, kleavor.met_location);
+        assert_eq!(Ball::LAOrigin, kleavor.ball);
+        assert_eq!(70, kleavor.met_level);
+        assert_eq!(70, kleavor.stat_level);
+        assert_eq!(150, kleavor.stat_hp_max);
+        assert_eq!(80, kleavor.stat_spd);
+        assert_eq!(8, kleavor.generation());
+        assert!(kleavor.is_pla_origin());
+        assert!(kleavor.is_untraded());
+    }
+
+    #[test]
+    fn pa8_shiny_test() {
+        let mut kleavor = PA8::from(&kleavor());
+        assert_eq!((0x888, 0x7DA), (kleavor.psv(), kleavor.tsv()));
+        assert!(!kleavor.is_shiny());
+
+        kleavor.pid = 0x7DA9_0000;
+        assert!(kleavor.is_shiny());
+        let rebuilt = PA8::from(&kleavor.build().unwrap().to_bytes());
+        assert!(rebuilt.is_shiny());
+
+        kleavor.sid ^= 0x10;
+        assert!(!kleavor.is_shiny());
+    }
+
+    #[test]
+    fn pa8_try_from_test() {
+        let mut data = kleavor();
+        let party = PA8::try_from(&data[..]).unwrap();
+        assert_eq!(PA8::from(&data), party);
+        assert_eq!(party.nickname, PA8::try_from(&data[..SIZE_8ASTORED]).unwrap().nickname);
+        assert_eq!(party, PA8::try_from(&encrypt_array8a(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_8ASTORED, SIZE_8APARTY],
+                actual: 0x100
+            }),
+            PA8::try_from(&data[..0x100])
+        );
+        data[0x08] ^= 1;
+        assert!(matches!(PA8::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pa8_move_shop_test() {
+        let mut kleavor = PA8::from(&kleavor());
+        assert!(kleavor.get_purchased_record_flag(0));
+        assert!(kleavor.get_purchased_record_flag(1));
+        assert!(!kleavor.get_purchased_record_flag(2));
+        assert!(!kleavor.get_mastered_record_flag(0));
+        assert!(kleavor.get_mastered_record_flag(1));
+        assert!(!kleavor.get_mastered_record_flag(64));
+
+        kleavor.set_purchased_record_flag(63, true);
+        kleavor.set_mastered_record_flag(1, false);
+        let kleavor = PA8::from(&kleavor.build().unwrap().to_bytes());
+        assert!(kleavor.get_purchased_record_flag(63));
+        assert!(!kleavor.get_mastered_record_flag(1));
+    }
+
+    #[test]
+    fn pa8_from_encrypted_test() {
+        let mut pa8 = kleavor();
+        let ea8 = encrypt_array8a(&mut pa8);
+        assert_ne!(pa8, ea8);
+        assert_eq!(PA8::from(&pa8), PA8::from(&ea8));
+
+        let mut stored = <[u8; SIZE_8ASTORED]>::try_from(&pa8[..SIZE_8ASTORED]).unwrap();
+        let from_stored = PA8::from(&stored);
+        assert_eq!(from_stored, PA8::from(&encrypt_array8a(&mut stored)));
+        assert_eq!(0, from_stored.stat_level);
+    }
+
+    #[test]
+    fn pa8_build_test() {
+        let data = kleavor();
+        let mut kleavor = PA8::from(&data);
+        assert_eq!(data, kleavor.build().unwrap().to_bytes());
+
+        kleavor.is_noble = true;
+        kleavor.gv_atk = 7;
+        let rebuilt = PA8::from(&kleavor.build().unwrap().to_bytes());
+        assert!(rebuilt.is_noble);
+        assert!(rebuilt.is_alpha);
+        assert_eq!(7, rebuilt.gv_atk);
+    }
+
+    #[test]
+    fn pa8_round_trip_test() {
+        let mut data = kleavor();
+        for offset in [
+            0x17, 0x1A, 0x23, 0x33, 0x4C, 0x53, 0x7A, 0x85, 0x99, 0xAA, 0xB4, 0xD5, 0xDE, 0xF0,
+            0xF9, 0x10F, 0x12D, 0x137, 0x165, 0x169, 0x176,
+        ] {
+            data[offset] = 0xA5;
+        }
+        data[0x16] |= 0x80;
+        data[0x22] |= 0xF0;
+        data[0x13E] |= 0b1100_0000;
+        data[0x34] |= 1;
+        data[0x44] |= 0b10;
+        let chk = get_chk::<SIZE_8APARTY>(&data, SIZE_8ASTORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+
+        let mut kleavor = PA8::from(&data);
+        assert_eq!(1, kleavor.unk_16);
+        assert_eq!(0xA5, kleavor.unk_f9[0]);
+        assert_eq!(Flag::Set, kleavor.ribbon_champion_kalos);
+        assert_eq!(Flag::Set, kleavor.ribbon_mark_slump);
+        assert_eq!(data, kleavor.build().unwrap().to_bytes());
+    }
+}
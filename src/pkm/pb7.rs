@@ -0,0 +1,468 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species, stat::Stat
+    }, pkm::{
+        strings::string_converter::{get_string7, set_string7b}, util::pokecrypto::{decrypt_if_encrypted67, get_chk, SIZE_6PARTY, SIZE_6STORED}
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 7;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 252;
+pub const MAX_AV: i32 = 200;
+pub const OT_LENGTH: usize = 12;
+pub const NICK_LENGTH: usize = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPB7 {
+    data: [u8; SIZE_6PARTY],
+}
+
+impl RawPB7 {
+    pub fn to_bytes(&self) -> [u8; SIZE_6PARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PB7 {
+    pub encryption_constant: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk::<SIZE_6PARTY>(&to_array(self.to_bytes()?)?, SIZE_6STORED)")]
+    pub checksum: u16,
+
+    // Block A
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    #[deku(
+        reader = "read::read_ability_u8(deku::rest)",
+        writer = "write::write_ability_u8(deku::output, self.ability)"
+    )]
+    pub ability: Ability,
+    #[deku(bits = 4)]
+    pub unk_15: u8,
+    #[deku(bits = 1)]
+    pub favourite: bool,
+    #[deku(bits = 3)]
+    pub ability_number: u8,
+    pub mark_value: u16,
+    pub pid: u32,
+    pub nature: Nature,
+    #[deku(bits = 5)]
+    pub form: u8,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub av_hp: u8,
+    pub av_atk: u8,
+    pub av_def: u8,
+    pub av_spe: u8,
+    pub av_spa: u8,
+    pub av_spd: u8,
+    pub unk_2a: u8,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    pub field_event_fatigue1: u8,
+    pub field_event_fatigue2: u8,
+    pub fullness: u8,
+    pub rank: u8,
+    pub unk_30: [u8; 10],
+    pub height_scalar: u8,
+    pub weight_scalar: u8,
+    pub height_absolute: f32,
+
+    // Block B
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub relearn_move1: Move,
+    pub relearn_move2: Move,
+    pub relearn_move3: Move,
+    pub relearn_move4: Move,
+    pub unk_72: [u8; 2],
+    pub iv32: u32,
+
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ht_name, OT_LENGTH))"
+    )]
+    pub ht_name: String,
+    pub ht_gender: Gender,
+    pub current_handler: u8,
+    pub unk_94: [u8; 14],
+    pub ht_friendship: u8,
+    pub ht_affection: u8,
+    pub ht_intensity: u8,
+    pub ht_memory: u8,
+    pub ht_feeling: u8,
+    pub unk_a7: u8,
+    pub ht_text_var: u16,
+    pub unk_aa: [u8; 6],
+
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub ot_friendship: u8,
+    pub received_year: u8,
+    pub received_month: u8,
+    pub received_day: u8,
+    pub received_hour: u8,
+    pub received_minute: u8,
+    pub received_second: u8,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    pub unk_d7: u8,
+    pub egg_location: u16,
+    pub met_location: u16,
+    pub ball: Ball,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    #[deku(bits = "2")]
+    pub unk_de: u8,
+    #[deku(bits = "1")]
+    pub ht_spe: Flag,
+    #[deku(bits = "1")]
+    pub ht_spd: Flag,
+    #[deku(bits = "1")]
+    pub ht_spa: Flag,
+    #[deku(bits = "1")]
+    pub ht_def: Flag,
+    #[deku(bits = "1")]
+    pub ht_atk: Flag,
+    #[deku(bits = "1")]
+    pub ht_hp: Flag,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    pub unk_e0: [u8; 3],
+    pub language: LanguageID,
+    pub weight_absolute: f32,
+
+    // Party Stats
+    pub status_condition: i32,
+    pub stat_level: u8,
+    pub unk_ed: [u8; 3],
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    pub stat_cp: u16,
+    pub stat_is_mega: bool,
+    pub stat_mega_form: u8,
+    pub unk_102: [u8; 2],
+}
+
+impl PB7 {
+    fn is_chinese(&self) -> bool {
+        [LanguageID::ChineseS, LanguageID::ChineseT].contains(&self.language)
+    }
+
+    /// Checks if the entity originated from Pokémon: Let's Go, Pikachu! or
+    /// Let's Go, Eevee!
+    pub fn is_lgpe_origin(&self) -> bool { Self::is_lgpe(self.version) }
+
+    /// Checks if the entity was transferred from Pokémon GO through the GO
+    /// Park.
+    pub fn is_go_park_origin(&self) -> bool { Self::is_go_lgpe(self.version, self.met_location) }
+
+    /// Gets the sum of all Awakening Values.
+    pub fn av_total(&self) -> i32 {
+        [self.av_hp, self.av_atk, self.av_def, self.av_spe, self.av_spa, self.av_spd]
+            .iter()
+            .map(|&av| av as i32)
+            .sum()
+    }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location) }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_6PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PB7::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PB7 {
+    type RawVariant = RawPB7;
+
+    fn get_string(data: &[u16]) -> String { get_string7(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string7b(data.as_ref(), max_length, self.language, 0, 0, self.is_chinese())
+    }
+
+    fn build(&mut self) -> Result<RawPB7, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPB7 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_6PARTY]> for PB7 {
+    fn from(data: &[u8; SIZE_6PARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted67(&mut array);
+        PB7::read_bytes(&array).unwrap()
+    }
+}
+
+impl TryFrom<&[u8]> for PB7 {
+    type Error = PkmError;
+
+    /// Parses party sized data, encrypted or not, rejecting data whose stored
+    /// checksum does not match. Let's Go data is only ever party sized.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array: [u8; SIZE_6PARTY] = to_array(data.to_vec())?;
+        decrypt_if_encrypted67(&mut array);
+
+        let stored = u16::from_le_bytes([array[0x06], array[0x07]]);
+        let calculated = get_chk::<SIZE_6PARTY>(&array, SIZE_6STORED);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PB7::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        legality::tables::location, pkm::util::{pokecrypto::encrypt_array67, testutil::write_string}
+    };
+    use alloc::vec;
+
+    fn eevee() -> [u8; SIZE_6PARTY] {
+        let mut data = [0u8; SIZE_6PARTY];
+        data[0x00..0x04].copy_from_slice(&0x1B2C_3D4Eu32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&(Species::Eevee as u16).to_le_bytes());
+        data[0x0C..0x0E].copy_from_slice(&12345u16.to_le_bytes());
+        data[0x0E..0x10].copy_from_slice(&54321u16.to_le_bytes());
+        data[0x14] = Ability::Adaptability as u8;
+        data[0x15] = 0x08 | 2; // Favourite, hidden ability
+        data[0x18..0x1C].copy_from_slice(&0xCAFE_BABEu32.to_le_bytes());
+        data[0x1C] = Nature::Jolly as u8;
+        data[0x1D] = 1 << 1; // Female
+        data[0x24] = 200;
+        data[0x27] = 150;
+        data[0x2E] = 255;
+        data[0x3A] = 128;
+        data[0x3B] = 64;
+        data[0x3C..0x40].copy_from_slice(&0.3f32.to_le_bytes());
+        write_string(&mut data, 0x40, "Eevee".encode_utf16(), Endian::Little);
+        data[0x5A..0x5C].copy_from_slice(&(Move::Tackle as u16).to_le_bytes());
+        data[0x74..0x78].copy_from_slice(&0x3FFF_FFFFu32.to_le_bytes());
+        data[0xA2] = 70;
+        write_string(&mut data, 0xB0, "Chase".encode_utf16(), Endian::Little);
+        data[0xCA] = 255;
+        data[0xCB] = 18;
+        data[0xD0] = 42;
+        data[0xDA..0xDC].copy_from_slice(&3u16.to_le_bytes());
+        data[0xDC] = Ball::Poke as u8;
+        data[0xDD] = 12;
+        data[0xDE] = 0b0000_0010; // HT Attack
+        data[0xDF] = GameVersion::GE as u8;
+        data[0xE3] = LanguageID::English as u8;
+        data[0xE4..0xE8].copy_from_slice(&6.5f32.to_le_bytes());
+        data[0xEC] = 12;
+        data[0xF0..0xF2].copy_from_slice(&38u16.to_le_bytes());
+        data[0xF2..0xF4].copy_from_slice(&38u16.to_le_bytes());
+        data[0xFC..0xFE].copy_from_slice(&24u16.to_le_bytes());
+        data[0xFE..0x100].copy_from_slice(&312u16.to_le_bytes());
+        let chk = get_chk::<SIZE_6PARTY>(&data, SIZE_6STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pb7_get_test() {
+        let eevee = PB7::from(&eevee());
+        assert_eq!(0x1B2C3D4E, eevee.encryption_constant);
+        assert_eq!(Species::Eevee, eevee.species);
+        assert_eq!(12345, eevee.tid);
+        assert_eq!(54321, eevee.sid);
+        assert_eq!(Ability::Adaptability, eevee.ability);
+        assert!(eevee.favourite);
+        assert_eq!(2, eevee.ability_number);
+        assert_eq!(0xCAFEBABE, eevee.pid);
+        assert_eq!(Nature::Jolly, eevee.nature);
+        assert_eq!(Gender::Female, eevee.gender);
+        assert_eq!(0, eevee.ev_hp);
+        assert_eq!(200, eevee.av_hp);
+        assert_eq!(150, eevee.av_spe);
+        assert_eq!(255, eevee.fullness);
+        assert_eq!((128, 64), (eevee.height_scalar, eevee.weight_scalar));
+        assert_eq!(0.3, eevee.height_absolute);
+        assert_eq!("Eevee", eevee.nickname);
+        assert_eq!(Move::Tackle, eevee.move1);
+        assert_eq!(31, eevee.iv(Stat::HP));
+        assert_eq!(31, eevee.iv(Stat::SpD));
+        assert!(!eevee.is_egg());
+        assert_eq!(70, eevee.ht_friendship);
+        assert_eq!("Chase", eevee.ot_name);
+        assert_eq!(255, eevee.ot_friendship);
+        assert_eq!((18, 42), (eevee.received_year, eevee.received_second));
+        assert_eq!(3, eevee.met_location);
+        assert_eq!(Ball::Poke, eevee.ball);
+        assert_eq!(12, eevee.met_level);
+        assert_eq!(Flag::Set, eevee.ht_atk);
+        assert_eq!(Flag::Unset, eevee.ht_hp);
+        assert_eq!(GameVersion::GE, eevee.version);
+        assert_eq!(LanguageID::English, eevee.language);
+        assert_eq!(6.5, eevee.weight_absolute);
+        assert_eq!(12, eevee.stat_level);
+        assert_eq!(38, eevee.stat_hp_max);
+        assert_eq!(24, eevee.stat_spd);
+        assert_eq!(312, eevee.stat_cp);
+        assert!(!eevee.stat_is_mega);
+        assert_eq!(350, eevee.av_total());
+    }
+
+    #[test]
+    fn pb7_origin_test() {
+        let mut eevee = PB7::from(&eevee());
+        assert_eq!(7, eevee.generation());
+        assert!(eevee.is_lgpe_origin());
+        assert!(!eevee.is_go_park_origin());
+
+        eevee.version = GameVersion::GO;
+        eevee.met_location = location::GO_7 as u16;
+        assert_eq!(7, eevee.generation());
+        let eevee = PB7::from(&eevee.build().unwrap().to_bytes());
+        assert!(!eevee.is_lgpe_origin());
+        assert!(eevee.is_go_park_origin());
+    }
+
+    #[test]
+    fn pb7_from_encrypted_test() {
+        let mut pb7 = eevee();
+        let eb7 = encrypt_array67(&mut pb7);
+        assert_ne!(pb7, eb7);
+        assert_eq!(PB7::from(&pb7), PB7::from(&eb7));
+    }
+
+    #[test]
+    fn pb7_try_from_test() {
+        let mut data = eevee();
+        let eevee = PB7::try_from(&data[..]).unwrap();
+        assert_eq!(PB7::from(&data), eevee);
+        assert_eq!(eevee, PB7::try_from(&encrypt_array67(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![SIZE_6PARTY], actual: SIZE_6STORED }),
+            PB7::try_from(&data[..SIZE_6STORED])
+        );
+        data[0x08] ^= 1;
+        assert!(matches!(PB7::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pb7_build_test() {
+        let data = eevee();
+        let mut eevee = PB7::from(&data);
+        assert_eq!(data, eevee.build().unwrap().to_bytes());
+
+        eevee.av_atk = 200;
+        eevee.set_iv(Stat::Spe, 0);
+        eevee.nickname = String::from("Vaporeon");
+        let rebuilt = PB7::from(&eevee.build().unwrap().to_bytes());
+        assert_eq!(200, rebuilt.av_atk);
+        assert_eq!(0, rebuilt.iv(Stat::Spe));
+        assert_eq!(31, rebuilt.iv(Stat::SpA));
+        assert_eq!("Vaporeon", rebuilt.nickname);
+        assert_ne!(data[0x06..0x08], rebuilt.checksum.to_le_bytes());
+    }
+
+    #[test]
+    fn pb7_round_trip_test() {
+        let mut data = eevee();
+        for offset in
+            [0x2A, 0x30, 0x39, 0x72, 0x73, 0x94, 0xA1, 0xA7, 0xAA, 0xAF, 0xD7, 0xE0, 0xED, 0x103]
+        {
+            data[offset] = 0xA5;
+        }
+        data[0x15] |= 0xF0;
+        data[0xDE] |= 0b1100_0000;
+        let chk = get_chk::<SIZE_6PARTY>(&data, SIZE_6STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+
+        let mut eevee = PB7::from(&data);
+        assert_eq!(0xF, eevee.unk_15);
+        assert_eq!(0xA5, eevee.unk_30[0]);
+        assert_eq!(data, eevee.build().unwrap().to_bytes());
+    }
+}
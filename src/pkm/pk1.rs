@@ -0,0 +1,391 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{format, string::String, vec::Vec};
+use core::convert::TryFrom;
+use deku::prelude::*;
+
+use crate::{
+    error::PkmError, game::enums::{
+        game_version::GameVersion, gender::Gender, moves::Move, nature::Nature, species::Species
+    }, pkm::{
+        strings::string_converter12::{get_string1, set_string1}, util::{
+            experience::get_nature_vc, pokecrypto::{SIZE_1JLIST, SIZE_1PARTY, SIZE_1STORED, SIZE_1ULIST}, species_converter::{get_g1_species, set_g1_species}
+        }
+    }, util::{
+        custom_read_write::{read, write}, packutil::pack_u32
+    }
+};
+
+pub const FORMAT: i32 = 1;
+
+pub const MAX_IV: i32 = 15;
+pub const MAX_EV: i32 = 65535;
+pub const OT_LENGTH: usize = 7;
+pub const NICK_LENGTH: usize = 10;
+pub const OT_LENGTH_JP: usize = 5;
+pub const NICK_LENGTH_JP: usize = 5;
+
+/// Size of an international string buffer in list storage, including the
+/// terminator.
+pub const STRING_LENGTH: usize = 11;
+
+/// Size of a Japanese string buffer in list storage, including the terminator.
+pub const STRING_LENGTH_JP: usize = 6;
+
+/// Species list terminator used by single entity lists.
+pub(crate) const LIST_TERMINATOR: u8 = 0xFF;
+
+/// Gets the HP DV, which is built from the lowest bit of each other DV.
+pub(crate) fn get_hp_dv(atk: u8, def: u8, spe: u8, spc: u8) -> u8 {
+    ((atk & 1) << 3) | ((def & 1) << 2) | ((spe & 1) << 1) | (spc & 1)
+}
+
+/// Checks if a set of DVs is shiny by the Generation 2 rules.
+pub(crate) fn is_shiny_dv(atk: u8, def: u8, spe: u8, spc: u8) -> bool {
+    def == 10 && spe == 10 && spc == 10 && (atk & 2) == 2
+}
+
+/// Gets the gender implied by the Attack DV for a species' gender ratio.
+pub(crate) fn get_gender_dv(atk: u8, gender_ratio: u8) -> Gender {
+    match gender_ratio {
+        255 => Gender::Genderless,
+        254 => Gender::Female,
+        0 => Gender::Male,
+        _ if atk > (gender_ratio >> 4) => Gender::Male,
+        _ => Gender::Female,
+    }
+}
+
+/// Splits a single entity list into its species, entity, OT name and nickname
+/// regions.
+pub(crate) fn read_list(
+    data: &[u8],
+    party_size: usize,
+    string_length: usize,
+) -> (u8, &[u8], &[u8], &[u8]) {
+    let (entity, strings) = data[3..].split_at(party_size);
+    let (ot_name, nickname) = strings.split_at(string_length);
+    (data[1], entity, ot_name, &nickname[..string_length])
+}
+
+/// Writes a single entity list from its species, entity, OT name and
+/// nickname regions.
+pub(crate) fn write_list(species: u8, entity: &[u8], ot_name: &[u8], nickname: &[u8]) -> Vec<u8> {
+    let mut list = Vec::with_capacity(3 + entity.len() + ot_name.len() + nickname.len());
+    list.extend_from_slice(&[1, species, LIST_TERMINATOR]);
+    list.extend_from_slice(entity);
+    list.extend_from_slice(ot_name);
+    list.extend_from_slice(nickname);
+    list
+}
+
+/// Single entity list storage. International lists are [`SIZE_1ULIST`] bytes
+/// and Japanese lists are [`SIZE_1JLIST`] bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawPK1 {
+    data: Vec<u8>,
+}
+
+impl RawPK1 {
+    pub fn to_bytes(&self) -> Vec<u8> { self.data.clone() }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "big")]
+#[repr(C)]
+pub struct PK1 {
+    #[deku(update = "set_g1_species(self.species)")]
+    pub species_id1: u8,
+    #[deku(skip, default = "get_g1_species(*species_id1)")]
+    pub species: Species,
+    pub stat_hp_current: u16,
+    pub box_level: u8,
+    pub status_condition: u8,
+    pub type_a: u8,
+    pub type_b: u8,
+    /// Catch rate, which doubles as the held item once traded to Generation 2.
+    pub catch_rate: u8,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move1)"
+    )]
+    pub move1: Move,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move2)"
+    )]
+    pub move2: Move,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move3)"
+    )]
+    pub move3: Move,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move4)"
+    )]
+    pub move4: Move,
+    pub tid: u16,
+    #[deku(bits = 24)]
+    pub exp: u32,
+    pub ev_hp: u16,
+    pub ev_atk: u16,
+    pub ev_def: u16,
+    pub ev_spe: u16,
+    pub ev_spc: u16,
+    #[deku(
+        update = "(pack_u32(&[(self.iv_spc, 4), (self.iv_spe, 4), (self.iv_def, 4), (self.iv_atk, 4)]) as u16)"
+    )]
+    pub dv16: u16,
+    #[deku(skip, default = "((*dv16 >> 12) & 0xF) as u8")]
+    pub iv_atk: u8,
+    #[deku(skip, default = "((*dv16 >> 8) & 0xF) as u8")]
+    pub iv_def: u8,
+    #[deku(skip, default = "((*dv16 >> 4) & 0xF) as u8")]
+    pub iv_spe: u8,
+    #[deku(skip, default = "(*dv16 & 0xF) as u8")]
+    pub iv_spc: u8,
+    #[deku(bits = 2)]
+    pub move_1_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move1_pp: u8,
+    #[deku(bits = 2)]
+    pub move_2_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move2_pp: u8,
+    #[deku(bits = 2)]
+    pub move_3_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move3_pp: u8,
+    #[deku(bits = 2)]
+    pub move_4_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move4_pp: u8,
+
+    // Party Stats
+    pub stat_level: u8,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spc: u16,
+
+    // List Storage
+    #[deku(skip)]
+    pub ot_name: String,
+    #[deku(skip)]
+    pub nickname: String,
+    /// Whether the entity uses the Japanese character table and list layout.
+    #[deku(skip)]
+    pub japanese: bool,
+    #[deku(skip, default = "GameVersion::RBY")]
+    pub version: GameVersion,
+    #[deku(skip, default = "Self::get_generation(GameVersion::RBY, 0)")]
+    pub generation: i32,
+}
+
+impl PK1 {
+    /// Gets the HP DV, which is derived from the other DVs.
+    pub fn iv_hp(&self) -> u8 { get_hp_dv(self.iv_atk, self.iv_def, self.iv_spe, self.iv_spc) }
+
+    /// Checks if the entity would be shiny once traded to Generation 2.
+    pub fn is_shiny(&self) -> bool {
+        is_shiny_dv(self.iv_atk, self.iv_def, self.iv_spe, self.iv_spc)
+    }
+
+    /// Gets the gender the entity will have once traded to Generation 2.
+    ///
+    /// # Arguments
+    ///
+    /// * `gender_ratio` - Gender ratio of the species, where 0 is male-only,
+    ///   254 is female-only and 255 is genderless.
+    pub fn gender(&self, gender_ratio: u8) -> Gender { get_gender_dv(self.iv_atk, gender_ratio) }
+
+    /// Gets the nature assigned when transferred from the Virtual Console.
+    pub fn nature(&self) -> Nature { Nature::n(get_nature_vc(self.exp) as u8).unwrap() }
+
+    fn string_length(&self) -> usize {
+        if self.japanese {
+            STRING_LENGTH_JP
+        } else {
+            STRING_LENGTH
+        }
+    }
+
+    fn from_list(data: &[u8], japanese: bool) -> Self {
+        let string_length = if japanese { STRING_LENGTH_JP } else { STRING_LENGTH };
+        let (_species, entity, ot_name, nickname) = read_list(data, SIZE_1PARTY, string_length);
+        let mut file = PK1::from(&<[u8; SIZE_1PARTY]>::try_from(entity).unwrap());
+        file.ot_name = get_string1(ot_name, japanese);
+        file.nickname = get_string1(nickname, japanese);
+        file.japanese = japanese;
+        file
+    }
+}
+
+impl PKM for PK1 {
+    type RawVariant = RawPK1;
+
+    fn get_string(data: &[u16]) -> String {
+        get_string1(&data.iter().map(|&c| c as u8).collect::<Vec<u8>>(), false)
+    }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string1(data.as_ref(), max_length, self.string_length(), self.japanese)
+            .into_iter()
+            .map(u16::from)
+            .collect()
+    }
+
+    fn build(&mut self) -> Result<RawPK1, PkmError> {
+        self.refresh_checksum();
+        let string_length = self.string_length();
+        let (nick_length, ot_length) =
+            if self.japanese { (NICK_LENGTH_JP, OT_LENGTH_JP) } else { (NICK_LENGTH, OT_LENGTH) };
+        Ok(RawPK1 {
+            data: write_list(
+                self.species_id1,
+                &self.to_bytes()?,
+                &set_string1(&self.ot_name, ot_length, string_length, self.japanese),
+                &set_string1(&self.nickname, nick_length, string_length, self.japanese),
+            ),
+        })
+    }
+}
+
+impl From<&[u8; SIZE_1PARTY]> for PK1 {
+    fn from(data: &[u8; SIZE_1PARTY]) -> Self {
+        let (_rest, file) = PK1::from_bytes((data.as_ref(), 0)).unwrap();
+        file
+    }
+}
+
+impl From<&[u8; SIZE_1STORED]> for PK1 {
+    fn from(data: &[u8; SIZE_1STORED]) -> Self {
+        let mut array = [0u8; SIZE_1PARTY];
+        array[..SIZE_1STORED].copy_from_slice(data);
+        PK1::from(&array)
+    }
+}
+
+impl From<&[u8; SIZE_1ULIST]> for PK1 {
+    fn from(data: &[u8; SIZE_1ULIST]) -> Self { PK1::from_list(data, false) }
+}
+
+impl From<&[u8; SIZE_1JLIST]> for PK1 {
+    fn from(data: &[u8; SIZE_1JLIST]) -> Self { PK1::from_list(data, true) }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn pikachu(japanese: bool) -> Vec<u8> {
+        let mut entity = [0u8; SIZE_1PARTY];
+        entity[0x00] = 84; // Pikachu
+        entity[0x01..0x03].copy_from_slice(&35u16.to_be_bytes());
+        entity[0x03] = 25;
+        entity[0x05] = 23; // Electric
+        entity[0x06] = 23;
+        entity[0x07] = 190;
+        entity[0x08] = Move::ThunderShock as u8;
+        entity[0x09] = Move::Growl as u8;
+        entity[0x0C..0x0E].copy_from_slice(&25518u16.to_be_bytes());
+        entity[0x0E..0x11].copy_from_slice(&15_631u32.to_be_bytes()[1..]);
+        entity[0x11..0x13].copy_from_slice(&65535u16.to_be_bytes());
+        entity[0x1B..0x1D].copy_from_slice(&0x7AAAu16.to_be_bytes());
+        entity[0x1D] = 0xC0 | 30;
+        entity[0x21] = 25;
+        entity[0x22..0x24].copy_from_slice(&55u16.to_be_bytes());
+        entity[0x2A..0x2C].copy_from_slice(&50u16.to_be_bytes());
+        let string_length = if japanese { STRING_LENGTH_JP } else { STRING_LENGTH };
+        let (ot_name, nickname) =
+            if japanese { ("サトシ", "ピカチュウ") } else { ("ASH", "PIKACHU") };
+        write_list(
+            84,
+            &entity,
+            &set_string1(ot_name, 7, string_length, japanese),
+            &set_string1(nickname, 10, string_length, japanese),
+        )
+    }
+
+    #[test]
+    fn pk1_get_test() {
+        let pikachu = PK1::from(&<[u8; SIZE_1ULIST]>::try_from(pikachu(false)).unwrap());
+        assert_eq!(84, pikachu.species_id1);
+        assert_eq!(Species::Pikachu, pikachu.species);
+        assert_eq!(35, pikachu.stat_hp_current);
+        assert_eq!(25, pikachu.box_level);
+        assert_eq!(190, pikachu.catch_rate);
+        assert_eq!(Move::ThunderShock, pikachu.move1);
+        assert_eq!(Move::Growl, pikachu.move2);
+        assert_eq!(25518, pikachu.tid);
+        assert_eq!(15_631, pikachu.exp);
+        assert_eq!(65535, pikachu.ev_hp);
+        assert_eq!(
+            (7, 10, 10, 10),
+            (pikachu.iv_atk, pikachu.iv_def, pikachu.iv_spe, pikachu.iv_spc)
+        );
+        assert_eq!(8, pikachu.iv_hp());
+        assert_eq!((3, 30), (pikachu.move_1_pp_ups, pikachu.move1_pp));
+        assert_eq!(25, pikachu.stat_level);
+        assert_eq!(50, pikachu.stat_spc);
+        assert_eq!("ASH", pikachu.ot_name);
+        assert_eq!("PIKACHU", pikachu.nickname);
+        assert!(!pikachu.japanese);
+        assert_eq!(1, pikachu.generation);
+    }
+
+    #[test]
+    fn pk1_dv_test() {
+        let mut pikachu = PK1::from(&<[u8; SIZE_1ULIST]>::try_from(pikachu(false)).unwrap());
+        assert!(pikachu.is_shiny());
+        assert_eq!(Gender::Male, pikachu.gender(63));
+        assert_eq!(Gender::Female, pikachu.gender(127));
+        assert_eq!(Gender::Genderless, pikachu.gender(255));
+        assert_eq!(Nature::Docile, pikachu.nature());
+
+        pikachu.iv_atk = 9;
+        assert!(!pikachu.is_shiny());
+        let rebuilt =
+            PK1::from(&<[u8; SIZE_1ULIST]>::try_from(pikachu.build().unwrap().to_bytes()).unwrap());
+        assert_eq!(0x9AAA, rebuilt.dv16);
+        assert_eq!(8, rebuilt.iv_hp());
+    }
+
+    #[test]
+    fn pk1_list_test() {
+        let data = pikachu(false);
+        let mut entity = PK1::from(&<[u8; SIZE_1ULIST]>::try_from(data.clone()).unwrap());
+        assert_eq!(data, entity.build().unwrap().to_bytes());
+
+        let data = pikachu(true);
+        let mut entity = PK1::from(&<[u8; SIZE_1JLIST]>::try_from(data.clone()).unwrap());
+        assert!(entity.japanese);
+        assert_eq!("サトシ", entity.ot_name);
+        assert_eq!("ピカチュウ", entity.nickname);
+        assert_eq!(data, entity.build().unwrap().to_bytes());
+
+        entity.species = Species::Raichu;
+        let rebuilt = entity.build().unwrap().to_bytes();
+        assert_eq!(SIZE_1JLIST, rebuilt.len());
+        assert_eq!([1, 85, 0xFF, 85], rebuilt[..4]);
+    }
+
+    #[test]
+    fn pk1_move_out_of_range_test() {
+        let mut entity = PK1::from(&<[u8; SIZE_1ULIST]>::try_from(pikachu(false)).unwrap());
+        entity.move2 = Move::MudSport;
+        assert!(matches!(entity.build(), Err(PkmError::Deku(DekuError::InvalidParam(_)))));
+    }
+
+    #[test]
+    fn pk1_from_stored_test() {
+        let list = pikachu(false);
+        let stored = <[u8; SIZE_1STORED]>::try_from(&list[3..3 + SIZE_1STORED]).unwrap();
+        let pikachu = PK1::from(&stored);
+        assert_eq!(Species::Pikachu, pikachu.species);
+        assert_eq!(0, pikachu.stat_level);
+        assert_eq!("", pikachu.nickname);
+    }
+}
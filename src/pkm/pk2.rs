@@ -0,0 +1,352 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{format, string::String, vec::Vec};
+use core::convert::TryFrom;
+use deku::prelude::*;
+
+use crate::{
+    error::PkmError, game::enums::{
+        game_version::GameVersion, gender::Gender, moves::Move, nature::Nature, species::Species
+    }, pkm::{
+        pk1::{get_gender_dv, get_hp_dv, is_shiny_dv, read_list, write_list}, strings::string_converter12::{get_string1, set_string1}, util::{
+            experience::get_nature_vc, pokecrypto::{SIZE_2JLIST, SIZE_2PARTY, SIZE_2STORED, SIZE_2ULIST}
+        }
+    }, util::{
+        custom_read_write::{read, write}, packutil::pack_u32
+    }
+};
+
+pub use crate::pkm::pk1::{
+    MAX_EV, MAX_IV, NICK_LENGTH, NICK_LENGTH_JP, OT_LENGTH, OT_LENGTH_JP, STRING_LENGTH, STRING_LENGTH_JP
+};
+
+pub const FORMAT: i32 = 2;
+
+/// Species list value used in place of the species for eggs.
+const EGG_LIST_SPECIES: u8 = 0xFD;
+
+/// Single entity list storage. International lists are [`SIZE_2ULIST`] bytes
+/// and Japanese lists are [`SIZE_2JLIST`] bytes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawPK2 {
+    data: Vec<u8>,
+}
+
+impl RawPK2 {
+    pub fn to_bytes(&self) -> Vec<u8> { self.data.clone() }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "big")]
+#[repr(C)]
+pub struct PK2 {
+    #[deku(update = "(self.species as u8)")]
+    pub species_id2: u8,
+    #[deku(skip, default = "Species::n(*species_id2 as u16).unwrap_or_default()")]
+    pub species: Species,
+    /// Item index in the Generation 2 item list.
+    pub held_item: u8,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move1)"
+    )]
+    pub move1: Move,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move2)"
+    )]
+    pub move2: Move,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move3)"
+    )]
+    pub move3: Move,
+    #[deku(
+        reader = "read::read_move_u8(deku::rest)",
+        writer = "write::write_move_u8(deku::output, self.move4)"
+    )]
+    pub move4: Move,
+    pub tid: u16,
+    #[deku(bits = 24)]
+    pub exp: u32,
+    pub ev_hp: u16,
+    pub ev_atk: u16,
+    pub ev_def: u16,
+    pub ev_spe: u16,
+    pub ev_spc: u16,
+    #[deku(
+        update = "(pack_u32(&[(self.iv_spc, 4), (self.iv_spe, 4), (self.iv_def, 4), (self.iv_atk, 4)]) as u16)"
+    )]
+    pub dv16: u16,
+    #[deku(skip, default = "((*dv16 >> 12) & 0xF) as u8")]
+    pub iv_atk: u8,
+    #[deku(skip, default = "((*dv16 >> 8) & 0xF) as u8")]
+    pub iv_def: u8,
+    #[deku(skip, default = "((*dv16 >> 4) & 0xF) as u8")]
+    pub iv_spe: u8,
+    #[deku(skip, default = "(*dv16 & 0xF) as u8")]
+    pub iv_spc: u8,
+    #[deku(bits = 2)]
+    pub move_1_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move1_pp: u8,
+    #[deku(bits = 2)]
+    pub move_2_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move2_pp: u8,
+    #[deku(bits = 2)]
+    pub move_3_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move3_pp: u8,
+    #[deku(bits = 2)]
+    pub move_4_pp_ups: u8,
+    #[deku(bits = 6)]
+    pub move4_pp: u8,
+    /// Friendship, or the remaining egg cycles while an egg.
+    pub ot_friendship: u8,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    // Caught Data (Crystal only)
+    #[deku(bits = 2)]
+    pub met_time_of_day: u8,
+    #[deku(bits = 6)]
+    pub met_level: u8,
+    #[deku(bits = 1)]
+    pub ot_gender: Gender,
+    #[deku(bits = 7)]
+    pub met_location: u8,
+    pub stat_level: u8,
+
+    // Party Stats
+    pub status_condition: u8,
+    pub unk_21: u8,
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+
+    // List Storage
+    #[deku(skip)]
+    pub ot_name: String,
+    #[deku(skip)]
+    pub nickname: String,
+    /// Whether the entity uses the Japanese character table and list layout.
+    #[deku(skip)]
+    pub japanese: bool,
+    /// Whether the entity is listed as an egg.
+    #[deku(skip)]
+    pub is_egg: bool,
+    #[deku(
+        skip,
+        default = "if *met_level != 0 || *met_location != 0 { GameVersion::C } else { GameVersion::GSC }"
+    )]
+    pub version: GameVersion,
+    #[deku(skip, default = "Self::get_generation(*version, 0)")]
+    pub generation: i32,
+}
+
+impl PK2 {
+    /// Gets the HP DV, which is derived from the other DVs.
+    pub fn iv_hp(&self) -> u8 { get_hp_dv(self.iv_atk, self.iv_def, self.iv_spe, self.iv_spc) }
+
+    /// Checks if the entity is shiny, which Generation 2 derives from the DVs.
+    pub fn is_shiny(&self) -> bool {
+        is_shiny_dv(self.iv_atk, self.iv_def, self.iv_spe, self.iv_spc)
+    }
+
+    /// Gets the gender, which Generation 2 derives from the Attack DV.
+    ///
+    /// # Arguments
+    ///
+    /// * `gender_ratio` - Gender ratio of the species, where 0 is male-only,
+    ///   254 is female-only and 255 is genderless.
+    pub fn gender(&self, gender_ratio: u8) -> Gender { get_gender_dv(self.iv_atk, gender_ratio) }
+
+    /// Gets the nature assigned when transferred from the Virtual Console.
+    pub fn nature(&self) -> Nature { Nature::n(get_nature_vc(self.exp) as u8).unwrap() }
+
+    /// Checks if the entity has Crystal caught data.
+    pub fn has_caught_data(&self) -> bool { self.met_level != 0 || self.met_location != 0 }
+
+    fn string_length(&self) -> usize {
+        if self.japanese {
+            STRING_LENGTH_JP
+        } else {
+            STRING_LENGTH
+        }
+    }
+
+    fn from_list(data: &[u8], japanese: bool) -> Self {
+        let string_length = if japanese { STRING_LENGTH_JP } else { STRING_LENGTH };
+        let (species, entity, ot_name, nickname) = read_list(data, SIZE_2PARTY, string_length);
+        let mut file = PK2::from(&<[u8; SIZE_2PARTY]>::try_from(entity).unwrap());
+        file.ot_name = get_string1(ot_name, japanese);
+        file.nickname = get_string1(nickname, japanese);
+        file.japanese = japanese;
+        file.is_egg = species == EGG_LIST_SPECIES;
+        file
+    }
+}
+
+impl PKM for PK2 {
+    type RawVariant = RawPK2;
+
+    fn get_string(data: &[u16]) -> String {
+        get_string1(&data.iter().map(|&c| c as u8).collect::<Vec<u8>>(), false)
+    }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string1(data.as_ref(), max_length, self.string_length(), self.japanese)
+            .into_iter()
+            .map(u16::from)
+            .collect()
+    }
+
+    fn build(&mut self) -> Result<RawPK2, PkmError> {
+        self.refresh_checksum();
+        let string_length = self.string_length();
+        let (nick_length, ot_length) =
+            if self.japanese { (NICK_LENGTH_JP, OT_LENGTH_JP) } else { (NICK_LENGTH, OT_LENGTH) };
+        Ok(RawPK2 {
+            data: write_list(
+                if self.is_egg { EGG_LIST_SPECIES } else { self.species_id2 },
+                &self.to_bytes()?,
+                &set_string1(&self.ot_name, ot_length, string_length, self.japanese),
+                &set_string1(&self.nickname, nick_length, string_length, self.japanese),
+            ),
+        })
+    }
+}
+
+impl From<&[u8; SIZE_2PARTY]> for PK2 {
+    fn from(data: &[u8; SIZE_2PARTY]) -> Self {
+        let (_rest, file) = PK2::from_bytes((data.as_ref(), 0)).unwrap();
+        file
+    }
+}
+
+impl From<&[u8; SIZE_2STORED]> for PK2 {
+    fn from(data: &[u8; SIZE_2STORED]) -> Self {
+        let mut array = [0u8; SIZE_2PARTY];
+        array[..SIZE_2STORED].copy_from_slice(data);
+        PK2::from(&array)
+    }
+}
+
+impl From<&[u8; SIZE_2ULIST]> for PK2 {
+    fn from(data: &[u8; SIZE_2ULIST]) -> Self { PK2::from_list(data, false) }
+}
+
+impl From<&[u8; SIZE_2JLIST]> for PK2 {
+    fn from(data: &[u8; SIZE_2JLIST]) -> Self { PK2::from_list(data, true) }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn togepi(japanese: bool, egg: bool) -> Vec<u8> {
+        let mut entity = [0u8; SIZE_2PARTY];
+        entity[0x00] = Species::Togepi as u8;
+        entity[0x01] = 0x52; // King's Rock
+        entity[0x02] = Move::Growl as u8;
+        entity[0x03] = Move::Charm as u8;
+        entity[0x06..0x08].copy_from_slice(&1337u16.to_be_bytes());
+        entity[0x08..0x0B].copy_from_slice(&125u32.to_be_bytes()[1..]);
+        entity[0x0B..0x0D].copy_from_slice(&1024u16.to_be_bytes());
+        entity[0x15..0x17].copy_from_slice(&0x3AAAu16.to_be_bytes());
+        entity[0x17] = 0x40 | 40;
+        entity[0x1B] = if egg { 10 } else { 70 };
+        entity[0x1C] = 0x12;
+        entity[0x1D] = (1 << 6) | 5; // Morning, level 5
+        entity[0x1E] = 0x80 | 16; // Female OT, Goldenrod City
+        entity[0x1F] = 5;
+        entity[0x22..0x24].copy_from_slice(&20u16.to_be_bytes());
+        entity[0x2E..0x30].copy_from_slice(&13u16.to_be_bytes());
+        let string_length = if japanese { STRING_LENGTH_JP } else { STRING_LENGTH };
+        let (ot_name, nickname) =
+            if japanese { ("コトネ", "トゲピー") } else { ("KRIS", "TOGEPI") };
+        write_list(
+            if egg { EGG_LIST_SPECIES } else { Species::Togepi as u8 },
+            &entity,
+            &set_string1(ot_name, 7, string_length, japanese),
+            &set_string1(nickname, 10, string_length, japanese),
+        )
+    }
+
+    #[test]
+    fn pk2_get_test() {
+        let togepi = PK2::from(&<[u8; SIZE_2ULIST]>::try_from(togepi(false, false)).unwrap());
+        assert_eq!(Species::Togepi, togepi.species);
+        assert_eq!(0x52, togepi.held_item);
+        assert_eq!(Move::Growl, togepi.move1);
+        assert_eq!(Move::Charm, togepi.move2);
+        assert_eq!(1337, togepi.tid);
+        assert_eq!(125, togepi.exp);
+        assert_eq!(1024, togepi.ev_hp);
+        assert_eq!((3, 10, 10, 10), (togepi.iv_atk, togepi.iv_def, togepi.iv_spe, togepi.iv_spc));
+        assert_eq!((1, 40), (togepi.move_1_pp_ups, togepi.move1_pp));
+        assert_eq!(70, togepi.ot_friendship);
+        assert_eq!((1, 2), (togepi.pkrs_strain, togepi.pkrs_days));
+        assert_eq!(1, togepi.met_time_of_day);
+        assert_eq!(5, togepi.met_level);
+        assert_eq!(Gender::Female, togepi.ot_gender);
+        assert_eq!(16, togepi.met_location);
+        assert_eq!(5, togepi.stat_level);
+        assert_eq!(13, togepi.stat_spd);
+        assert_eq!("KRIS", togepi.ot_name);
+        assert_eq!("TOGEPI", togepi.nickname);
+        assert!(!togepi.is_egg);
+        assert!(togepi.has_caught_data());
+        assert_eq!(GameVersion::C, togepi.version);
+        assert_eq!(2, togepi.generation);
+    }
+
+    #[test]
+    fn pk2_dv_test() {
+        let mut togepi = PK2::from(&<[u8; SIZE_2ULIST]>::try_from(togepi(false, false)).unwrap());
+        assert!(togepi.is_shiny());
+        assert_eq!(Gender::Male, togepi.gender(31));
+        assert_eq!(Gender::Female, togepi.gender(63));
+        assert_eq!(Gender::Female, togepi.gender(254));
+        assert_eq!(Nature::Hardy, togepi.nature());
+
+        togepi.iv_def = 11;
+        assert!(!togepi.is_shiny());
+        assert_eq!(get_hp_dv(3, 11, 10, 10), togepi.iv_hp());
+    }
+
+    #[test]
+    fn pk2_list_test() {
+        let data = togepi(false, false);
+        let mut entity = PK2::from(&<[u8; SIZE_2ULIST]>::try_from(data.clone()).unwrap());
+        assert_eq!(data, entity.build().unwrap().to_bytes());
+
+        let data = togepi(true, true);
+        let mut egg = PK2::from(&<[u8; SIZE_2JLIST]>::try_from(data.clone()).unwrap());
+        assert!(egg.japanese);
+        assert!(egg.is_egg);
+        assert_eq!("コトネ", egg.ot_name);
+        assert_eq!("トゲピー", egg.nickname);
+        assert_eq!(data, egg.build().unwrap().to_bytes());
+
+        egg.is_egg = false;
+        let rebuilt = egg.build().unwrap().to_bytes();
+        assert_eq!(SIZE_2JLIST, rebuilt.len());
+        assert_eq!([1, Species::Togepi as u8, 0xFF], rebuilt[..3]);
+    }
+
+    #[test]
+    fn pk2_from_stored_test() {
+        let list = togepi(false, false);
+        let stored = <[u8; SIZE_2STORED]>::try_from(&list[3..3 + SIZE_2STORED]).unwrap();
+        let togepi = PK2::from(&stored);
+        assert_eq!(Species::Togepi, togepi.species);
+        assert_eq!(0, togepi.stat_hp_max);
+    }
+}
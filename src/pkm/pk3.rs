@@ -0,0 +1,556 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitSlice, BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ball::Ball, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species, stat::Stat
+    }, pkm::{
+        strings::string_converter3::{get_string3, set_string3}, util::{
+            pokecrypto::{decrypt_if_encrypted3, get_chk3, SIZE_3PARTY, SIZE_3STORED}, species_converter::{get_g3_species, get_g4_species}
+        }
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 3;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 255;
+pub const OT_LENGTH: usize = 7;
+pub const NICK_LENGTH: usize = 10;
+pub const OT_LENGTH_JP: usize = 5;
+pub const NICK_LENGTH_JP: usize = 5;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPK3 {
+    data: [u8; SIZE_3PARTY],
+}
+
+impl RawPK3 {
+    pub fn to_bytes(&self) -> [u8; SIZE_3PARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PK3 {
+    pub pid: u32,
+    pub tid: u16,
+    pub sid: u16,
+    #[deku(
+        reader = "read::read_string_u8(deku::rest, NICK_LENGTH, Self::string_decoder(Self::peek_language(deku::rest)))",
+        writer = "write::write_string_u8(deku::output, self.encode_string(&self.nickname, NICK_LENGTH, NICK_LENGTH_JP))"
+    )]
+    pub nickname: String,
+    pub language: LanguageID,
+    #[deku(bits = 5)]
+    pub unk_13: u8,
+    #[deku(bits = 1)]
+    pub flag_is_egg: bool,
+    #[deku(bits = 1)]
+    pub flag_has_species: bool,
+    #[deku(bits = 1)]
+    pub flag_is_bad_egg: bool,
+    #[deku(
+        reader = "read::read_string_u8(deku::rest, OT_LENGTH, Self::string_decoder(*language))",
+        writer = "write::write_string_u8(deku::output, self.encode_string(&self.ot_name, OT_LENGTH, OT_LENGTH_JP))"
+    )]
+    pub ot_name: String,
+    pub mark_value: u8,
+    #[deku(update = "get_chk3::<SIZE_3PARTY>(&to_array(self.to_bytes()?)?)")]
+    pub checksum: u16,
+    pub sanity: u16,
+
+    // Growth
+    #[deku(update = "get_g3_species(self.species)")]
+    pub species_id3: u16,
+    #[deku(skip, default = "get_g4_species(*species_id3)")]
+    pub species: Species,
+    /// Item index in the Generation 3 item list.
+    pub held_item: u16,
+    pub exp: u32,
+    #[deku(bits = 2)]
+    pub move_4_pp_ups: u8,
+    #[deku(bits = 2)]
+    pub move_3_pp_ups: u8,
+    #[deku(bits = 2)]
+    pub move_2_pp_ups: u8,
+    #[deku(bits = 2)]
+    pub move_1_pp_ups: u8,
+    pub ot_friendship: u8,
+    pub unk_2a: u16,
+
+    // Attacks
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+
+    // EVs & Contest Stats
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+
+    // Miscellaneous
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    pub met_location: u8,
+    /// Met level, version, ball and trainer gender, read through their
+    /// accessors.
+    pub origins: u16,
+    pub iv32: u32,
+    /// Contest ribbon tiers (3 bits each), then the other ribbons, with the
+    /// fateful encounter flag in the top bit.
+    pub raw_ribbons: u32,
+
+    // Party Stats
+    pub status_condition: i32,
+    pub stat_level: u8,
+    pub unk_55: u8,
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+}
+
+impl PK3 {
+    /// Gets the nature, which Generation 3 derives from the PID.
+    pub fn nature(&self) -> Nature { Nature::n((self.pid % 25) as u8).unwrap() }
+
+    /// Gets the level the entity was met at.
+    pub fn met_level(&self) -> u8 { (self.origins & 0x7F) as u8 }
+
+    pub fn set_met_level(&mut self, value: u8) {
+        self.origins = (self.origins & !0x7F) | (value as u16 & 0x7F);
+    }
+
+    /// Gets the version the entity originated from, or an error holding the
+    /// raw value if it is not a known version.
+    pub fn version(&self) -> Result<GameVersion, PkmError> {
+        let value = (self.origins >> 7) & 0xF;
+        GameVersion::n(value as i32).ok_or(PkmError::InvalidField {
+            field: "version",
+            offset: 0x46,
+            value: value as u32,
+        })
+    }
+
+    /// Sets the origin version, which must fit in the 4 bits available.
+    pub fn set_version(&mut self, version: GameVersion) -> Result<(), PkmError> {
+        let value = version as i32;
+        if !(0..=0xF).contains(&value) {
+            return Err(PkmError::InvalidField {
+                field: "version",
+                offset: 0x46,
+                value: value as u32,
+            });
+        }
+        self.origins = (self.origins & !(0xF << 7)) | ((value as u16) << 7);
+        Ok(())
+    }
+
+    /// Gets the ball the entity was caught in. Every 4 bit value is a ball.
+    pub fn ball(&self) -> Ball { Ball::n(((self.origins >> 11) & 0xF) as u8).unwrap() }
+
+    /// Sets the ball, which must be one of the balls available in Generation 3
+    /// or 4.
+    pub fn set_ball(&mut self, ball: Ball) -> Result<(), PkmError> {
+        let value = ball as u16;
+        if value > 0xF {
+            return Err(PkmError::InvalidField {
+                field: "ball",
+                offset: 0x46,
+                value: value as u32,
+            });
+        }
+        self.origins = (self.origins & !(0xF << 11)) | (value << 11);
+        Ok(())
+    }
+
+    pub fn ot_gender(&self) -> Gender {
+        if (self.origins >> 15) == 1 {
+            Gender::Female
+        } else {
+            Gender::Male
+        }
+    }
+
+    pub fn set_ot_gender(&mut self, gender: Gender) {
+        self.origins = (self.origins & 0x7FFF) | (((gender == Gender::Female) as u16) << 15);
+    }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    /// Selects the second of the species' two abilities.
+    pub fn ability_bit(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_ability_bit(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    pub fn fateful_encounter(&self) -> bool { (self.raw_ribbons >> 31) & 1 == 1 }
+    pub fn set_fateful_encounter(&mut self, value: bool) {
+        self.raw_ribbons = (self.raw_ribbons & 0x7FFF_FFFF) | ((value as u32) << 31);
+    }
+
+    /// Gets the generation the entity originated from, or -1 if the version is
+    /// not known.
+    pub fn generation(&self) -> i32 {
+        self.version().map_or(-1, |version| Self::get_generation(version, self.met_location as u16))
+    }
+
+    /// Checks if the entity uses the Japanese character table.
+    pub fn is_japanese(&self) -> bool { self.language == LanguageID::Japanese }
+
+    /// Checks if the entity originated from Pokémon Ruby or Sapphire.
+    pub fn is_rs_origin(&self) -> bool {
+        matches!(self.version(), Ok(version) if Self::is_rs(version))
+    }
+
+    /// Checks if the entity originated from Pokémon Emerald.
+    pub fn is_e_origin(&self) -> bool {
+        matches!(self.version(), Ok(version) if Self::is_e(version))
+    }
+
+    /// Checks if the entity originated from Pokémon FireRed or LeafGreen.
+    pub fn is_frlg_origin(&self) -> bool {
+        matches!(self.version(), Ok(version) if Self::is_frlg(version))
+    }
+
+    /// Checks if the entity originated from Pokémon Colosseum or XD.
+    pub fn is_cxd_origin(&self) -> bool { self.version() == Ok(GameVersion::CXD) }
+
+    /// Reads the language that follows the nickname, which selects the
+    /// character table used by both trainer and entity names.
+    fn peek_language(rest: &BitSlice<Msb0, u8>) -> LanguageID {
+        rest.get(NICK_LENGTH * 8..)
+            .and_then(|rest| LanguageID::read(rest, Endian::Little).ok())
+            .map_or(LanguageID::default(), |(_, language)| language)
+    }
+
+    fn string_decoder(language: LanguageID) -> impl Fn(&[u8]) -> String {
+        move |data| get_string3(data, language == LanguageID::Japanese)
+    }
+
+    fn encode_string(&self, data: &str, max_length: usize, max_length_jp: usize) -> Vec<u8> {
+        let japanese = self.is_japanese();
+        set_string3(data, if japanese { max_length_jp } else { max_length }, max_length, japanese)
+    }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_3PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PK3::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PK3 {
+    type RawVariant = RawPK3;
+
+    fn get_string(data: &[u16]) -> String {
+        get_string3(&data.iter().map(|&c| c as u8).collect::<Vec<u8>>(), false)
+    }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        let japanese = self.is_japanese();
+        set_string3(data.as_ref(), max_length, max_length, japanese)
+            .into_iter()
+            .map(u16::from)
+            .collect()
+    }
+
+    fn build(&mut self) -> Result<RawPK3, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPK3 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_3PARTY]> for PK3 {
+    fn from(data: &[u8; SIZE_3PARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted3(&mut array);
+        PK3::read_bytes(&array).unwrap()
+    }
+}
+
+impl From<&[u8; SIZE_3STORED]> for PK3 {
+    fn from(data: &[u8; SIZE_3STORED]) -> Self {
+        let mut stored = *data;
+        decrypt_if_encrypted3(&mut stored);
+        let mut array = [0u8; SIZE_3PARTY];
+        array[..SIZE_3STORED].copy_from_slice(&stored);
+        PK3::from(&array)
+    }
+}
+
+impl TryFrom<&[u8]> for PK3 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, rejecting data
+    /// whose stored checksum does not match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array = [0u8; SIZE_3PARTY];
+        match data.len() {
+            SIZE_3PARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted3(&mut array);
+            }
+            SIZE_3STORED => {
+                let mut stored: [u8; SIZE_3STORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted3(&mut stored);
+                array[..SIZE_3STORED].copy_from_slice(&stored);
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_3STORED, SIZE_3PARTY],
+                    actual,
+                })
+            }
+        }
+
+        let stored = u16::from_le_bytes([array[0x1C], array[0x1D]]);
+        let calculated = get_chk3::<SIZE_3PARTY>(&array);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PK3::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::util::pokecrypto::encrypt_array3;
+
+    fn torchic() -> [u8; SIZE_3PARTY] {
+        let mut data = [0u8; SIZE_3PARTY];
+        data[0x00..0x04].copy_from_slice(&0x5A3C_1E07u32.to_le_bytes());
+        data[0x04..0x06].copy_from_slice(&31337u16.to_le_bytes());
+        data[0x06..0x08].copy_from_slice(&4242u16.to_le_bytes());
+        data[0x08..0x12].copy_from_slice(&set_string3("TORCHIC", 10, 10, false));
+        data[0x12] = LanguageID::English as u8;
+        data[0x13] = 0b0000_0010; // Has species
+        data[0x14..0x1B].copy_from_slice(&set_string3("MAY", 7, 7, false));
+        data[0x20..0x22].copy_from_slice(&280u16.to_le_bytes()); // Torchic
+        data[0x22..0x24].copy_from_slice(&13u16.to_le_bytes());
+        data[0x24..0x28].copy_from_slice(&135u32.to_le_bytes());
+        data[0x28] = 0b1100_0001; // Move 1 and 4 PP Ups
+        data[0x29] = 70;
+        data[0x2C..0x2E].copy_from_slice(&(Move::Scratch as u16).to_le_bytes());
+        data[0x2E..0x30].copy_from_slice(&(Move::Growl as u16).to_le_bytes());
+        data[0x34] = 35;
+        data[0x3C] = 252;
+        data[0x44] = 0x13;
+        data[0x45] = 16;
+        data[0x46..0x48].copy_from_slice(
+            &((1u16 << 15) | ((Ball::Poke as u16) << 11) | ((GameVersion::E as u16) << 7) | 5)
+                .to_le_bytes(),
+        );
+        data[0x48..0x4C].copy_from_slice(&0xBE00_001Fu32.to_le_bytes());
+        data[0x4C..0x50].copy_from_slice(&0x8000_0001u32.to_le_bytes());
+        data[0x54] = 5;
+        data[0x56..0x58].copy_from_slice(&20u16.to_le_bytes());
+        data[0x62..0x64].copy_from_slice(&11u16.to_le_bytes());
+        let chk = get_chk3::<SIZE_3PARTY>(&data);
+        data[0x1C..0x1E].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pk3_get_test() {
+        let torchic = PK3::from(&torchic());
+        assert_eq!(31337, torchic.tid);
+        assert_eq!(4242, torchic.sid);
+        assert_eq!("TORCHIC", torchic.nickname);
+        assert_eq!(LanguageID::English, torchic.language);
+        assert!(torchic.flag_has_species);
+        assert!(!torchic.flag_is_bad_egg);
+        assert_eq!("MAY", torchic.ot_name);
+        assert_eq!(280, torchic.species_id3);
+        assert_eq!(Species::Torchic, torchic.species);
+        assert_eq!(135, torchic.exp);
+        assert_eq!(
+            (1, 0, 0, 3),
+            (
+                torchic.move_1_pp_ups,
+                torchic.move_2_pp_ups,
+                torchic.move_3_pp_ups,
+                torchic.move_4_pp_ups
+            )
+        );
+        assert_eq!(Move::Scratch, torchic.move1);
+        assert_eq!(Move::Growl, torchic.move2);
+        assert_eq!(35, torchic.move1_pp);
+        assert_eq!(252, torchic.ev_spa);
+        assert_eq!((1, 3), (torchic.pkrs_strain, torchic.pkrs_days));
+        assert_eq!(16, torchic.met_location);
+        assert_eq!(5, torchic.met_level());
+        assert_eq!(Ok(GameVersion::E), torchic.version());
+        assert_eq!(Ball::Poke, torchic.ball());
+        assert_eq!(Gender::Female, torchic.ot_gender());
+        assert_eq!(
+            (31, 0, 31),
+            (torchic.iv(Stat::HP), torchic.iv(Stat::Atk), torchic.iv(Stat::SpD))
+        );
+        assert!(!torchic.is_egg());
+        assert!(torchic.ability_bit());
+        assert!(torchic.fateful_encounter());
+        assert_eq!(5, torchic.stat_level);
+        assert_eq!(11, torchic.stat_spd);
+        assert_eq!(Nature::Serious, torchic.nature());
+    }
+
+    #[test]
+    fn pk3_generation_test() {
+        let mut torchic = PK3::from(&torchic());
+        assert_eq!(3, torchic.generation());
+        assert!(torchic.is_e_origin());
+        assert!(!torchic.is_frlg_origin());
+
+        torchic.set_version(GameVersion::LG).unwrap();
+        let torchic = PK3::from(&torchic.build().unwrap().to_bytes());
+        assert_eq!(3, torchic.generation());
+        assert!(torchic.is_frlg_origin());
+        assert!(!torchic.is_rs_origin());
+    }
+
+    #[test]
+    fn pk3_from_encrypted_test() {
+        let mut pk3 = torchic();
+        let ek3 = encrypt_array3(&mut pk3);
+        assert_ne!(pk3, ek3);
+        assert_eq!(PK3::from(&pk3), PK3::from(&ek3));
+
+        let mut stored = <[u8; SIZE_3STORED]>::try_from(&pk3[..SIZE_3STORED]).unwrap();
+        let from_stored = PK3::from(&stored);
+        assert_eq!(from_stored, PK3::from(&encrypt_array3(&mut stored)));
+        assert_eq!(0, from_stored.stat_level);
+    }
+
+    #[test]
+    fn pk3_try_from_test() {
+        let mut data = torchic();
+        let party = PK3::try_from(&data[..]).unwrap();
+        assert_eq!(PK3::from(&data), party);
+        assert_eq!(party.nickname, PK3::try_from(&data[..SIZE_3STORED]).unwrap().nickname);
+        assert_eq!(party, PK3::try_from(&encrypt_array3(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_3STORED, SIZE_3PARTY],
+                actual: 0x60
+            }),
+            PK3::try_from(&data[..0x60])
+        );
+        data[0x20] ^= 1;
+        assert!(matches!(PK3::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pk3_round_trip_test() {
+        let data = torchic();
+        let mut torchic = PK3::from(&data);
+        assert_eq!(data, torchic.build().unwrap().to_bytes());
+
+        torchic.species = Species::Combusken;
+        torchic.set_ball(Ball::Premier).unwrap();
+        let rebuilt = PK3::from(&torchic.build().unwrap().to_bytes());
+        assert_eq!(281, rebuilt.species_id3);
+        assert_eq!(Species::Combusken, rebuilt.species);
+        assert_eq!(Ball::Premier, rebuilt.ball());
+        assert!(rebuilt.fateful_encounter());
+
+        torchic.set_fateful_encounter(false);
+        torchic.set_iv(Stat::Atk, 12);
+        torchic.set_ability_bit(false);
+        let rebuilt = torchic.build().unwrap().to_bytes();
+        assert_eq!(
+            0x0000_0001,
+            u32::from_le_bytes([rebuilt[0x4C], rebuilt[0x4D], rebuilt[0x4E], rebuilt[0x4F]])
+        );
+        assert_eq!(
+            0x3E00_019F,
+            u32::from_le_bytes([rebuilt[0x48], rebuilt[0x49], rebuilt[0x4A], rebuilt[0x4B]])
+        );
+    }
+
+    #[test]
+    fn pk3_origins_test() {
+        let mut data = torchic();
+        data[0x46..0x48].copy_from_slice(&((9u16 << 7) | 5).to_le_bytes());
+        let chk = get_chk3::<SIZE_3PARTY>(&data);
+        data[0x1C..0x1E].copy_from_slice(&chk.to_le_bytes());
+
+        let mut torchic = PK3::from(&data);
+        assert_eq!(
+            Err(PkmError::InvalidField { field: "version", offset: 0x46, value: 9 }),
+            torchic.version()
+        );
+        assert_eq!(-1, torchic.generation());
+        assert!(!torchic.is_e_origin());
+        assert_eq!(data, torchic.build().unwrap().to_bytes());
+
+        assert!(torchic.set_version(GameVersion::SW).is_err());
+        assert!(torchic.set_ball(Ball::Cherish).is_err());
+        torchic.set_met_level(100);
+        torchic.set_ot_gender(Gender::Female);
+        assert_eq!(100, torchic.met_level());
+        assert_eq!(Gender::Female, torchic.ot_gender());
+        assert_eq!(Ball::None, torchic.ball());
+        assert_eq!(9, (torchic.origins >> 7) & 0xF);
+    }
+
+    #[test]
+    fn pk3_japanese_test() {
+        let mut data = torchic();
+        data[0x08..0x12].copy_from_slice(&set_string3("アチャモ", 5, 10, true));
+        data[0x12] = LanguageID::Japanese as u8;
+        data[0x14..0x1B].copy_from_slice(&set_string3("ハルカ", 5, 7, true));
+        let mut torchic = PK3::from(&data);
+        assert_eq!("アチャモ", torchic.nickname);
+        assert_eq!("ハルカ", torchic.ot_name);
+
+        torchic.nickname = String::from("アチャモアチャモ");
+        let rebuilt = torchic.build().unwrap().to_bytes();
+        assert_eq!([0x51, 0x61, 0x84, 0x73, 0x51, 0xFF], rebuilt[0x08..0x0E]);
+    }
+}
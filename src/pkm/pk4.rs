@@ -0,0 +1,520 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species, stat::Stat
+    }, legality::tables::location::{
+        is_pt_hgss_location, is_pt_hgss_location_egg, FARAWAY_4, POKEWALKER_4
+    }, pkm::{
+        strings::string_converter4::{get_string4, set_string4}, util::pokecrypto::{decrypt_if_encrypted45, get_chk, SIZE_4PARTY, SIZE_4STORED}
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 4;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 255;
+pub const OT_LENGTH: usize = 7;
+pub const NICK_LENGTH: usize = 10;
+
+/// Gets a Generation 4 location, preferring the Pt/HGSS value when present.
+pub(crate) fn get_location4(pt_hgss: u16, dp: u16) -> u16 {
+    if pt_hgss != 0 {
+        pt_hgss
+    } else {
+        dp
+    }
+}
+
+/// Splits a Generation 4 location into its (Pt/HGSS, DP) stored values.
+///
+/// Locations that do not exist in Diamond and Pearl are stored as "Faraway
+/// place" in the DP field so those games can still display them.
+pub(crate) fn split_location4(value: u16, pt_hgss_origin: bool) -> (u16, u16) {
+    match value {
+        0 => (0, 0),
+        _ if is_pt_hgss_location(value as i32) || is_pt_hgss_location_egg(value as i32) => {
+            (value, FARAWAY_4 as u16)
+        }
+        _ if pt_hgss_origin => (value, value),
+        _ => (0, value),
+    }
+}
+
+/// Gets a Generation 4 ball, preferring the HGSS value when present.
+pub(crate) fn get_ball4(dppt: Ball, hgss: Ball) -> Ball {
+    if hgss > dppt {
+        hgss
+    } else {
+        dppt
+    }
+}
+
+/// Splits a Generation 4 ball into its (DPPt, HGSS) stored values.
+///
+/// HeartGold and SoulSilver added balls that DPPt cannot display, so those
+/// are shown as a Poké Ball there. The HGSS value is only kept for non-event
+/// captures made in HeartGold or SoulSilver.
+pub(crate) fn split_ball4(value: Ball, hgss_capture: bool) -> (Ball, Ball) {
+    let dppt = if value <= Ball::Cherish { value } else { Ball::Poke };
+    let hgss = if hgss_capture { value } else { Ball::None };
+    (dppt, hgss)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPK4 {
+    data: [u8; SIZE_4PARTY],
+}
+
+impl RawPK4 {
+    pub fn to_bytes(&self) -> [u8; SIZE_4PARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PK4 {
+    pub pid: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk::<SIZE_4PARTY>(&to_array(self.to_bytes()?)?, SIZE_4STORED)")]
+    pub checksum: u16,
+
+    // Block A
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    pub ot_friendship: u8,
+    #[deku(
+        reader = "read::read_ability_u8(deku::rest)",
+        writer = "write::write_ability_u8(deku::output, self.ability)"
+    )]
+    pub ability: Ability,
+    pub mark_value: u8,
+    pub language: LanguageID,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+    // 0x24-0x27 Sinnoh Ribbons (Set 1)
+    pub raw_ribbons_sinnoh_1: [u8; 4],
+
+    // Block B
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub iv32: u32,
+    // 0x3C-0x3F Hoenn Ribbons
+    pub raw_ribbons_hoenn: [u8; 4],
+    #[deku(bits = 5)]
+    pub form: u8,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    #[deku(bits = 2)]
+    pub unk_41: u8,
+    #[deku(bits = 1)]
+    pub shiny_leaf_crown: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_e: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_d: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_c: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_b: bool,
+    #[deku(bits = 1)]
+    pub shiny_leaf_a: bool,
+    pub unk_42: u16,
+    pub egg_location_pt_hgss: u16,
+    pub met_location_pt_hgss: u16,
+
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub unk_5e: u8,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    // 0x60-0x63 Sinnoh Ribbons (Set 2)
+    pub raw_ribbons_sinnoh_2: [u8; 4],
+    pub unk_64: [u8; 4],
+
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    pub egg_location_dp: u16,
+    pub met_location_dp: u16,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    pub ball_dppt: Ball,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    pub encounter_type: u8,
+    pub ball_hgss: Ball,
+    pub pokeathlon_stat: u8,
+
+    // Party Stats
+    pub status_condition: i32,
+    pub stat_level: u8,
+    pub ball_capsule_index: u8,
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    #[deku(count = "0x38")]
+    pub mail: Vec<u8>,
+    pub seal_coordinates: [u8; 0x18],
+}
+
+impl PK4 {
+    /// Gets the nature, which Generation 4 derives from the PID.
+    pub fn nature(&self) -> Nature { Nature::n((self.pid % 25) as u8).unwrap() }
+
+    /// Checks if the entity originated from Pokémon Diamond, Pearl or
+    /// Platinum.
+    pub fn is_dppt_origin(&self) -> bool { Self::is_dppt(self.version) }
+
+    /// Checks if the entity originated from Pokémon HeartGold or SoulSilver.
+    pub fn is_hgss_origin(&self) -> bool { Self::is_hgss(self.version) }
+
+    /// Checks if the entity was received from the Pokéwalker.
+    pub fn is_pokewalker_origin(&self) -> bool {
+        self.is_hgss_origin() && self.met_location() == POKEWALKER_4 as u16
+    }
+
+    /// Gets the egg location as displayed by the game of origin.
+    pub fn egg_location(&self) -> u16 {
+        get_location4(self.egg_location_pt_hgss, self.egg_location_dp)
+    }
+
+    /// Sets the egg location, keeping the DP and Pt/HGSS fields consistent.
+    pub fn set_egg_location(&mut self, value: u16) {
+        let (pt_hgss, dp) = split_location4(value, Self::is_pt_hgss(self.version));
+        self.egg_location_pt_hgss = pt_hgss;
+        self.egg_location_dp = dp;
+    }
+
+    /// Gets the met location as displayed by the game of origin.
+    pub fn met_location(&self) -> u16 {
+        get_location4(self.met_location_pt_hgss, self.met_location_dp)
+    }
+
+    /// Sets the met location, keeping the DP and Pt/HGSS fields consistent.
+    pub fn set_met_location(&mut self, value: u16) {
+        let (pt_hgss, dp) = split_location4(value, Self::is_pt_hgss(self.version));
+        self.met_location_pt_hgss = pt_hgss;
+        self.met_location_dp = dp;
+    }
+
+    /// Gets the ball the entity was caught in.
+    pub fn ball(&self) -> Ball { get_ball4(self.ball_dppt, self.ball_hgss) }
+
+    /// Sets the ball the entity was caught in, keeping the DPPt and HGSS
+    /// fields consistent.
+    pub fn set_ball(&mut self, value: Ball) {
+        let hgss_capture = self.is_hgss_origin() && !self.fateful_encounter;
+        let (dppt, hgss) = split_ball4(value, hgss_capture);
+        self.ball_dppt = dppt;
+        self.ball_hgss = hgss;
+    }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location()) }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_4PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PK4::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PK4 {
+    type RawVariant = RawPK4;
+
+    fn get_string(data: &[u16]) -> String { get_string4(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string4(data.as_ref(), max_length)
+    }
+
+    fn build(&mut self) -> Result<RawPK4, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPK4 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_4PARTY]> for PK4 {
+    fn from(data: &[u8; SIZE_4PARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted45(&mut array);
+        PK4::read_bytes(&array).unwrap()
+    }
+}
+
+impl From<&[u8; SIZE_4STORED]> for PK4 {
+    fn from(data: &[u8; SIZE_4STORED]) -> Self {
+        let mut stored = *data;
+        decrypt_if_encrypted45(&mut stored);
+        let mut array = [0u8; SIZE_4PARTY];
+        array[..SIZE_4STORED].copy_from_slice(&stored);
+        PK4::from(&array)
+    }
+}
+
+impl TryFrom<&[u8]> for PK4 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, rejecting data
+    /// whose stored checksum does not match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array = [0u8; SIZE_4PARTY];
+        match data.len() {
+            SIZE_4PARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted45(&mut array);
+            }
+            SIZE_4STORED => {
+                let mut stored: [u8; SIZE_4STORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted45(&mut stored);
+                array[..SIZE_4STORED].copy_from_slice(&stored);
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_4STORED, SIZE_4PARTY],
+                    actual,
+                })
+            }
+        }
+
+        let stored = u16::from_le_bytes([array[0x06], array[0x07]]);
+        let calculated = get_chk::<SIZE_4PARTY>(&array, SIZE_4STORED);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PK4::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::util::{pokecrypto::encrypt_array45, testutil::write_string};
+
+    fn cyndaquil() -> [u8; SIZE_4PARTY] {
+        let mut data = [0u8; SIZE_4PARTY];
+        data[0x00..0x04].copy_from_slice(&0x2E4A_91C3u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&(Species::Cyndaquil as u16).to_le_bytes());
+        data[0x0C..0x0E].copy_from_slice(&13579u16.to_le_bytes());
+        data[0x14] = 70;
+        data[0x15] = Ability::Blaze as u8;
+        data[0x17] = LanguageID::English as u8;
+        data[0x1C] = 252;
+        data[0x28..0x2A].copy_from_slice(&(Move::Ember as u16).to_le_bytes());
+        data[0x38..0x3C].copy_from_slice(&0x0000_03FFu32.to_le_bytes());
+        data[0x40] = 1 << 1; // Female
+        data[0x41] = 0b0010_0101; // Leaves A, C and crown
+        data[0x46..0x48].copy_from_slice(&(POKEWALKER_4 as u16).to_le_bytes());
+        write_string(&mut data, 0x48, set_string4("Cyndaquil", 10), Endian::Little);
+        data[0x5F] = GameVersion::HG as u8;
+        write_string(&mut data, 0x68, set_string4("Lyra", 10), Endian::Little);
+        data[0x80..0x82].copy_from_slice(&(FARAWAY_4 as u16).to_le_bytes());
+        data[0x83] = Ball::Poke as u8;
+        data[0x84] = 0x80 | 5;
+        data[0x86] = Ball::Fast as u8;
+        data[0x87] = 0x12;
+        data[0x8C] = 5;
+        data[0x8D] = 3;
+        data[0x9A..0x9C].copy_from_slice(&12u16.to_le_bytes());
+        data[0xEB] = 0x40;
+        let chk = get_chk::<SIZE_4PARTY>(&data, SIZE_4STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pk4_get_test() {
+        let cyndaquil = PK4::from(&cyndaquil());
+        assert_eq!(Species::Cyndaquil, cyndaquil.species);
+        assert_eq!(13579, cyndaquil.tid);
+        assert_eq!(Ability::Blaze, cyndaquil.ability);
+        assert_eq!(LanguageID::English, cyndaquil.language);
+        assert_eq!(252, cyndaquil.ev_spa);
+        assert_eq!(Move::Ember, cyndaquil.move1);
+        assert_eq!(
+            (31, 31, 0),
+            (cyndaquil.iv(Stat::HP), cyndaquil.iv(Stat::Atk), cyndaquil.iv(Stat::Def))
+        );
+        assert!(!cyndaquil.is_egg());
+        assert_eq!(Gender::Female, cyndaquil.gender);
+        assert_eq!(Nature::Modest, cyndaquil.nature());
+        assert!(cyndaquil.shiny_leaf_a && cyndaquil.shiny_leaf_c && cyndaquil.shiny_leaf_crown);
+        assert!(!cyndaquil.shiny_leaf_b);
+        assert_eq!("Cyndaquil", cyndaquil.nickname);
+        assert_eq!("Lyra", cyndaquil.ot_name);
+        assert_eq!(GameVersion::HG, cyndaquil.version);
+        assert_eq!(Gender::Female, cyndaquil.ot_gender);
+        assert_eq!(5, cyndaquil.met_level);
+        assert_eq!(0x12, cyndaquil.pokeathlon_stat);
+        assert_eq!(5, cyndaquil.stat_level);
+        assert_eq!(3, cyndaquil.ball_capsule_index);
+        assert_eq!(12, cyndaquil.stat_spd);
+        assert_eq!(0x40, cyndaquil.seal_coordinates[0x17]);
+        assert_eq!(4, cyndaquil.generation());
+    }
+
+    #[test]
+    fn pk4_hgss_test() {
+        let mut cyndaquil = PK4::from(&cyndaquil());
+        assert!(cyndaquil.is_hgss_origin());
+        assert!(cyndaquil.is_pokewalker_origin());
+        assert_eq!(POKEWALKER_4 as u16, cyndaquil.met_location());
+        assert_eq!(Ball::Fast, cyndaquil.ball());
+
+        cyndaquil.set_ball(Ball::Dive);
+        assert_eq!((Ball::Dive, Ball::Dive), (cyndaquil.ball_dppt, cyndaquil.ball_hgss));
+        cyndaquil.set_ball(Ball::Sport);
+        assert_eq!((Ball::Poke, Ball::Sport), (cyndaquil.ball_dppt, cyndaquil.ball_hgss));
+        cyndaquil.fateful_encounter = true;
+        cyndaquil.set_ball(Ball::Cherish);
+        assert_eq!((Ball::Cherish, Ball::None), (cyndaquil.ball_dppt, cyndaquil.ball_hgss));
+
+        // Route 29 only exists in HGSS
+        cyndaquil.set_met_location(126);
+        assert_eq!(
+            (126, FARAWAY_4 as u16),
+            (cyndaquil.met_location_pt_hgss, cyndaquil.met_location_dp)
+        );
+        assert!(!cyndaquil.is_pokewalker_origin());
+
+        // Route 201 exists in DP, but HGSS still records the Pt/HGSS field
+        cyndaquil.set_met_location(16);
+        assert_eq!((16, 16), (cyndaquil.met_location_pt_hgss, cyndaquil.met_location_dp));
+
+        cyndaquil.version = GameVersion::D;
+        cyndaquil.set_egg_location(16);
+        assert_eq!((0, 16), (cyndaquil.egg_location_pt_hgss, cyndaquil.egg_location_dp));
+        assert_eq!(16, cyndaquil.egg_location());
+        assert!(cyndaquil.is_dppt_origin());
+    }
+
+    #[test]
+    fn pk4_from_encrypted_test() {
+        let mut pk4 = cyndaquil();
+        let ek4 = encrypt_array45(&mut pk4);
+        assert_ne!(pk4, ek4);
+        assert_eq!(PK4::from(&pk4), PK4::from(&ek4));
+
+        let mut stored = <[u8; SIZE_4STORED]>::try_from(&pk4[..SIZE_4STORED]).unwrap();
+        let from_stored = PK4::from(&stored);
+        assert_eq!(from_stored, PK4::from(&encrypt_array45(&mut stored)));
+        assert_eq!(0, from_stored.stat_level);
+    }
+
+    #[test]
+    fn pk4_try_from_test() {
+        let mut data = cyndaquil();
+        let party = PK4::try_from(&data[..]).unwrap();
+        assert_eq!(PK4::from(&data), party);
+        assert_eq!(party.ot_name, PK4::try_from(&data[..SIZE_4STORED]).unwrap().ot_name);
+        assert_eq!(party, PK4::try_from(&encrypt_array45(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_4STORED, SIZE_4PARTY],
+                actual: 0x100
+            }),
+            PK4::try_from(&[0u8; 0x100][..])
+        );
+        data[0x08] ^= 1;
+        assert!(matches!(PK4::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pk4_round_trip_test() {
+        let data = cyndaquil();
+        let mut cyndaquil = PK4::from(&data);
+        assert_eq!(data, cyndaquil.build().unwrap().to_bytes());
+
+        cyndaquil.shiny_leaf_b = true;
+        cyndaquil.set_iv(Stat::Def, 15);
+        cyndaquil.set_is_nicknamed(true);
+        let rebuilt = cyndaquil.build().unwrap().to_bytes();
+        assert_eq!(0b0010_0111, rebuilt[0x41]);
+        assert_eq!(
+            0x8000_3FFF,
+            u32::from_le_bytes([rebuilt[0x38], rebuilt[0x39], rebuilt[0x3A], rebuilt[0x3B]])
+        );
+        assert_eq!(PK4::from(&rebuilt).checksum, cyndaquil.checksum);
+    }
+}
@@ -0,0 +1,394 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species, stat::Stat
+    }, pkm::{
+        strings::string_converter::{get_string5, set_string5}, util::pokecrypto::{decrypt_if_encrypted45, get_chk, SIZE_5PARTY, SIZE_5STORED}
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 5;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 255;
+pub const OT_LENGTH: usize = 7;
+pub const NICK_LENGTH: usize = 10;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPK5 {
+    data: [u8; SIZE_5PARTY],
+}
+
+impl RawPK5 {
+    pub fn to_bytes(&self) -> [u8; SIZE_5PARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PK5 {
+    pub pid: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk::<SIZE_5PARTY>(&to_array(self.to_bytes()?)?, SIZE_5STORED)")]
+    pub checksum: u16,
+
+    // Block A
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    pub ot_friendship: u8,
+    #[deku(
+        reader = "read::read_ability_u8(deku::rest)",
+        writer = "write::write_ability_u8(deku::output, self.ability)"
+    )]
+    pub ability: Ability,
+    pub mark_value: u8,
+    pub language: LanguageID,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+    // 0x24-0x27 Sinnoh Ribbons (Set 1)
+    pub raw_ribbons_sinnoh_1: [u8; 4],
+
+    // Block B
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub iv32: u32,
+    // 0x3C-0x3F Hoenn Ribbons
+    pub raw_ribbons_hoenn: [u8; 4],
+    #[deku(bits = 5)]
+    pub form: u8,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    pub nature: Nature,
+    #[deku(bits = 6)]
+    pub unk_42: u8,
+    #[deku(bits = 1)]
+    pub n_sparkle: Flag,
+    #[deku(bits = 1)]
+    pub hidden_ability: Flag,
+    pub unk_43: u8,
+    pub unk_44: [u8; 4],
+
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub unk_5e: u8,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    // 0x60-0x63 Sinnoh Ribbons (Set 2)
+    pub raw_ribbons_sinnoh_2: [u8; 4],
+    pub unk_64: [u8; 4],
+
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    pub egg_location: u16,
+    pub met_location: u16,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    pub ball: Ball,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    pub encounter_type: u8,
+    pub unk_86: u8,
+    pub poke_star_fame: u8,
+
+    // Party Stats
+    pub status_condition: i32,
+    pub stat_level: u8,
+    pub unk_8d: u8,
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    #[deku(count = "SIZE_5PARTY - 0x9C")]
+    pub unk_9c: Vec<u8>,
+}
+
+impl PK5 {
+    /// Checks if the entity originated from Pokémon Black or White.
+    pub fn is_bw_origin(&self) -> bool { Self::is_bw(self.version) }
+
+    /// Checks if the entity originated from Pokémon Black 2 or White 2.
+    pub fn is_b2w2_origin(&self) -> bool { Self::is_b2w2(self.version) }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location) }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_5PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PK5::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PK5 {
+    type RawVariant = RawPK5;
+
+    fn get_string(data: &[u16]) -> String { get_string5(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string5(data.as_ref(), max_length)
+    }
+
+    fn build(&mut self) -> Result<RawPK5, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPK5 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_5PARTY]> for PK5 {
+    fn from(data: &[u8; SIZE_5PARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted45(&mut array);
+        PK5::read_bytes(&array).unwrap()
+    }
+}
+
+impl From<&[u8; SIZE_5STORED]> for PK5 {
+    fn from(data: &[u8; SIZE_5STORED]) -> Self {
+        let mut stored = *data;
+        decrypt_if_encrypted45(&mut stored);
+        let mut array = [0u8; SIZE_5PARTY];
+        array[..SIZE_5STORED].copy_from_slice(&stored);
+        PK5::from(&array)
+    }
+}
+
+impl TryFrom<&[u8]> for PK5 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, rejecting data
+    /// whose stored checksum does not match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array = [0u8; SIZE_5PARTY];
+        match data.len() {
+            SIZE_5PARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted45(&mut array);
+            }
+            SIZE_5STORED => {
+                let mut stored: [u8; SIZE_5STORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted45(&mut stored);
+                array[..SIZE_5STORED].copy_from_slice(&stored);
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_5STORED, SIZE_5PARTY],
+                    actual,
+                })
+            }
+        }
+
+        let stored = u16::from_le_bytes([array[0x06], array[0x07]]);
+        let calculated = get_chk::<SIZE_5PARTY>(&array, SIZE_5STORED);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PK5::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::util::{pokecrypto::encrypt_array45, testutil::write_string};
+
+    fn zorua() -> [u8; SIZE_5PARTY] {
+        let mut data = [0u8; SIZE_5PARTY];
+        data[0x00..0x04].copy_from_slice(&0x4B21_07D3u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&(Species::Zorua as u16).to_le_bytes());
+        data[0x0C..0x0E].copy_from_slice(&24680u16.to_le_bytes());
+        data[0x14] = 70;
+        data[0x15] = Ability::Illusion as u8;
+        data[0x17] = LanguageID::English as u8;
+        data[0x18] = 255;
+        data[0x28..0x2A].copy_from_slice(&(Move::Pursuit as u16).to_le_bytes());
+        data[0x38..0x3C].copy_from_slice(&0x8000_001Fu32.to_le_bytes());
+        data[0x40] = 1 << 1; // Female
+        data[0x41] = Nature::Timid as u8;
+        data[0x42] = 0b0000_0010; // N's Pokémon
+        write_string(&mut data, 0x48, set_string5("Zorua♀", 10), Endian::Little);
+        data[0x5F] = GameVersion::B2 as u8;
+        write_string(&mut data, 0x68, set_string5("N", 10), Endian::Little);
+        data[0x80..0x82].copy_from_slice(&30003u16.to_le_bytes());
+        data[0x82] = 0x21;
+        data[0x83] = Ball::Poke as u8;
+        data[0x84] = 10;
+        data[0x87] = 3;
+        data[0x8C] = 10;
+        data[0x9A..0x9C].copy_from_slice(&25u16.to_le_bytes());
+        data[0xDB] = 0x7F;
+        let chk = get_chk::<SIZE_5PARTY>(&data, SIZE_5STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pk5_get_test() {
+        let zorua = PK5::from(&zorua());
+        assert_eq!(Species::Zorua, zorua.species);
+        assert_eq!(24680, zorua.tid);
+        assert_eq!(70, zorua.ot_friendship);
+        assert_eq!(Ability::Illusion, zorua.ability);
+        assert_eq!(LanguageID::English, zorua.language);
+        assert_eq!(255, zorua.ev_hp);
+        assert_eq!(Move::Pursuit, zorua.move1);
+        assert_eq!(31, zorua.iv(Stat::HP));
+        assert_eq!(0, zorua.iv(Stat::SpD));
+        assert!(zorua.is_nicknamed());
+        assert_eq!(Gender::Female, zorua.gender);
+        assert_eq!(Nature::Timid, zorua.nature);
+        assert_eq!(Flag::Set, zorua.n_sparkle);
+        assert_eq!(Flag::Unset, zorua.hidden_ability);
+        assert_eq!("Zorua♀", zorua.nickname);
+        assert_eq!(GameVersion::B2, zorua.version);
+        assert_eq!("N", zorua.ot_name);
+        assert_eq!(30003, zorua.met_location);
+        assert_eq!((2, 1), (zorua.pkrs_strain, zorua.pkrs_days));
+        assert_eq!(Ball::Poke, zorua.ball);
+        assert_eq!(10, zorua.met_level);
+        assert_eq!(3, zorua.poke_star_fame);
+        assert_eq!(10, zorua.stat_level);
+        assert_eq!(25, zorua.stat_spd);
+    }
+
+    #[test]
+    fn pk5_generation_test() {
+        let mut zorua = PK5::from(&zorua());
+        assert_eq!(5, zorua.generation());
+        assert!(zorua.is_b2w2_origin());
+        assert!(!zorua.is_bw_origin());
+
+        zorua.version = GameVersion::W;
+        assert_eq!(5, zorua.generation());
+        assert!(zorua.is_bw_origin());
+
+        zorua.version = GameVersion::HG;
+        assert_eq!(4, zorua.generation());
+    }
+
+    #[test]
+    fn pk5_from_encrypted_test() {
+        let mut pk5 = zorua();
+        let ek5 = encrypt_array45(&mut pk5);
+        assert_ne!(pk5, ek5);
+        assert_eq!(PK5::from(&pk5), PK5::from(&ek5));
+
+        let mut stored = <[u8; SIZE_5STORED]>::try_from(&pk5[..SIZE_5STORED]).unwrap();
+        let from_stored = PK5::from(&stored);
+        assert_eq!(from_stored, PK5::from(&encrypt_array45(&mut stored)));
+        assert_eq!(0, from_stored.stat_level);
+    }
+
+    #[test]
+    fn pk5_try_from_test() {
+        let mut data = zorua();
+        let party = PK5::try_from(&data[..]).unwrap();
+        assert_eq!(PK5::from(&data), party);
+        assert_eq!(party.nickname, PK5::try_from(&data[..SIZE_5STORED]).unwrap().nickname);
+        assert_eq!(party, PK5::try_from(&encrypt_array45(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_5STORED, SIZE_5PARTY],
+                actual: 0x100
+            }),
+            PK5::try_from(&[0u8; 0x100][..])
+        );
+        data[0x08] ^= 1;
+        assert!(matches!(PK5::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pk5_round_trip_test() {
+        let data = zorua();
+        let mut zorua = PK5::from(&data);
+        assert_eq!(data, zorua.build().unwrap().to_bytes());
+
+        zorua.hidden_ability = Flag::Set;
+        zorua.set_iv(Stat::Spe, 7);
+        let rebuilt = zorua.build().unwrap().to_bytes();
+        assert_eq!(0b0000_0011, rebuilt[0x42]);
+        assert_eq!(PK5::from(&rebuilt).checksum, zorua.checksum);
+        assert_eq!(7, PK5::from(&rebuilt).iv(Stat::Spe));
+        assert!(PK5::from(&rebuilt).is_nicknamed());
+    }
+}
@@ -0,0 +1,637 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, region_id::RegionID, species::Species, stat::Stat
+    }, pkm::{
+        strings::string_converter::{get_string6, set_string6}, util::pokecrypto::{decrypt_if_encrypted67, get_chk, SIZE_6PARTY, SIZE_6STORED}
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 6;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 252;
+pub const OT_LENGTH: usize = 12;
+pub const NICK_LENGTH: usize = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPK6 {
+    data: [u8; SIZE_6PARTY],
+}
+
+impl RawPK6 {
+    pub fn to_bytes(&self) -> [u8; SIZE_6PARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PK6 {
+    pub encryption_constant: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk::<SIZE_6PARTY>(&to_array(self.to_bytes()?)?, SIZE_6STORED)")]
+    pub checksum: u16,
+
+    // Block A
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    #[deku(
+        reader = "read::read_ability_u8(deku::rest)",
+        writer = "write::write_ability_u8(deku::output, self.ability)"
+    )]
+    pub ability: Ability,
+    pub ability_number: u8,
+    pub training_bag_hits: u8,
+    pub training_bag: u8,
+    pub pid: u32,
+    pub nature: Nature,
+    #[deku(bits = 5)]
+    pub form: u8,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+    pub mark_value: u8,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    // 0x2C Super Training
+    #[deku(bits = 1)]
+    pub super_train_1_def: Flag,
+    #[deku(bits = 1)]
+    pub super_train_1_spe: Flag,
+    #[deku(bits = 1)]
+    pub super_train_1_spd: Flag,
+    #[deku(bits = 1)]
+    pub super_train_1_atk: Flag,
+    #[deku(bits = 1)]
+    pub super_train_1_hp: Flag,
+    #[deku(bits = 1)]
+    pub super_train_1_spa: Flag,
+    #[deku(bits = 1)]
+    pub super_train_unused1: Flag,
+    #[deku(bits = 1)]
+    pub super_train_unused0: Flag,
+    // 0x2D Super Training
+    #[deku(bits = 1)]
+    pub super_train_3_hp: Flag,
+    #[deku(bits = 1)]
+    pub super_train_3_spa: Flag,
+    #[deku(bits = 1)]
+    pub super_train_2_def: Flag,
+    #[deku(bits = 1)]
+    pub super_train_2_spe: Flag,
+    #[deku(bits = 1)]
+    pub super_train_2_spd: Flag,
+    #[deku(bits = 1)]
+    pub super_train_2_atk: Flag,
+    #[deku(bits = 1)]
+    pub super_train_2_hp: Flag,
+    #[deku(bits = 1)]
+    pub super_train_2_spa: Flag,
+    // 0x2E Super Training
+    #[deku(bits = 1)]
+    pub super_train_5_3: Flag,
+    #[deku(bits = 1)]
+    pub super_train_5_2: Flag,
+    #[deku(bits = 1)]
+    pub super_train_5_1: Flag,
+    #[deku(bits = 1)]
+    pub super_train_4_1: Flag,
+    #[deku(bits = 1)]
+    pub super_train_3_def: Flag,
+    #[deku(bits = 1)]
+    pub super_train_3_spe: Flag,
+    #[deku(bits = 1)]
+    pub super_train_3_spd: Flag,
+    #[deku(bits = 1)]
+    pub super_train_3_atk: Flag,
+    // 0x2F Super Training
+    #[deku(bits = 1)]
+    pub super_train_8_1: Flag,
+    #[deku(bits = 1)]
+    pub super_train_7_3: Flag,
+    #[deku(bits = 1)]
+    pub super_train_7_2: Flag,
+    #[deku(bits = 1)]
+    pub super_train_7_1: Flag,
+    #[deku(bits = 1)]
+    pub super_train_6_3: Flag,
+    #[deku(bits = 1)]
+    pub super_train_6_2: Flag,
+    #[deku(bits = 1)]
+    pub super_train_6_1: Flag,
+    #[deku(bits = 1)]
+    pub super_train_5_4: Flag,
+    // 0x30 Ribbons
+    #[deku(bits = 1)]
+    pub ribbon_effort: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battler_expert: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battler_skillful: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_training: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_best_friends: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_sinnoh: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_g3_hoenn: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_kalos: Flag,
+    // 0x31 Ribbons
+    #[deku(bits = 1)]
+    pub ribbon_gorgeous: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_smile: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_snooze: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_relax: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_careless: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_downcast: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_shock: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_alert: Flag,
+    // 0x32 Ribbons
+    #[deku(bits = 1)]
+    pub ribbon_national: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_country: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_legend: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_record: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_footprint: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_artist: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_gorgeous_royal: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_royal: Flag,
+    // 0x33 Ribbons
+    #[deku(bits = 1)]
+    pub ribbon_souvenir: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_special: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_birthday: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_event: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_premier: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_classic: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_world: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_earth: Flag,
+    // 0x34 Ribbons
+    #[deku(bits = 1)]
+    pub ribbon_champion_g6_hoenn: Flag,
+    #[deku(bits = 1)]
+    pub has_battle_memory_ribbon: Flag,
+    #[deku(bits = 1)]
+    pub has_contest_memory_ribbon: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_world: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_national: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_regional: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_battle: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_wishing: Flag,
+    // 0x35 Ribbons
+    #[deku(bits = 1)]
+    pub rib35_7: Flag,
+    #[deku(bits = 1)]
+    pub rib35_6: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_toughness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_cleverness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_cuteness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_beauty: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_coolness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_contest_star: Flag,
+    pub unk_36: [u8; 2],
+    pub ribbon_count_memory_contest: u8,
+    pub ribbon_count_memory_battle: u8,
+    // 0x3A Distribution Super Training
+    #[deku(bits = 1)]
+    pub dist_super_train_unused7: Flag,
+    #[deku(bits = 1)]
+    pub dist_super_train_unused6: Flag,
+    #[deku(bits = 1)]
+    pub dist_super_train_6: Flag,
+    #[deku(bits = 1)]
+    pub dist_super_train_5: Flag,
+    #[deku(bits = 1)]
+    pub dist_super_train_4: Flag,
+    #[deku(bits = 1)]
+    pub dist_super_train_3: Flag,
+    #[deku(bits = 1)]
+    pub dist_super_train_2: Flag,
+    #[deku(bits = 1)]
+    pub dist_super_train_1: Flag,
+    pub unk_3b: [u8; 5],
+
+    // Block B
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub relearn_move1: Move,
+    pub relearn_move2: Move,
+    pub relearn_move3: Move,
+    pub relearn_move4: Move,
+    #[deku(bits = 6)]
+    pub unk_72: u8,
+    #[deku(bits = 1)]
+    pub secret_super_training_complete: Flag,
+    #[deku(bits = 1)]
+    pub secret_super_training_unlocked: Flag,
+    pub unk_73: u8,
+    pub iv32: u32,
+
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ht_name, OT_LENGTH))"
+    )]
+    pub ht_name: String,
+    pub ht_gender: Gender,
+    pub current_handler: u8,
+    pub geo1_region: u8,
+    pub geo1_country: u8,
+    pub geo2_region: u8,
+    pub geo2_country: u8,
+    pub geo3_region: u8,
+    pub geo3_country: u8,
+    pub geo4_region: u8,
+    pub geo4_country: u8,
+    pub geo5_region: u8,
+    pub geo5_country: u8,
+    pub unk_9e: [u8; 4],
+    pub ht_friendship: u8,
+    pub ht_affection: u8,
+    pub ht_intensity: u8,
+    pub ht_memory: u8,
+    pub ht_feeling: u8,
+    pub unk_a7: u8,
+    pub ht_text_var: u16,
+    pub unk_aa: [u8; 4],
+    pub fullness: u8,
+    pub enjoyment: u8,
+
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub ot_friendship: u8,
+    pub ot_affection: u8,
+    pub ot_intensity: u8,
+    pub ot_memory: u8,
+    pub ot_text_var: u16,
+    pub ot_feeling: u8,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    pub unk_d7: u8,
+    pub egg_location: u16,
+    pub met_location: u16,
+    pub ball: Ball,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    pub encounter_type: u8,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    pub country: u8,
+    pub region: u8,
+    pub console_region: RegionID,
+    pub language: LanguageID,
+    pub unk_e4: [u8; 4],
+
+    // Party Stats
+    pub status_condition: i32,
+    pub stat_level: u8,
+    pub unk_ed: [u8; 3],
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    pub unk_fe: [u8; 6],
+}
+
+impl PK6 {
+    /// Checks if the entity originated from Pokémon X or Y.
+    pub fn is_xy_origin(&self) -> bool { Self::is_xy(self.version) }
+
+    /// Checks if the entity originated from Pokémon Omega Ruby or Alpha
+    /// Sapphire.
+    pub fn is_ao_origin(&self) -> bool { Self::is_ao(self.version) }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location) }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_6PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PK6::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PK6 {
+    type RawVariant = RawPK6;
+
+    fn get_string(data: &[u16]) -> String { get_string6(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string6(data.as_ref(), max_length)
+    }
+
+    fn build(&mut self) -> Result<RawPK6, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPK6 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_6PARTY]> for PK6 {
+    fn from(data: &[u8; SIZE_6PARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted67(&mut array);
+        PK6::read_bytes(&array).unwrap()
+    }
+}
+
+impl From<&[u8; SIZE_6STORED]> for PK6 {
+    fn from(data: &[u8; SIZE_6STORED]) -> Self {
+        let mut stored = *data;
+        decrypt_if_encrypted67(&mut stored);
+        let mut array = [0u8; SIZE_6PARTY];
+        array[..SIZE_6STORED].copy_from_slice(&stored);
+        PK6::from(&array)
+    }
+}
+
+impl TryFrom<&[u8]> for PK6 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, rejecting data
+    /// whose stored checksum does not match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array = [0u8; SIZE_6PARTY];
+        match data.len() {
+            SIZE_6PARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted67(&mut array);
+            }
+            SIZE_6STORED => {
+                let mut stored: [u8; SIZE_6STORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted67(&mut stored);
+                array[..SIZE_6STORED].copy_from_slice(&stored);
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_6STORED, SIZE_6PARTY],
+                    actual,
+                })
+            }
+        }
+
+        let stored = u16::from_le_bytes([array[0x06], array[0x07]]);
+        let calculated = get_chk::<SIZE_6PARTY>(&array, SIZE_6STORED);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PK6::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::util::{pokecrypto::encrypt_array67, testutil::write_string};
+
+    fn froakie() -> [u8; SIZE_6PARTY] {
+        let mut data = [0u8; SIZE_6PARTY];
+        data[0x00..0x04].copy_from_slice(&0x9A1B_33C0u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&(Species::Froakie as u16).to_le_bytes());
+        data[0x14] = Ability::Torrent as u8;
+        data[0x15] = 1;
+        data[0x1D] = 1 << 3; // Form 1
+        data[0x2C..0x30].copy_from_slice(&0x8000_0014u32.to_le_bytes());
+        data[0x34] = 0b1010_0000; // G6 Hoenn Champion, Contest Memory
+        data[0x35] = 0b0000_0011; // Contest Star, Master Coolness
+        data[0x36..0x38].copy_from_slice(&[0xAB, 0xCD]);
+        data[0x38] = 40;
+        data[0x3A] = 0b0000_0101;
+        write_string(&mut data, 0x40, "Froakie".encode_utf16(), Endian::Little);
+        data[0x72] = 0b0000_0011;
+        write_string(&mut data, 0x78, "Calem".encode_utf16(), Endian::Little);
+        data[0x94] = 3;
+        data[0x95] = 49;
+        data[0x9C] = 7;
+        data[0x9D] = 105;
+        data[0xA7] = 0x5A;
+        write_string(&mut data, 0xB0, "Sycamore".encode_utf16(), Endian::Little);
+        data[0xDC] = Ball::Poke as u8;
+        data[0xDD] = 0x80 | 5;
+        data[0xDF] = GameVersion::OR as u8;
+        data[0xE0] = 49;
+        data[0xE1] = 3;
+        data[0xE2] = RegionID::NorthAmerica as u8;
+        data[0xE3] = LanguageID::English as u8;
+        data[0xEC] = 5;
+        data[0xFC..0xFE].copy_from_slice(&12u16.to_le_bytes());
+        data[0x102] = 0xEE;
+        let chk = get_chk::<SIZE_6PARTY>(&data, SIZE_6STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pk6_get_test() {
+        let froakie = PK6::from(&froakie());
+        assert_eq!(Species::Froakie, froakie.species);
+        assert_eq!(Ability::Torrent, froakie.ability);
+        assert_eq!(1, froakie.form);
+        assert_eq!(Gender::Male, froakie.gender);
+        assert_eq!(Flag::Set, froakie.super_train_1_spa);
+        assert_eq!(Flag::Set, froakie.super_train_1_atk);
+        assert_eq!(Flag::Unset, froakie.super_train_1_hp);
+        assert_eq!(Flag::Set, froakie.super_train_8_1);
+        assert_eq!(Flag::Set, froakie.ribbon_champion_g6_hoenn);
+        assert_eq!(Flag::Set, froakie.has_contest_memory_ribbon);
+        assert_eq!(Flag::Unset, froakie.has_battle_memory_ribbon);
+        assert_eq!(Flag::Set, froakie.ribbon_contest_star);
+        assert_eq!(Flag::Set, froakie.ribbon_master_coolness);
+        assert_eq!(Flag::Unset, froakie.ribbon_master_beauty);
+        assert_eq!(40, froakie.ribbon_count_memory_contest);
+        assert_eq!(Flag::Set, froakie.dist_super_train_1);
+        assert_eq!(Flag::Set, froakie.dist_super_train_3);
+        assert_eq!(Flag::Set, froakie.secret_super_training_unlocked);
+        assert_eq!(Flag::Set, froakie.secret_super_training_complete);
+        assert_eq!(0, froakie.iv(Stat::HP));
+        assert!(!froakie.is_nicknamed());
+        assert!(!froakie.is_egg());
+        assert_eq!("Froakie", froakie.nickname);
+        assert_eq!("Calem", froakie.ht_name);
+        assert_eq!((3, 49), (froakie.geo1_region, froakie.geo1_country));
+        assert_eq!((7, 105), (froakie.geo5_region, froakie.geo5_country));
+        assert_eq!("Sycamore", froakie.ot_name);
+        assert_eq!(Gender::Female, froakie.ot_gender);
+        assert_eq!(5, froakie.met_level);
+        assert_eq!(GameVersion::OR, froakie.version);
+        assert_eq!((49, 3), (froakie.country, froakie.region));
+        assert_eq!(RegionID::NorthAmerica, froakie.console_region);
+        assert_eq!(LanguageID::English, froakie.language);
+        assert_eq!(12, froakie.stat_spd);
+    }
+
+    #[test]
+    fn pk6_generation_test() {
+        let mut froakie = PK6::from(&froakie());
+        assert_eq!(6, froakie.generation());
+        assert!(froakie.is_ao_origin());
+        assert!(!froakie.is_xy_origin());
+
+        froakie.version = GameVersion::Y;
+        assert_eq!(6, froakie.generation());
+        let froakie = PK6::from(&froakie.build().unwrap().to_bytes());
+        assert!(froakie.is_xy_origin());
+        assert!(!froakie.is_ao_origin());
+    }
+
+    #[test]
+    fn pk6_from_encrypted_test() {
+        let mut pk6 = froakie();
+        let ek6 = encrypt_array67(&mut pk6);
+        assert_eq!(PK6::from(&pk6), PK6::from(&ek6));
+
+        let mut stored = <[u8; SIZE_6STORED]>::try_from(&pk6[..SIZE_6STORED]).unwrap();
+        let from_stored = PK6::from(&stored);
+        assert_eq!(from_stored, PK6::from(&encrypt_array67(&mut stored)));
+        assert_eq!(0, from_stored.stat_level);
+    }
+
+    #[test]
+    fn pk6_try_from_test() {
+        let mut data = froakie();
+        let party = PK6::try_from(&data[..]).unwrap();
+        assert_eq!(PK6::from(&data), party);
+        assert_eq!(party.ot_name, PK6::try_from(&data[..SIZE_6STORED]).unwrap().ot_name);
+        assert_eq!(party, PK6::try_from(&encrypt_array67(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_6STORED, SIZE_6PARTY],
+                actual: 0x100
+            }),
+            PK6::try_from(&data[..0x100])
+        );
+        data[0x08] ^= 1;
+        assert!(matches!(PK6::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pk6_round_trip_test() {
+        let data = froakie();
+        let mut froakie = PK6::from(&data);
+        assert_eq!(data, froakie.build().unwrap().to_bytes());
+
+        froakie.set_iv(Stat::HP, 20);
+        froakie.set_iv(Stat::SpD, 40);
+        froakie.set_is_egg(true);
+        let rebuilt = PK6::from(&froakie.build().unwrap().to_bytes());
+        assert_eq!(20, rebuilt.iv(Stat::HP));
+        assert_eq!(MAX_IV as u8, rebuilt.iv(Stat::SpD));
+        assert_eq!(0, rebuilt.iv(Stat::Spe));
+        assert!(rebuilt.is_egg());
+        assert!(!rebuilt.is_nicknamed());
+    }
+}
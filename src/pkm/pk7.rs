@@ -0,0 +1,484 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, region_id::RegionID, species::Species, stat::Stat
+    }, pkm::{
+        strings::string_converter::{get_string7, set_string7b}, util::pokecrypto::{decrypt_if_encrypted67, get_chk, SIZE_6PARTY, SIZE_6STORED}
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 7;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 252;
+pub const OT_LENGTH: usize = 12;
+pub const NICK_LENGTH: usize = 12;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPK7 {
+    data: [u8; SIZE_6PARTY],
+}
+
+impl RawPK7 {
+    pub fn to_bytes(&self) -> [u8; SIZE_6PARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PK7 {
+    pub encryption_constant: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk::<SIZE_6PARTY>(&to_array(self.to_bytes()?)?, SIZE_6STORED)")]
+    pub checksum: u16,
+
+    // Block A
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    #[deku(
+        reader = "read::read_ability_u8(deku::rest)",
+        writer = "write::write_ability_u8(deku::output, self.ability)"
+    )]
+    pub ability: Ability,
+    pub ability_number: u8,
+    pub mark_value: u16,
+    pub pid: u32,
+    pub nature: Nature,
+    #[deku(bits = 5)]
+    pub form: u8,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+    pub resort_event_status: u8,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    pub super_train_bit_flags: u32,
+    // 0x30-0x36 Ribbons
+    pub raw_ribbons: [u8; 7],
+    pub unk_37: u8,
+    pub ribbon_count_memory_contest: u8,
+    pub ribbon_count_memory_battle: u8,
+    pub unk_3a: [u8; 2],
+    pub form_argument: u32,
+
+    // Block B
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub relearn_move1: Move,
+    pub relearn_move2: Move,
+    pub relearn_move3: Move,
+    pub relearn_move4: Move,
+    pub unk_72: [u8; 2],
+    pub iv32: u32,
+
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ht_name, OT_LENGTH))"
+    )]
+    pub ht_name: String,
+    pub ht_gender: Gender,
+    pub current_handler: u8,
+    pub geo1_region: u8,
+    pub geo1_country: u8,
+    pub geo2_region: u8,
+    pub geo2_country: u8,
+    pub geo3_region: u8,
+    pub geo3_country: u8,
+    pub geo4_region: u8,
+    pub geo4_country: u8,
+    pub geo5_region: u8,
+    pub geo5_country: u8,
+    pub unk_9e: [u8; 4],
+    pub ht_friendship: u8,
+    pub ht_affection: u8,
+    pub ht_intensity: u8,
+    pub ht_memory: u8,
+    pub ht_feeling: u8,
+    pub unk_a7: u8,
+    pub ht_text_var: u16,
+    pub unk_aa: [u8; 4],
+    pub fullness: u8,
+    pub enjoyment: u8,
+
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub ot_friendship: u8,
+    pub ot_affection: u8,
+    pub ot_intensity: u8,
+    pub ot_memory: u8,
+    pub ot_text_var: u16,
+    pub ot_feeling: u8,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    pub unk_d7: u8,
+    pub egg_location: u16,
+    pub met_location: u16,
+    pub ball: Ball,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    #[deku(bits = "2")]
+    pub unk_de: u8,
+    #[deku(bits = "1")]
+    pub ht_spe: Flag,
+    #[deku(bits = "1")]
+    pub ht_spd: Flag,
+    #[deku(bits = "1")]
+    pub ht_spa: Flag,
+    #[deku(bits = "1")]
+    pub ht_def: Flag,
+    #[deku(bits = "1")]
+    pub ht_atk: Flag,
+    #[deku(bits = "1")]
+    pub ht_hp: Flag,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    pub country: u8,
+    pub region: u8,
+    pub console_region: RegionID,
+    pub language: LanguageID,
+    pub unk_e4: [u8; 4],
+
+    // Party Stats
+    pub status_condition: i32,
+    pub stat_level: u8,
+    pub dirt_type: u8,
+    pub dirt_location: u8,
+    pub unk_ef: u8,
+    pub stat_hp_current: u16,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    pub unk_fe: [u8; 6],
+}
+
+impl PK7 {
+    fn is_chinese(&self) -> bool {
+        [LanguageID::ChineseS, LanguageID::ChineseT].contains(&self.language)
+    }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location) }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_6PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PK7::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PK7 {
+    type RawVariant = RawPK7;
+
+    fn get_string(data: &[u16]) -> String { get_string7(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string7b(data.as_ref(), max_length, self.language, 0, 0, self.is_chinese())
+    }
+
+    fn build(&mut self) -> Result<RawPK7, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPK7 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_6PARTY]> for PK7 {
+    fn from(data: &[u8; SIZE_6PARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted67(&mut array);
+        PK7::read_bytes(&array).unwrap()
+    }
+}
+
+impl From<&[u8; SIZE_6STORED]> for PK7 {
+    fn from(data: &[u8; SIZE_6STORED]) -> Self {
+        let mut stored = *data;
+        decrypt_if_encrypted67(&mut stored);
+        let mut array = [0u8; SIZE_6PARTY];
+        array[..SIZE_6STORED].copy_from_slice(&stored);
+        PK7::from(&array)
+    }
+}
+
+impl TryFrom<&[u8]> for PK7 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, rejecting data
+    /// whose stored checksum does not match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array = [0u8; SIZE_6PARTY];
+        match data.len() {
+            SIZE_6PARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted67(&mut array);
+            }
+            SIZE_6STORED => {
+                let mut stored: [u8; SIZE_6STORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted67(&mut stored);
+                array[..SIZE_6STORED].copy_from_slice(&stored);
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_6STORED, SIZE_6PARTY],
+                    actual,
+                })
+            }
+        }
+
+        let stored = u16::from_le_bytes([array[0x06], array[0x07]]);
+        let calculated = get_chk::<SIZE_6PARTY>(&array, SIZE_6STORED);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PK7::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::{
+        strings::resources::char_zh::GEN7_ZH_OFS, util::{pokecrypto::encrypt_array67, testutil::write_string}
+    };
+
+    fn rowlet() -> [u8; SIZE_6PARTY] {
+        let mut data = [0u8; SIZE_6PARTY];
+        data[0x00..0x04].copy_from_slice(&0x2C7F_5E31u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&(Species::Rowlet as u16).to_le_bytes());
+        data[0x0C..0x0E].copy_from_slice(&12345u16.to_le_bytes());
+        data[0x0E..0x10].copy_from_slice(&54321u16.to_le_bytes());
+        data[0x14] = Ability::Overgrow as u8;
+        data[0x15] = 1;
+        data[0x18..0x1C].copy_from_slice(&0xDEAD_BEEFu32.to_le_bytes());
+        data[0x1C] = Nature::Adamant as u8;
+        data[0x1D] = 1 << 1; // Female
+        data[0x3C..0x40].copy_from_slice(&0x0000_0102u32.to_le_bytes());
+        write_string(&mut data, 0x40, "Rowlet".encode_utf16(), Endian::Little);
+        data[0x5A..0x5C].copy_from_slice(&(Move::Tackle as u16).to_le_bytes());
+        data[0x74..0x78].copy_from_slice(&0x3FFF_FFFFu32.to_le_bytes());
+        write_string(&mut data, 0xB0, "Kukui".encode_utf16(), Endian::Little);
+        data[0xDA..0xDC].copy_from_slice(&24u16.to_le_bytes());
+        data[0xDC] = Ball::Poke as u8;
+        data[0xDD] = 5;
+        data[0xDE] = 0b0010_0001; // HT HP and Speed
+        data[0xDF] = GameVersion::SN as u8;
+        data[0xE2] = RegionID::NorthAmerica as u8;
+        data[0xE3] = LanguageID::English as u8;
+        data[0xEC] = 5;
+        data[0xF0..0xF2].copy_from_slice(&20u16.to_le_bytes());
+        data[0xF2..0xF4].copy_from_slice(&20u16.to_le_bytes());
+        data[0xFC..0xFE].copy_from_slice(&11u16.to_le_bytes());
+        let chk = get_chk::<SIZE_6PARTY>(&data, SIZE_6STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pk7_get_test() {
+        let rowlet = PK7::from(&rowlet());
+        assert_eq!(0x2C7F5E31, rowlet.encryption_constant);
+        assert_eq!(Species::Rowlet, rowlet.species);
+        assert_eq!(12345, rowlet.tid);
+        assert_eq!(54321, rowlet.sid);
+        assert_eq!(Ability::Overgrow, rowlet.ability);
+        assert_eq!(1, rowlet.ability_number);
+        assert_eq!(0xDEADBEEF, rowlet.pid);
+        assert_eq!(Nature::Adamant, rowlet.nature);
+        assert_eq!(Gender::Female, rowlet.gender);
+        assert!(!rowlet.fateful_encounter);
+        assert_eq!(0x102, rowlet.form_argument);
+        assert_eq!("Rowlet", rowlet.nickname);
+        assert_eq!(Move::Tackle, rowlet.move1);
+        assert_eq!(31, rowlet.iv(Stat::HP));
+        assert_eq!(31, rowlet.iv(Stat::SpD));
+        assert!(!rowlet.is_egg());
+        assert_eq!(7, rowlet.generation());
+        assert_eq!("Kukui", rowlet.ot_name);
+        assert_eq!(24, rowlet.met_location);
+        assert_eq!(Ball::Poke, rowlet.ball);
+        assert_eq!(5, rowlet.met_level);
+        assert_eq!(Gender::Male, rowlet.ot_gender);
+        assert_eq!(Flag::Set, rowlet.ht_hp);
+        assert_eq!(Flag::Unset, rowlet.ht_atk);
+        assert_eq!(Flag::Set, rowlet.ht_spe);
+        assert_eq!(GameVersion::SN, rowlet.version);
+        assert_eq!(RegionID::NorthAmerica, rowlet.console_region);
+        assert_eq!(LanguageID::English, rowlet.language);
+        assert_eq!(5, rowlet.stat_level);
+        assert_eq!(20, rowlet.stat_hp_max);
+        assert_eq!(11, rowlet.stat_spd);
+    }
+
+    #[test]
+    fn pk7_from_encrypted_test() {
+        let mut pk7 = rowlet();
+        let ek7 = encrypt_array67(&mut pk7);
+        assert_ne!(pk7, ek7);
+        assert_eq!(PK7::from(&pk7), PK7::from(&ek7));
+    }
+
+    #[test]
+    fn pk7_from_stored_test() {
+        let mut pk7 = rowlet();
+        let mut stored = <[u8; SIZE_6STORED]>::try_from(&pk7[..SIZE_6STORED]).unwrap();
+        let party = PK7::from(&pk7);
+        let from_stored = PK7::from(&stored);
+        assert_eq!(party.nickname, from_stored.nickname);
+        assert_eq!(party.language, from_stored.language);
+        assert_eq!(0, from_stored.stat_level);
+
+        stored = encrypt_array67(&mut stored);
+        assert_eq!(from_stored, PK7::from(&stored));
+        assert_eq!(party.ot_name, PK7::from(&encrypt_array67(&mut pk7)).ot_name);
+    }
+
+    #[test]
+    fn pk7_build_test() {
+        let data = rowlet();
+        let mut rowlet = PK7::from(&data);
+        assert_eq!(data, rowlet.build().unwrap().to_bytes());
+
+        rowlet.set_iv(Stat::Atk, 0);
+        rowlet.nickname = String::from("Decidueye");
+        let rebuilt = PK7::from(&rowlet.build().unwrap().to_bytes());
+        assert_eq!(0, rebuilt.iv(Stat::Atk));
+        assert_eq!(31, rebuilt.iv(Stat::Def));
+        assert_eq!("Decidueye", rebuilt.nickname);
+        assert_ne!(data[0x06..0x08], rebuilt.checksum.to_le_bytes());
+    }
+
+    #[test]
+    fn pk7_ability_out_of_range_test() {
+        let mut rowlet = PK7::from(&rowlet());
+        rowlet.ability = Ability::Hospitality;
+        assert!(matches!(rowlet.build(), Err(PkmError::Deku(DekuError::InvalidParam(_)))));
+    }
+
+    #[test]
+    fn pk7_try_from_test() {
+        let mut data = rowlet();
+        let party = PK7::try_from(&data[..]).unwrap();
+        assert_eq!(PK7::from(&data), party);
+        assert_eq!(party.nickname, PK7::try_from(&data[..SIZE_6STORED]).unwrap().nickname);
+        assert_eq!(party, PK7::try_from(&encrypt_array67(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_6STORED, SIZE_6PARTY],
+                actual: 0x100
+            }),
+            PK7::try_from(&data[..0x100])
+        );
+        data[0x08] ^= 1;
+        assert!(matches!(PK7::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pk7_round_trip_test() {
+        let mut data = rowlet();
+        for offset in
+            [0x37, 0x3A, 0x3B, 0x72, 0x73, 0x9E, 0xA7, 0xAA, 0xD7, 0xE4, 0xEF, 0xFE, 0x103]
+        {
+            data[offset] = 0xA5;
+        }
+        data[0xDE] |= 0b1100_0000;
+        let chk = get_chk::<SIZE_6PARTY>(&data, SIZE_6STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+
+        let mut rowlet = PK7::from(&data);
+        assert_eq!([0xA5, 0xA5], rowlet.unk_3a);
+        assert_eq!(0b11, rowlet.unk_de);
+        assert_eq!(data, rowlet.build().unwrap().to_bytes());
+    }
+
+    #[test]
+    fn pk7_chinese_nickname_test() {
+        let mut rowlet = PK7::from(&rowlet());
+        rowlet.language = LanguageID::ChineseS;
+        rowlet.nickname = String::from("木木枭");
+        let data = rowlet.build().unwrap().to_bytes();
+        assert!((GEN7_ZH_OFS..GEN7_ZH_OFS + 0x400)
+            .contains(&u16::from_le_bytes([data[0x40], data[0x41]])));
+        assert_eq!("木木枭", PK7::from(&data).nickname);
+    }
+}
@@ -1,16 +1,23 @@
 #![allow(non_snake_case)]
-use crate::pkm::PKM;
-use alloc::{format, string::String, vec::Vec};
+use crate::pkm::{ChecksumMode, Entity, Slot, PKM};
+use alloc::{string::String, vec, vec::Vec};
+use bitconv::{endian::Little, to_uint16, to_uint32};
 use core::convert::TryFrom;
-use deku::prelude::*;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
 
 use crate::{
-    game::enums::{
-        ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species
+    error::{to_array, PkmError}, game::{
+        enums::{
+            ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, ribbon_index::RibbonIndex, species::Species, stat::Stat
+        }, game_data::game_data, personal_info::{PersonalInfo, PersonalTable}
     }, pkm::{
-        strings::string_converter::{get_string7, set_string7b}, util::pokecrypto::{decrypt_if_encrypted8, get_chk, SIZE_8PARTY, SIZE_8STORED}
+        strings::string_converter::{get_string7, set_string7b}, util::{
+            experience::get_level, pokecrypto::{decrypt_if_encrypted8, get_chk, SIZE_8PARTY, SIZE_8STORED}, stats
+        }
     }, util::{
-        custom_read_write::{read, write}, packutil::pack_u32
+        custom_read_write::{read, write}, flagutil::{get_flag, set_flag}
     }
 };
 
@@ -21,27 +28,26 @@ pub const MAX_EV: i32 = 252;
 pub const OT_LENGTH: usize = 12;
 pub const NICK_LENGTH: usize = 12;
 
-// TODO: PersonalInfo
-
+/// Built PK8 data, either [`SIZE_8STORED`] or [`SIZE_8PARTY`] bytes long.
 #[derive(Debug, Clone, PartialEq)]
-#[repr(C)]
 pub struct RawPK8 {
-    data: [u8; SIZE_8PARTY],
+    data: Vec<u8>,
 }
 
 impl RawPK8 {
-    pub fn to_bytes(&self) -> [u8; SIZE_8PARTY] { self.data }
+    pub fn to_bytes(&self) -> Vec<u8> { self.data.clone() }
+
+    /// Checks if the data includes the party stats.
+    pub fn is_party(&self) -> bool { self.data.len() == SIZE_8PARTY }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
-#[deku(endian = "little")]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
 #[repr(C)]
 pub struct PK8 {
     pub encryption_constant: u32,
     pub sanity: u16,
-    #[deku(
-        update = "get_chk::<SIZE_8PARTY>(&<[u8; SIZE_8PARTY]>::try_from(self.to_bytes().unwrap()).unwrap(), SIZE_8STORED)"
-    )]
+    #[deku(update = "get_chk::<SIZE_8PARTY>(&to_array(self.to_bytes()?)?, SIZE_8STORED)")]
     pub checksum: u16,
 
     // Block A
@@ -50,39 +56,31 @@ pub struct PK8 {
     pub tid: u16,
     pub sid: u16,
     pub exp: u32,
-    #[deku(pad_bits_after = "3")]
     pub ability: Ability,
+    #[deku(bits = 3)]
+    pub unk_16: u8,
     #[deku(bits = 1)]
     pub can_gigantamax: bool,
     #[deku(bits = 1)]
     pub favourite: bool,
     #[deku(bits = 3)]
     pub ability_number: u8,
-    // 0x17 alignment unused
-    #[deku(pad_bytes_before = "1")]
+    pub unk_17: u8,
     pub mark_value: u16,
-    // 0x1A alignment unused
-    // 0x1B alignment unused
-    #[deku(pad_bytes_before = "2")]
+    pub unk_1a: [u8; 2],
     pub pid: u32,
-    #[deku(skip, default = "((pid >> 16 ^ (pid & 0xFFFF)) >> 4) as i32")]
-    pub psv: i32,
-    #[deku(skip, default = "((tid ^ sid) >> 4) as i32")]
-    pub tsv: i32,
-    #[deku(skip, default = "psv == tsv")]
-    pub is_shiny: bool,
 
     pub nature: Nature,
-    #[deku(pad_bits_after = "4")]
     pub stat_nature: Nature,
+    #[deku(bits = 4)]
+    pub unk_22: u8,
     #[deku(bits = 2)]
     pub gender: Gender,
     #[deku(bits = 1)]
     pub flag2: Flag,
     #[deku(bits = 1)]
     pub fateful_encounter: bool,
-    // 0x23 alignment unused
-    #[deku(pad_bytes_before = "1")]
+    pub unk_23: u8,
     pub form: u16,
     pub ev_hp: u8,
     pub ev_atk: u8,
@@ -98,298 +96,296 @@ pub struct PK8 {
     pub cnt_sheen: u8,
     #[deku(bits = 4)]
     pub pkrs_strain: u8,
-    #[deku(bits = 4, pad_bytes_after = "1")]
+    #[deku(bits = 4)]
     pub pkrs_days: u8,
-    // 0x33 unused padding
+    pub unk_33: u8,
     #[deku(bits = 1)]
-    pub ribbon_champion_kalos: Flag,
-    #[deku(bits = 1)]
-    pub ribbon_champion_g3_hoenn: Flag,
+    pub ribbon_effort: Flag,
     #[deku(bits = 1)]
-    pub ribbon_champion_sinnoh: Flag,
+    pub ribbon_battler_expert: Flag,
     #[deku(bits = 1)]
-    pub ribbon_best_friends: Flag,
+    pub ribbon_battler_skillful: Flag,
     #[deku(bits = 1)]
     pub ribbon_training: Flag,
     #[deku(bits = 1)]
-    pub ribbon_battler_skillful: Flag,
+    pub ribbon_best_friends: Flag,
     #[deku(bits = 1)]
-    pub ribbon_battler_expert: Flag,
+    pub ribbon_champion_sinnoh: Flag,
     #[deku(bits = 1)]
-    pub ribbon_effort: Flag,
-
+    pub ribbon_champion_g3_hoenn: Flag,
     #[deku(bits = 1)]
-    pub ribbon_alert: Flag,
+    pub ribbon_champion_kalos: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_shock: Flag,
+    pub ribbon_gorgeous: Flag,
     #[deku(bits = 1)]
-    pub ribbon_downcast: Flag,
+    pub ribbon_smile: Flag,
     #[deku(bits = 1)]
-    pub ribbon_careless: Flag,
+    pub ribbon_snooze: Flag,
     #[deku(bits = 1)]
     pub ribbon_relax: Flag,
     #[deku(bits = 1)]
-    pub ribbon_snooze: Flag,
+    pub ribbon_careless: Flag,
     #[deku(bits = 1)]
-    pub ribbon_smile: Flag,
+    pub ribbon_downcast: Flag,
     #[deku(bits = 1)]
-    pub ribbon_gorgeous: Flag,
-
+    pub ribbon_shock: Flag,
     #[deku(bits = 1)]
-    pub ribbon_royal: Flag,
+    pub ribbon_alert: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_gorgeous_royal: Flag,
+    pub ribbon_national: Flag,
     #[deku(bits = 1)]
-    pub ribbon_artist: Flag,
+    pub ribbon_country: Flag,
     #[deku(bits = 1)]
-    pub ribbon_footprint: Flag,
+    pub ribbon_legend: Flag,
     #[deku(bits = 1)]
     pub ribbon_record: Flag,
     #[deku(bits = 1)]
-    pub ribbon_legend: Flag,
+    pub ribbon_footprint: Flag,
     #[deku(bits = 1)]
-    pub ribbon_country: Flag,
+    pub ribbon_artist: Flag,
     #[deku(bits = 1)]
-    pub ribbon_national: Flag,
-
+    pub ribbon_gorgeous_royal: Flag,
     #[deku(bits = 1)]
-    pub ribbon_earth: Flag,
+    pub ribbon_royal: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_world: Flag,
+    pub ribbon_souvenir: Flag,
     #[deku(bits = 1)]
-    pub ribbon_classic: Flag,
+    pub ribbon_special: Flag,
     #[deku(bits = 1)]
-    pub ribbon_premier: Flag,
+    pub ribbon_birthday: Flag,
     #[deku(bits = 1)]
     pub ribbon_event: Flag,
     #[deku(bits = 1)]
-    pub ribbon_birthday: Flag,
+    pub ribbon_premier: Flag,
     #[deku(bits = 1)]
-    pub ribbon_special: Flag,
+    pub ribbon_classic: Flag,
     #[deku(bits = 1)]
-    pub ribbon_souvenir: Flag,
-
+    pub ribbon_world: Flag,
     #[deku(bits = 1)]
-    pub ribbon_wishing: Flag,
+    pub ribbon_earth: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_champion_battle: Flag,
+    pub ribbon_champion_g6_hoenn: Flag,
     #[deku(bits = 1)]
-    pub ribbon_champion_regional: Flag,
+    pub has_battle_memory_ribbon: Flag,
     #[deku(bits = 1)]
-    pub ribbon_champion_national: Flag,
+    pub has_contest_memory_ribbon: Flag,
     #[deku(bits = 1)]
     pub ribbon_champion_world: Flag,
     #[deku(bits = 1)]
-    pub has_contest_memory_ribbon: Flag,
+    pub ribbon_champion_national: Flag,
     #[deku(bits = 1)]
-    pub has_battle_memory_ribbon: Flag,
+    pub ribbon_champion_regional: Flag,
     #[deku(bits = 1)]
-    pub ribbon_champion_g6_hoenn: Flag,
-
+    pub ribbon_champion_battle: Flag,
     #[deku(bits = 1)]
-    pub ribbon_contest_star: Flag,
+    pub ribbon_wishing: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_master_coolness: Flag,
+    pub ribbon_battle_royale: Flag,
     #[deku(bits = 1)]
-    pub ribbon_master_beauty: Flag,
+    pub ribbon_champion_alola: Flag,
     #[deku(bits = 1)]
-    pub ribbon_master_cuteness: Flag,
+    pub ribbon_master_toughness: Flag,
     #[deku(bits = 1)]
     pub ribbon_master_cleverness: Flag,
     #[deku(bits = 1)]
-    pub ribbon_master_toughness: Flag,
+    pub ribbon_master_cuteness: Flag,
     #[deku(bits = 1)]
-    pub ribbon_champion_alola: Flag,
+    pub ribbon_master_beauty: Flag,
     #[deku(bits = 1)]
-    pub ribbon_battle_royale: Flag,
-
+    pub ribbon_master_coolness: Flag,
     #[deku(bits = 1)]
-    pub ribbon_battle_tree_great: Flag,
+    pub ribbon_contest_star: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_battle_tree_master: Flag,
+    pub ribbon_mark_dusk: Flag,
     #[deku(bits = 1)]
-    pub ribbon_champion_galar: Flag,
+    pub ribbon_mark_sleepy_time: Flag,
     #[deku(bits = 1)]
-    pub ribbon_tower_master: Flag,
+    pub ribbon_mark_lunchtime: Flag,
     #[deku(bits = 1)]
     pub ribbon_master_rank: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_lunchtime: Flag,
+    pub ribbon_tower_master: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_sleepy_time: Flag,
+    pub ribbon_champion_galar: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_dusk: Flag,
-
+    pub ribbon_battle_tree_master: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_dawn: Flag,
+    pub ribbon_battle_tree_great: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_mark_cloudy: Flag,
+    pub ribbon_mark_sandstorm: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_rainy: Flag,
+    pub ribbon_mark_dry: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_stormy: Flag,
+    pub ribbon_mark_blizzard: Flag,
     #[deku(bits = 1)]
     pub ribbon_mark_snowy: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_blizzard: Flag,
+    pub ribbon_mark_stormy: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_dry: Flag,
+    pub ribbon_mark_rainy: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_sandstorm: Flag,
+    pub ribbon_mark_cloudy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_dawn: Flag,
     pub ribbon_count_memory_contest: u8,
-    #[deku(pad_bytes_after = "2")]
     pub ribbon_count_memory_battle: u8,
-    // 0x3E padding
-    // 0x3F padding
+    pub unk_3e: [u8; 2],
 
     // 0x40 Ribbon 1
     #[deku(bits = 1)]
-    pub ribbon_mark_misty: Flag,
-    #[deku(bits = 1)]
-    pub ribbon_mark_destiny: Flag,
+    pub ribbon_mark_absent_minded: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_fishing: Flag,
+    pub ribbon_mark_rowdy: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_curry: Flag,
+    pub ribbon_mark_rare: Flag,
     #[deku(bits = 1)]
     pub ribbon_mark_uncommon: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_rare: Flag,
+    pub ribbon_mark_curry: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_rowdy: Flag,
+    pub ribbon_mark_fishing: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_absent_minded: Flag,
-
+    pub ribbon_mark_destiny: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_jittery: Flag,
+    pub ribbon_mark_misty: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_mark_excited: Flag,
+    pub ribbon_mark_angry: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_charismatic: Flag,
+    pub ribbon_mark_joyful: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_calmness: Flag,
+    pub ribbon_mark_zoned_out: Flag,
     #[deku(bits = 1)]
     pub ribbon_mark_intense: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_zoned_out: Flag,
+    pub ribbon_mark_calmness: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_joyful: Flag,
+    pub ribbon_mark_charismatic: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_angry: Flag,
-
+    pub ribbon_mark_excited: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_smiley: Flag,
+    pub ribbon_mark_jittery: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_mark_teary: Flag,
+    pub ribbon_mark_scowling: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_upbeat: Flag,
+    pub ribbon_mark_crafty: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_peeved: Flag,
+    pub ribbon_mark_ferocious: Flag,
     #[deku(bits = 1)]
     pub ribbon_mark_intellectual: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_ferocious: Flag,
+    pub ribbon_mark_peeved: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_crafty: Flag,
+    pub ribbon_mark_upbeat: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_scowling: Flag,
-
+    pub ribbon_mark_teary: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_kindly: Flag,
+    pub ribbon_mark_smiley: Flag,
+
     #[deku(bits = 1)]
-    pub ribbon_mark_flustered: Flag,
+    pub ribbon_mark_thorny: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_pumped_up: Flag,
+    pub ribbon_mark_humble: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_zero_energy: Flag,
+    pub ribbon_mark_unsure: Flag,
     #[deku(bits = 1)]
     pub ribbon_mark_prideful: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_unsure: Flag,
+    pub ribbon_mark_zero_energy: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_humble: Flag,
+    pub ribbon_mark_pumped_up: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_thorny: Flag,
-    // 0x44 Ribbon 2
+    pub ribbon_mark_flustered: Flag,
     #[deku(bits = 1)]
-    pub ribbon_mark_vigor: Flag,
+    pub ribbon_mark_kindly: Flag,
+    // 0x44 Ribbon 2
     #[deku(bits = 1)]
-    pub ribbon_mark_slump: Flag,
+    pub rib44_7: Flag,
     #[deku(bits = 1)]
-    pub rib44_2: Flag,
+    pub rib44_6: Flag,
     #[deku(bits = 1)]
-    pub rib44_3: Flag,
+    pub rib44_5: Flag,
     #[deku(bits = 1)]
     pub rib44_4: Flag,
     #[deku(bits = 1)]
-    pub rib44_5: Flag,
+    pub rib44_3: Flag,
     #[deku(bits = 1)]
-    pub rib44_6: Flag,
+    pub rib44_2: Flag,
     #[deku(bits = 1)]
-    pub rib44_7: Flag,
-
+    pub ribbon_mark_slump: Flag,
     #[deku(bits = 1)]
-    pub rib45_0: Flag,
+    pub ribbon_mark_vigor: Flag,
+
     #[deku(bits = 1)]
-    pub rib45_1: Flag,
+    pub rib45_7: Flag,
     #[deku(bits = 1)]
-    pub rib45_2: Flag,
+    pub rib45_6: Flag,
     #[deku(bits = 1)]
-    pub rib45_3: Flag,
+    pub rib45_5: Flag,
     #[deku(bits = 1)]
     pub rib45_4: Flag,
     #[deku(bits = 1)]
-    pub rib45_5: Flag,
+    pub rib45_3: Flag,
     #[deku(bits = 1)]
-    pub rib45_6: Flag,
+    pub rib45_2: Flag,
     #[deku(bits = 1)]
-    pub rib45_7: Flag,
-
+    pub rib45_1: Flag,
     #[deku(bits = 1)]
-    pub rib46_0: Flag,
+    pub rib45_0: Flag,
+
     #[deku(bits = 1)]
-    pub rib46_1: Flag,
+    pub rib46_7: Flag,
     #[deku(bits = 1)]
-    pub rib46_2: Flag,
+    pub rib46_6: Flag,
     #[deku(bits = 1)]
-    pub rib46_3: Flag,
+    pub rib46_5: Flag,
     #[deku(bits = 1)]
     pub rib46_4: Flag,
     #[deku(bits = 1)]
-    pub rib46_5: Flag,
+    pub rib46_3: Flag,
     #[deku(bits = 1)]
-    pub rib46_6: Flag,
+    pub rib46_2: Flag,
     #[deku(bits = 1)]
-    pub rib46_7: Flag,
-
+    pub rib46_1: Flag,
     #[deku(bits = 1)]
-    pub rib47_0: Flag,
+    pub rib46_0: Flag,
+
     #[deku(bits = 1)]
-    pub rib47_1: Flag,
+    pub rib47_7: Flag,
     #[deku(bits = 1)]
-    pub rib47_2: Flag,
+    pub rib47_6: Flag,
     #[deku(bits = 1)]
-    pub rib47_3: Flag,
+    pub rib47_5: Flag,
     #[deku(bits = 1)]
     pub rib47_4: Flag,
     #[deku(bits = 1)]
-    pub rib47_5: Flag,
+    pub rib47_3: Flag,
     #[deku(bits = 1)]
-    pub rib47_6: Flag,
+    pub rib47_2: Flag,
     #[deku(bits = 1)]
-    pub rib47_7: Flag,
-    #[deku(pad_bytes_after = "4")]
+    pub rib47_1: Flag,
+    #[deku(bits = 1)]
+    pub rib47_0: Flag,
     pub u48: u32,
-    // 0x4C-0x4F unused
+    pub unk_4c: [u8; 4],
     pub height_scalar: u8,
-    #[deku(pad_bytes_after = "6")]
     pub weight_scalar: u8,
-    // 0x52-0x57 unused
+    pub unk_52: [u8; 6],
     // Block B
+    /// Nickname as stored, including the trash past its terminator.
     #[deku(
-        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
-        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+        writer = "write::write_string_trash(deku::output, self.set_string(&self.nickname, NICK_LENGTH), &self.nickname_trash)"
     )]
+    pub nickname_trash: [u16; NICK_LENGTH + 1],
+    #[deku(skip, default = "Self::get_string(nickname_trash)")]
     pub nickname: String,
     pub move1: Move,
     pub move2: Move,
@@ -408,50 +404,33 @@ pub struct PK8 {
     pub relearn_move3: Move,
     pub relearn_move4: Move,
     pub stat_hp_current: u16,
-    #[deku(
-        update = "pack_u32(&[(self.iv_hp, 5), (self.iv_atk, 5), (self.iv_def, 5), (self.iv_spe, 5), (self.iv_spa, 5), (self.iv_spd, 5), (self.is_egg as u8, 1), (self.is_nicknamed as u8, 1)])"
-    )]
+    /// IVs, egg flag and nickname flag packed together. See [`PK8::iv`].
     pub iv32: u32,
-    #[deku(skip, default = "((*iv32) & 0x1F) as u8")]
-    pub iv_hp: u8,
-    #[deku(skip, default = "((*iv32 >> 05) & 0x1F) as u8")]
-    pub iv_atk: u8,
-    #[deku(skip, default = "((*iv32 >> 10) & 0x1F) as u8")]
-    pub iv_def: u8,
-    #[deku(skip, default = "((*iv32 >> 15) & 0x1F) as u8")]
-    pub iv_spe: u8,
-    #[deku(skip, default = "((*iv32 >> 20) & 0x1F) as u8")]
-    pub iv_spa: u8,
-    #[deku(skip, default = "((*iv32 >> 25) & 0x1F) as u8")]
-    pub iv_spd: u8,
-    #[deku(skip, default = "((*iv32 >> 30) & 1) == 1")]
-    pub is_egg: bool,
-    #[deku(skip, default = "((*iv32 >> 31) & 1) == 1")]
-    pub is_nicknamed: bool,
-    #[deku(pad_bytes_after = "3")]
     pub dynamax_level: u8,
+    pub unk_91: [u8; 3],
     pub status_condition: i32,
-    #[deku(pad_bytes_after = "12")]
     pub unk98: i32,
+    pub unk_9c: [u8; 12],
     // Block C
+    /// Handling trainer name as stored, including the trash past its
+    /// terminator.
     #[deku(
-        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
-        writer = "write::write_string_custom(deku::output, self.set_string(&self.ht_name, OT_LENGTH))"
+        writer = "write::write_string_trash(deku::output, self.set_string(&self.ht_name, OT_LENGTH), &self.ht_name_trash)"
     )]
+    pub ht_name_trash: [u16; OT_LENGTH + 1],
+    #[deku(skip, default = "Self::get_string(ht_name_trash)")]
     pub ht_name: String,
     pub ht_gender: Gender,
     pub ht_language: LanguageID,
-    #[deku(pad_bytes_after = "1")]
     pub current_handler: u8,
-    // 0xC5 unused (alignment)
+    pub unk_c5: u8,
     pub ht_trainer_id: u16,
     pub ht_friendship: u8,
     pub ht_intensity: u8,
     pub ht_memory: u8,
     pub ht_feeling: u8,
-    #[deku(pad_bytes_after = "14")]
     pub ht_text_var: u16,
-    // 0xCE-0xDB unused
+    pub unk_ce: [u8; 14],
     pub fullness: u8,
     pub enjoyment: u8,
     #[deku(
@@ -461,29 +440,30 @@ pub struct PK8 {
     pub version: GameVersion,
     #[deku(
         reader = "read::read_game_version(deku::rest)",
-        writer = "write::write_game_version(deku::output, self.battle_version)",
-        pad_bytes_after = "2"
+        writer = "write::write_game_version(deku::output, self.battle_version)"
     )]
     pub battle_version: GameVersion,
+    pub unk_e0: [u8; 2],
     // region: u8,
     // console_region: u8,
     pub language: LanguageID,
     pub unk_e3: u8,
     pub form_argument: u32,
-    #[deku(pad_bytes_after = "15")]
     pub affixed_ribbon: i8,
-    // 0xE9-0xF7 unused
+    pub unk_e9: [u8; 15],
     // Block D
+    /// Original trainer name as stored, including the trash past its
+    /// terminator.
     #[deku(
-        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
-        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+        writer = "write::write_string_trash(deku::output, self.set_string(&self.ot_name, OT_LENGTH), &self.ot_name_trash)"
     )]
+    pub ot_name_trash: [u16; OT_LENGTH + 1],
+    #[deku(skip, default = "Self::get_string(ot_name_trash)")]
     pub ot_name: String,
     pub ot_friendship: u8,
     pub ot_intensity: u8,
-    #[deku(pad_bytes_after = "1")]
     pub ot_memory: u8,
-    // 0x115 unused align
+    pub unk_115: u8,
     pub ot_text_var: u16,
     pub ot_feeling: u8,
     pub egg_year: u8,
@@ -491,9 +471,8 @@ pub struct PK8 {
     pub egg_day: u8,
     pub met_year: u8,
     pub met_month: u8,
-    #[deku(pad_bytes_after = "1")]
     pub met_day: u8,
-    // 0x11F unused align
+    pub unk_11f: u8,
     pub egg_location: u16,
     pub met_location: u16,
     pub ball: Ball,
@@ -501,7 +480,9 @@ pub struct PK8 {
     pub ot_gender: Gender,
     #[deku(bits = "7")]
     pub met_level: u8,
-    #[deku(bits = "1", pad_bits_before = "2")]
+    #[deku(bits = "2")]
+    pub unk_126: u8,
+    #[deku(bits = "1")]
     pub ht_spe: Flag,
     #[deku(bits = "1")]
     pub ht_spd: Flag,
@@ -514,10 +495,10 @@ pub struct PK8 {
     #[deku(bits = "1")]
     pub ht_hp: Flag,
     pub raw_move_record: [u8; 14],
-    #[deku(pad_bytes_after = "11")]
     pub tracker: u64,
-    #[deku(pad_bytes_after = "1")]
+    pub unk_13d: [u8; 11],
     pub stat_level: u8,
+    pub unk_149: u8,
     pub stat_hp_max: u16,
     pub stat_atk: u16,
     pub stat_def: u16,
@@ -525,10 +506,9 @@ pub struct PK8 {
     pub stat_spa: u16,
     pub stat_spd: u16,
     pub dynamax_type: u16,
-    #[deku(skip, default = "Self::get_generation(*version, *met_location)")]
-    pub generation: i32,
-    #[deku(skip, default = "ht_name.is_empty() && FORMAT == *generation")]
-    pub is_untraded: bool,
+    /// Whether the entity was loaded from, and builds into, party sized data.
+    #[deku(skip, default = "true")]
+    pub has_party_stats: bool,
 }
 
 impl PKM for PK8 {
@@ -540,9 +520,278 @@ impl PKM for PK8 {
         set_string7b(data.as_ref(), max_length, self.language, 0, 0, false)
     }
 
-    fn build(&mut self) -> RawPK8 {
+    fn build(&mut self) -> Result<RawPK8, PkmError> {
         self.refresh_checksum();
-        RawPK8 { data: <[u8; SIZE_8PARTY]>::try_from(self.to_bytes().unwrap()).unwrap() }
+        let mut data = self.to_bytes()?;
+        if !self.has_party_stats {
+            data.truncate(SIZE_8STORED);
+        }
+        Ok(RawPK8 { data })
+    }
+}
+
+impl PK8 {
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    /// Shiny value of the PID, compared against the [`PK8::tsv`].
+    pub fn psv(&self) -> i32 { ((self.pid >> 16 ^ (self.pid & 0xFFFF)) >> 4) as i32 }
+    /// Shiny value of the trainer IDs.
+    pub fn tsv(&self) -> i32 { ((self.tid ^ self.sid) >> 4) as i32 }
+    pub fn is_shiny(&self) -> bool { self.psv() == self.tsv() }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location) }
+    /// Whether the entity originates from this format and has never been
+    /// handled by another trainer.
+    pub fn is_untraded(&self) -> bool { self.ht_name.is_empty() && FORMAT == self.generation() }
+
+    /// Gets the personal entry of the entity's species and form from the data
+    /// set in use, see [`game_data`].
+    pub fn personal_info(&self) -> Option<&'static PersonalInfo> {
+        self.personal_info_in(&game_data().personal)
+    }
+
+    /// Gets the personal entry of the entity's species and form from `table`.
+    pub fn personal_info_in<'a>(&self, table: &'a PersonalTable) -> Option<&'a PersonalInfo> {
+        table.get(self.species, self.form)
+    }
+
+    /// Mints the entity, changing the nature its stats are computed with while
+    /// keeping its original nature.
+    pub fn apply_mint(&mut self, nature: Nature) { self.stat_nature = nature; }
+
+    /// Reverts a mint, so stats follow the original nature again.
+    pub fn remove_mint(&mut self) { self.stat_nature = self.nature; }
+
+    pub fn is_minted(&self) -> bool { self.stat_nature != self.nature }
+
+    /// Gets the nature a game of `generation` computes stats with. Mints only
+    /// exist from Sword/Shield onwards, so earlier games use the original
+    /// nature.
+    pub fn stat_nature_for(&self, generation: i32) -> Nature {
+        if generation >= FORMAT {
+            self.stat_nature
+        } else {
+            self.nature
+        }
+    }
+
+    /// Gets the level matching the entity's experience.
+    pub fn current_level(&self, personal: &PersonalInfo) -> u8 {
+        get_level(self.exp, personal.exp_growth as u32).map_or(1, |level| level as u8)
+    }
+
+    /// Gets the IVs used for stat computation, with hyper trained stats raised
+    /// to [`MAX_IV`].
+    pub fn stat_ivs(&self) -> [u8; 6] {
+        let trained = [self.ht_hp, self.ht_atk, self.ht_def, self.ht_spe, self.ht_spa, self.ht_spd];
+        let mut ivs = self.ivs();
+        for (iv, flag) in ivs.iter_mut().zip(trained.iter()) {
+            if *flag == Flag::Set {
+                *iv = MAX_IV as u8;
+            }
+        }
+        ivs
+    }
+
+    /// Calculates the stats at `level`, ordered HP, Atk, Def, Spe, SpA, SpD.
+    pub fn get_stats(&self, personal: &PersonalInfo, level: u8) -> [u16; 6] {
+        let mut stats = stats::get_stats(
+            personal.base_stats,
+            self.stat_ivs(),
+            self.evs(),
+            level,
+            self.stat_nature_for(FORMAT),
+        );
+        if self.species == Species::Shedinja {
+            stats[0] = 1;
+        }
+        stats
+    }
+
+    /// Rewrites the party stats for the level matching the entity's
+    /// experience, fully restoring its HP, using the personal entry from the
+    /// data set in use.
+    pub fn reset_party_stats(&mut self) -> Result<(), PkmError> {
+        let personal = self.personal_info().ok_or(PkmError::InvalidField {
+            field: "species",
+            offset: 0x08,
+            value: self.species as u32,
+        })?;
+        self.reset_party_stats_with(personal);
+        Ok(())
+    }
+
+    /// Rewrites the party stats for the level matching the entity's
+    /// experience, fully restoring its HP. Stored data gains party stats, so
+    /// it is built party sized from then on.
+    pub fn reset_party_stats_with(&mut self, personal: &PersonalInfo) {
+        let level = self.current_level(personal);
+        let [hp, atk, def, spe, spa, spd] = self.get_stats(personal, level);
+        self.has_party_stats = true;
+        self.stat_level = level;
+        self.stat_hp_max = hp;
+        self.stat_hp_current = hp;
+        self.stat_atk = atk;
+        self.stat_def = def;
+        self.stat_spe = spe;
+        self.stat_spa = spa;
+        self.stat_spd = spd;
+    }
+}
+
+impl Entity for PK8 {
+    fn species(&self) -> Species { self.species }
+    fn set_species(&mut self, species: Species) { self.species = species; }
+    fn form(&self) -> u16 { self.form }
+    fn set_form(&mut self, form: u16) { self.form = form; }
+
+    fn nickname(&self) -> &str { &self.nickname }
+    fn set_nickname(&mut self, nickname: &str) { self.nickname = nickname.into(); }
+    fn ot_name(&self) -> &str { &self.ot_name }
+    fn set_ot_name(&mut self, ot_name: &str) { self.ot_name = ot_name.into(); }
+    fn tid(&self) -> u16 { self.tid }
+    fn set_tid(&mut self, tid: u16) { self.tid = tid; }
+    fn sid(&self) -> u16 { self.sid }
+    fn set_sid(&mut self, sid: u16) { self.sid = sid; }
+
+    fn pid(&self) -> u32 { self.pid }
+    fn set_pid(&mut self, pid: u32) { self.pid = pid; }
+    fn encryption_constant(&self) -> u32 { self.encryption_constant }
+    fn set_encryption_constant(&mut self, ec: u32) { self.encryption_constant = ec; }
+
+    fn ivs(&self) -> [u8; 6] {
+        let mut ivs = [0; 6];
+        for (stat, iv) in ivs.iter_mut().enumerate() {
+            *iv = self.iv(Stat::n(stat as u8).unwrap());
+        }
+        ivs
+    }
+    fn set_ivs(&mut self, ivs: [u8; 6]) {
+        for (stat, &iv) in ivs.iter().enumerate() {
+            self.set_iv(Stat::n(stat as u8).unwrap(), iv);
+        }
+    }
+    fn evs(&self) -> [u8; 6] {
+        [self.ev_hp, self.ev_atk, self.ev_def, self.ev_spe, self.ev_spa, self.ev_spd]
+    }
+    fn set_evs(&mut self, evs: [u8; 6]) {
+        let [hp, atk, def, spe, spa, spd] = evs;
+        self.ev_hp = hp;
+        self.ev_atk = atk;
+        self.ev_def = def;
+        self.ev_spe = spe;
+        self.ev_spa = spa;
+        self.ev_spd = spd;
+    }
+
+    fn moves(&self) -> [Move; 4] { [self.move1, self.move2, self.move3, self.move4] }
+    fn set_moves(&mut self, moves: [Move; 4]) {
+        let [move1, move2, move3, move4] = moves;
+        self.move1 = move1;
+        self.move2 = move2;
+        self.move3 = move3;
+        self.move4 = move4;
+    }
+
+    fn ball(&self) -> Ball { self.ball }
+    fn set_ball(&mut self, ball: Ball) { self.ball = ball; }
+    fn met_location(&self) -> u16 { self.met_location }
+    fn set_met_location(&mut self, location: u16) { self.met_location = location; }
+    fn met_level(&self) -> u8 { self.met_level }
+    fn set_met_level(&mut self, level: u8) { self.met_level = level; }
+    fn met_date(&self) -> (u8, u8, u8) { (self.met_year, self.met_month, self.met_day) }
+    fn set_met_date(&mut self, year: u8, month: u8, day: u8) {
+        self.met_year = year;
+        self.met_month = month;
+        self.met_day = day;
+    }
+
+    fn language(&self) -> LanguageID { self.language }
+    fn set_language(&mut self, language: LanguageID) { self.language = language; }
+    fn version(&self) -> GameVersion { self.version }
+    fn set_version(&mut self, version: GameVersion) { self.version = version; }
+}
+
+impl PK8 {
+    /// Reads an entity from decrypted party sized data, without any validation.
+    fn read_bytes(data: &[u8; SIZE_8PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PK8::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+
+    /// Checks every enum backed field of decrypted data, so that an unknown
+    /// value is reported with its offset instead of a generic parse error.
+    fn validate_fields(data: &[u8; SIZE_8PARTY]) -> Result<(), PkmError> {
+        check_field::<Species>(data, "species", 0x08, 2)?;
+        check_field::<Ability>(data, "ability", 0x14, 2)?;
+        check_field::<Nature>(data, "nature", 0x20, 1)?;
+        check_field::<Nature>(data, "stat_nature", 0x21, 1)?;
+        let gender = (data[0x22] >> 2) & 0b11;
+        if gender > Gender::Genderless as u8 {
+            return Err(PkmError::InvalidField {
+                field: "gender",
+                offset: 0x22,
+                value: gender as u32,
+            });
+        }
+        for (i, offset) in (0x72..0x7A).step_by(2).enumerate() {
+            check_field::<Move>(data, ["move1", "move2", "move3", "move4"][i], offset, 2)?;
+        }
+        for (i, offset) in (0x82..0x8A).step_by(2).enumerate() {
+            let field = ["relearn_move1", "relearn_move2", "relearn_move3", "relearn_move4"][i];
+            check_field::<Move>(data, field, offset, 2)?;
+        }
+        check_field::<Gender>(data, "ht_gender", 0xC2, 1)?;
+        check_field::<LanguageID>(data, "ht_language", 0xC3, 1)?;
+        for (field, offset) in [("version", 0xDE), ("battle_version", 0xDF)] {
+            if GameVersion::n(data[offset] as i32).is_none() {
+                return Err(PkmError::InvalidField { field, offset, value: data[offset] as u32 });
+            }
+        }
+        check_field::<LanguageID>(data, "language", 0xE2, 1)?;
+        check_field::<Ball>(data, "ball", 0x124, 1)
+    }
+}
+
+/// Checks that the `size` bytes at `offset` can be read as `T`.
+fn check_field<T>(
+    data: &[u8],
+    field: &'static str,
+    offset: usize,
+    size: usize,
+) -> Result<(), PkmError>
+where
+    T: for<'a> DekuRead<'a, Endian>,
+{
+    let bytes = &data[offset..offset + size];
+    match T::read(bytes.view_bits::<Msb0>(), Endian::Little) {
+        Ok(_) => Ok(()),
+        Err(_) => {
+            let value = bytes.iter().rev().fold(0, |acc, &b| acc << 8 | b as u32);
+            Err(PkmError::InvalidField { field, offset, value })
+        }
     }
 }
 
@@ -550,15 +799,367 @@ impl From<&[u8; SIZE_8PARTY]> for PK8 {
     fn from(data: &[u8; SIZE_8PARTY]) -> Self {
         let mut array = *data;
         decrypt_if_encrypted8(&mut array);
-        let (_rest, file) = PK8::from_bytes((array.as_ref(), 0)).unwrap();
-        file
+        PK8::read_bytes(&array).unwrap()
+    }
+}
+
+impl From<&[u8; SIZE_8STORED]> for PK8 {
+    fn from(data: &[u8; SIZE_8STORED]) -> Self {
+        let mut stored = *data;
+        decrypt_if_encrypted8(&mut stored);
+        let mut array = [0u8; SIZE_8PARTY];
+        array[..SIZE_8STORED].copy_from_slice(&stored);
+        PK8 { has_party_stats: false, ..PK8::from(&array) }
+    }
+}
+
+impl TryFrom<&[u8]> for PK8 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, validating it along
+    /// the way.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> { PK8::parse(data, ChecksumMode::Reject) }
+}
+
+impl PK8 {
+    /// Parses stored or party sized data like [`PK8::try_from`], with `mode`
+    /// deciding what happens when the stored checksum is wrong.
+    pub fn parse(data: &[u8], mode: ChecksumMode) -> Result<Self, PkmError> {
+        let mut array = [0u8; SIZE_8PARTY];
+        let has_party_stats = match data.len() {
+            SIZE_8PARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted8(&mut array);
+                true
+            }
+            SIZE_8STORED => {
+                let mut stored: [u8; SIZE_8STORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted8(&mut stored);
+                array[..SIZE_8STORED].copy_from_slice(&stored);
+                false
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_8STORED, SIZE_8PARTY],
+                    actual,
+                })
+            }
+        };
+
+        if to_uint16::<Little>(&array, 0x70) != 0 || to_uint16::<Little>(&array, 0xC0) != 0 {
+            return Err(PkmError::DecryptionFailed);
+        }
+        let stored = to_uint16::<Little>(&array, 0x06);
+        let calculated = get_chk::<SIZE_8PARTY>(&array, SIZE_8STORED);
+        if stored != calculated && mode == ChecksumMode::Reject {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        PK8::validate_fields(&array)?;
+
+        Ok(PK8 { has_party_stats, ..PK8::read_bytes(&array)? })
+    }
+
+    /// Classifies the data of a box or party slot. Data that fails to load for
+    /// any reason, including a wrong checksum, is reported as a Bad Egg.
+    pub fn load_slot(data: &[u8]) -> Slot<PK8> {
+        match PK8::try_from(data) {
+            Ok(pk8) if pk8.species == Species::None => Slot::Empty,
+            Ok(pk8) => Slot::Entity(pk8),
+            Err(err) => Slot::BadEgg(err),
+        }
+    }
+
+    /// Checks if the stored checksum matches the one calculated from the data.
+    pub fn is_checksum_valid(&self) -> bool {
+        self.to_bytes()
+            .ok()
+            .and_then(|data| to_array::<SIZE_8PARTY>(data).ok())
+            .is_some_and(|data| get_chk(&data, SIZE_8STORED) == self.checksum)
+    }
+}
+
+/// Read-only view over decrypted party sized PK8 data, reading fields on
+/// demand instead of parsing the whole entity.
+#[derive(Debug, Copy, Clone)]
+pub struct PK8Ref<'a> {
+    data: &'a [u8; SIZE_8PARTY],
+}
+
+/// Mutable view over decrypted party sized PK8 data, reading and writing
+/// fields in place.
+#[derive(Debug)]
+pub struct PK8Mut<'a> {
+    data: &'a mut [u8; SIZE_8PARTY],
+}
+
+/// Implements the same items for both [`PK8Ref`] and [`PK8Mut`].
+macro_rules! impl_pk8_view {
+    ($($item:item)*) => {
+        impl PK8Ref<'_> { $($item)* }
+        impl PK8Mut<'_> { $($item)* }
+    };
+}
+
+impl_pk8_view! {
+    pub fn encryption_constant(&self) -> u32 { to_uint32::<Little>(&self.data[..], 0x00) }
+    pub fn checksum(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x06) }
+    pub fn species(&self) -> Option<Species> { Species::n(to_uint16::<Little>(&self.data[..], 0x08)) }
+    pub fn held_item(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x0A) }
+    pub fn tid(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x0C) }
+    pub fn sid(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x0E) }
+    pub fn exp(&self) -> u32 { to_uint32::<Little>(&self.data[..], 0x10) }
+    pub fn ability(&self) -> Option<Ability> { Ability::n(to_uint16::<Little>(&self.data[..], 0x14)) }
+    pub fn ability_number(&self) -> u8 { self.data[0x16] & 7 }
+    pub fn favourite(&self) -> bool { get_flag(&self.data[..], 0x16, 3) == Flag::Set }
+    pub fn can_gigantamax(&self) -> bool { get_flag(&self.data[..], 0x16, 4) == Flag::Set }
+    pub fn pid(&self) -> u32 { to_uint32::<Little>(&self.data[..], 0x1C) }
+    pub fn nature(&self) -> Option<Nature> { Nature::n(self.data[0x20]) }
+    pub fn stat_nature(&self) -> Option<Nature> { Nature::n(self.data[0x21]) }
+    pub fn fateful_encounter(&self) -> bool { get_flag(&self.data[..], 0x22, 0) == Flag::Set }
+    pub fn gender(&self) -> Option<Gender> {
+        match (self.data[0x22] >> 2) & 3 {
+            0 => Some(Gender::Male),
+            1 => Some(Gender::Female),
+            2 => Some(Gender::Genderless),
+            _ => None,
+        }
+    }
+    pub fn form(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x24) }
+
+    /// Gets the EV of a stat.
+    pub fn ev(&self, stat: Stat) -> u8 { self.data[0x26 + stat as usize] }
+
+    /// Gets a ribbon or mark.
+    pub fn ribbon(&self, ribbon: RibbonIndex) -> Flag {
+        let (offset, bit_index) = ribbon_flag(ribbon);
+        get_flag(&self.data[..], offset, bit_index)
+    }
+
+    /// Gets a ribbon or other bitflag.
+    pub fn flag(&self, offset: usize, bit_index: u32) -> Flag {
+        get_flag(&self.data[..], offset, bit_index)
+    }
+
+    pub fn height_scalar(&self) -> u8 { self.data[0x50] }
+    pub fn weight_scalar(&self) -> u8 { self.data[0x51] }
+    pub fn nickname(&self) -> String { self.read_string(0x58, NICK_LENGTH) }
+
+    /// Gets one of the four current moves, or `None` past the fourth.
+    pub fn move_id(&self, index: usize) -> Option<Move> {
+        if index >= MOVE_COUNT {
+            return None;
+        }
+        Move::n(to_uint16::<Little>(&self.data[..], 0x72 + index * 2))
+    }
+    /// Gets the PP of one of the four current moves.
+    pub fn move_pp(&self, index: usize) -> Option<u8> { self.data[0x7A..0x7E].get(index).copied() }
+    /// Gets the PP Ups of one of the four current moves.
+    pub fn move_pp_ups(&self, index: usize) -> Option<u8> {
+        self.data[0x7E..0x82].get(index).copied()
+    }
+    /// Gets one of the four relearnable moves, or `None` past the fourth.
+    pub fn relearn_move(&self, index: usize) -> Option<Move> {
+        if index >= MOVE_COUNT {
+            return None;
+        }
+        Move::n(to_uint16::<Little>(&self.data[..], 0x82 + index * 2))
+    }
+
+    pub fn stat_hp_current(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x8A) }
+    pub fn iv32(&self) -> u32 { to_uint32::<Little>(&self.data[..], 0x8C) }
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32() >> (stat as u32 * 5)) & 0x1F) as u8 }
+    pub fn is_egg(&self) -> bool { (self.iv32() >> 30) & 1 == 1 }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32() >> 31) & 1 == 1 }
+    pub fn dynamax_level(&self) -> u8 { self.data[0x90] }
+    pub fn ht_name(&self) -> String { self.read_string(0xA8, OT_LENGTH) }
+    pub fn version(&self) -> Option<GameVersion> { GameVersion::n(self.data[0xDE] as i32) }
+    pub fn ot_name(&self) -> String { self.read_string(0xF8, OT_LENGTH) }
+    pub fn egg_location(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x120) }
+    pub fn met_location(&self) -> u16 { to_uint16::<Little>(&self.data[..], 0x122) }
+    pub fn ball(&self) -> Option<Ball> { Ball::n(self.data[0x124]) }
+    pub fn ot_gender(&self) -> Gender {
+        if self.data[0x125] >> 7 == 0 { Gender::Male } else { Gender::Female }
+    }
+    pub fn met_level(&self) -> u8 { self.data[0x125] & 0x7F }
+    pub fn stat_level(&self) -> u8 { self.data[0x148] }
+
+    /// Gets a party stat.
+    pub fn stat(&self, stat: Stat) -> u16 {
+        to_uint16::<Little>(&self.data[..], 0x14A + stat as usize * 2)
+    }
+
+    /// Checks if the stored checksum matches the one calculated from the data.
+    pub fn is_checksum_valid(&self) -> bool {
+        get_chk::<SIZE_8PARTY>(self.data, SIZE_8STORED) == self.checksum()
+    }
+
+    fn read_string(&self, offset: usize, length: usize) -> String {
+        let chars: Vec<u16> = (0..=length)
+            .map(|i| to_uint16::<Little>(&self.data[..], offset + i * 2))
+            .collect();
+        get_string7(&chars)
+    }
+}
+
+impl<'a> PK8Ref<'a> {
+    /// Creates a view over decrypted party sized data.
+    pub fn new(data: &'a [u8; SIZE_8PARTY]) -> Self { PK8Ref { data } }
+}
+
+impl<'a> PK8Mut<'a> {
+    /// Creates a mutable view over decrypted party sized data.
+    pub fn new(data: &'a mut [u8; SIZE_8PARTY]) -> Self { PK8Mut { data } }
+
+    /// Reborrows the data as a read-only view.
+    pub fn view(&self) -> PK8Ref<'_> { PK8Ref { data: self.data } }
+
+    /// Overwrites the data with an owned entity.
+    pub fn write_entity(&mut self, pk8: &PK8) -> Result<(), PkmError> {
+        *self.data = to_array(pk8.to_bytes()?)?;
+        Ok(())
+    }
+
+    fn set_bit(&mut self, offset: usize, bit_index: u32, value: bool) {
+        set_flag(&mut self.data[..], offset, bit_index, if value { Flag::Set } else { Flag::Unset })
+    }
+
+    fn write_u16(&mut self, offset: usize, value: u16) {
+        self.data[offset..offset + 2].copy_from_slice(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, offset: usize, value: u32) {
+        self.data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn set_encryption_constant(&mut self, value: u32) { self.write_u32(0x00, value) }
+    pub fn set_species(&mut self, value: Species) { self.write_u16(0x08, value as u16) }
+    pub fn set_held_item(&mut self, value: u16) { self.write_u16(0x0A, value) }
+    pub fn set_tid(&mut self, value: u16) { self.write_u16(0x0C, value) }
+    pub fn set_sid(&mut self, value: u16) { self.write_u16(0x0E, value) }
+    pub fn set_exp(&mut self, value: u32) { self.write_u32(0x10, value) }
+    pub fn set_ability(&mut self, value: Ability) { self.write_u16(0x14, value as u16) }
+    pub fn set_ability_number(&mut self, value: u8) {
+        self.data[0x16] = (self.data[0x16] & !7) | (value & 7);
+    }
+    pub fn set_favourite(&mut self, value: bool) { self.set_bit(0x16, 3, value) }
+    pub fn set_can_gigantamax(&mut self, value: bool) { self.set_bit(0x16, 4, value) }
+    pub fn set_pid(&mut self, value: u32) { self.write_u32(0x1C, value) }
+    pub fn set_nature(&mut self, value: Nature) { self.data[0x20] = value as u8 }
+    pub fn set_stat_nature(&mut self, value: Nature) { self.data[0x21] = value as u8 }
+    pub fn set_fateful_encounter(&mut self, value: bool) { self.set_bit(0x22, 0, value) }
+    pub fn set_gender(&mut self, value: Gender) {
+        self.data[0x22] = (self.data[0x22] & !0b1100) | ((value as u8) << 2);
+    }
+    pub fn set_form(&mut self, value: u16) { self.write_u16(0x24, value) }
+    pub fn set_ev(&mut self, stat: Stat, value: u8) { self.data[0x26 + stat as usize] = value }
+
+    /// Sets a ribbon or mark.
+    pub fn set_ribbon(&mut self, ribbon: RibbonIndex, value: Flag) {
+        let (offset, bit_index) = ribbon_flag(ribbon);
+        set_flag(&mut self.data[..], offset, bit_index, value)
+    }
+
+    /// Sets a ribbon or other bitflag.
+    pub fn set_flag(&mut self, offset: usize, bit_index: u32, value: Flag) {
+        set_flag(&mut self.data[..], offset, bit_index, value)
+    }
+
+    pub fn set_height_scalar(&mut self, value: u8) { self.data[0x50] = value }
+    pub fn set_weight_scalar(&mut self, value: u8) { self.data[0x51] = value }
+    pub fn set_move_id(&mut self, index: usize, value: Move) -> Result<(), PkmError> {
+        check_move_index(index)?;
+        self.write_u16(0x72 + index * 2, value as u16);
+        Ok(())
+    }
+    pub fn set_move_pp(&mut self, index: usize, value: u8) -> Result<(), PkmError> {
+        check_move_index(index)?;
+        self.data[0x7A + index] = value;
+        Ok(())
+    }
+    pub fn set_move_pp_ups(&mut self, index: usize, value: u8) -> Result<(), PkmError> {
+        check_move_index(index)?;
+        self.data[0x7E + index] = value;
+        Ok(())
+    }
+    pub fn set_relearn_move(&mut self, index: usize, value: Move) -> Result<(), PkmError> {
+        check_move_index(index)?;
+        self.write_u16(0x82 + index * 2, value as u16);
+        Ok(())
+    }
+    pub fn set_stat_hp_current(&mut self, value: u16) { self.write_u16(0x8A, value) }
+    pub fn set_iv32(&mut self, value: u32) { self.write_u32(0x8C, value) }
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let iv32 = (self.iv32() & !(0x1F << shift)) | ((value as u32 & 0x1F) << shift);
+        self.set_iv32(iv32)
+    }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.set_iv32((self.iv32() & !(1 << 30)) | ((value as u32) << 30))
+    }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.set_iv32((self.iv32() & !(1 << 31)) | ((value as u32) << 31))
+    }
+    pub fn set_dynamax_level(&mut self, value: u8) { self.data[0x90] = value }
+    pub fn set_version(&mut self, value: GameVersion) { self.data[0xDE] = value as u8 }
+    pub fn set_egg_location(&mut self, value: u16) { self.write_u16(0x120, value) }
+    pub fn set_met_location(&mut self, value: u16) { self.write_u16(0x122, value) }
+    pub fn set_ball(&mut self, value: Ball) { self.data[0x124] = value as u8 }
+    pub fn set_ot_gender(&mut self, value: Gender) {
+        self.data[0x125] = (self.data[0x125] & 0x7F) | (((value as u8) & 1) << 7);
+    }
+    pub fn set_met_level(&mut self, value: u8) {
+        self.data[0x125] = (self.data[0x125] & 0x80) | (value & 0x7F);
+    }
+    pub fn set_stat_level(&mut self, value: u8) { self.data[0x148] = value }
+    pub fn set_stat(&mut self, stat: Stat, value: u16) {
+        self.write_u16(0x14A + stat as usize * 2, value)
+    }
+
+    /// Recalculates the checksum from the current data.
+    pub fn refresh_checksum(&mut self) {
+        let chk = get_chk(&*self.data, SIZE_8STORED);
+        self.write_u16(0x06, chk)
+    }
+}
+
+/// Number of current and relearnable move slots.
+const MOVE_COUNT: usize = 4;
+
+fn check_move_index(index: usize) -> Result<(), PkmError> {
+    if index >= MOVE_COUNT {
+        return Err(PkmError::InvalidIndex { index, count: MOVE_COUNT });
+    }
+    Ok(())
+}
+
+/// Gets the offset and bit of a ribbon flag. The first 64 flags are stored
+/// from 0x34, the rest from 0x40.
+fn ribbon_flag(ribbon: RibbonIndex) -> (usize, u32) {
+    let index = ribbon as usize;
+    let offset = if index < 64 { 0x34 + index / 8 } else { 0x40 + (index - 64) / 8 };
+    (offset, (index % 8) as u32)
+}
+
+impl TryFrom<PK8Ref<'_>> for PK8 {
+    type Error = PkmError;
+
+    /// Parses the viewed data into an owned entity, validating its fields but
+    /// leaving the checksum to [`PK8Ref::is_checksum_valid`].
+    fn try_from(view: PK8Ref<'_>) -> Result<Self, Self::Error> {
+        PK8::parse(&view.data[..], ChecksumMode::Flag)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::game::enums::{ability::Ability, gender::Gender, nature::Nature, species::Species};
+    use crate::{
+        game::{
+            enums::{ability::Ability, gender::Gender, nature::Nature, species::Species}, game_data::{set_game_data, GameData}
+        }, pkm::util::pokecrypto::encrypt_array8
+    };
+    use alloc::vec;
     use core::convert::TryFrom;
 
     #[test]
@@ -579,6 +1180,237 @@ mod test {
         assert_eq!(true, orbeetle_e == orbeetle_d);
     }
 
+    #[test]
+    fn pk8_try_from_slice_test() {
+        let pk8 = &include_bytes!("util/tests/data/Orbeetle.pk8")[..];
+        let ek8 = &include_bytes!("util/tests/data/Orbeetle.ek8")[..];
+        let orbeetle = PK8::try_from(pk8).unwrap();
+        assert_eq!(orbeetle, PK8::try_from(ek8).unwrap());
+        assert_eq!(PK8::from(include_bytes!("util/tests/data/Orbeetle.pk8")), orbeetle);
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![SIZE_8STORED, SIZE_8PARTY], actual: 3 }),
+            PK8::try_from(&pk8[..3])
+        );
+    }
+
+    #[test]
+    fn pk8_try_from_stored_test() {
+        let pk8 = include_bytes!("util/tests/data/Orbeetle.pk8");
+        let stored = PK8::try_from(&pk8[..SIZE_8STORED]).unwrap();
+        assert!(!stored.has_party_stats);
+        assert_eq!(PK8::from(pk8).nickname, stored.nickname);
+    }
+
+    #[test]
+    fn pk8_try_from_error_test() {
+        let pk8 = include_bytes!("util/tests/data/Orbeetle.pk8");
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_8STORED, SIZE_8PARTY],
+                actual: 0x100
+            }),
+            PK8::try_from(&pk8[..0x100])
+        );
+
+        let mut corrupted = *pk8;
+        corrupted[0x70] = 0xFF;
+        assert_eq!(Err(PkmError::DecryptionFailed), PK8::try_from(&corrupted[..]));
+
+        let mut corrupted = *pk8;
+        corrupted[0x0C] ^= 0xFF;
+        let calculated = get_chk::<SIZE_8PARTY>(&corrupted, SIZE_8STORED);
+        assert_eq!(
+            Err(PkmError::ChecksumMismatch { stored: 0x4E8E, calculated }),
+            PK8::try_from(&corrupted[..])
+        );
+
+        let mut corrupted = *pk8;
+        corrupted[0x124] = 0xFF;
+        let chk = get_chk::<SIZE_8PARTY>(&corrupted, SIZE_8STORED);
+        corrupted[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        assert_eq!(
+            Err(PkmError::InvalidField { field: "ball", offset: 0x124, value: 0xFF }),
+            PK8::try_from(&corrupted[..])
+        );
+
+        corrupted[0x124] = Ball::Poke as u8;
+        corrupted[0xDE] = 0xFF;
+        let chk = get_chk::<SIZE_8PARTY>(&corrupted, SIZE_8STORED);
+        corrupted[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        assert_eq!(
+            Err(PkmError::InvalidField { field: "version", offset: 0xDE, value: 0xFF }),
+            PK8::try_from(&corrupted[..])
+        );
+    }
+
+    #[test]
+    fn pk8_checksum_mode_test() {
+        let mut corrupted = *include_bytes!("util/tests/data/Orbeetle.pk8");
+        corrupted[0x0C] ^= 0xFF;
+        assert!(matches!(
+            PK8::parse(&corrupted, ChecksumMode::Reject),
+            Err(PkmError::ChecksumMismatch { .. })
+        ));
+
+        let mut flagged = PK8::parse(&corrupted, ChecksumMode::Flag).unwrap();
+        assert!(!flagged.is_checksum_valid());
+        flagged.refresh_checksum();
+        assert!(flagged.is_checksum_valid());
+    }
+
+    #[test]
+    fn pk8_load_slot_test() {
+        let pk8 = include_bytes!("util/tests/data/Orbeetle.pk8");
+        assert_eq!(Slot::Empty, PK8::load_slot(&[0u8; SIZE_8STORED]));
+        assert_eq!(Slot::Entity(PK8::from(pk8)), PK8::load_slot(pk8));
+
+        let mut corrupted = *pk8;
+        corrupted[0x0C] ^= 0xFF;
+        assert!(matches!(
+            PK8::load_slot(&corrupted),
+            Slot::BadEgg(PkmError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn pk8_round_trip_test() {
+        let fixtures: [&[u8; SIZE_8PARTY]; 3] = [
+            include_bytes!("util/tests/data/Orbeetle.pk8"),
+            include_bytes!("util/tests/data/Dracovish.pk8"),
+            include_bytes!("util/tests/data/Grookey.pk8"),
+        ];
+        for data in fixtures.iter() {
+            assert_eq!(data.to_vec(), PK8::from(*data).build().unwrap().to_bytes());
+        }
+
+        let encrypted = include_bytes!("util/tests/data/Orbeetle.ek8");
+        let mut decrypted = *encrypted;
+        decrypt_if_encrypted8(&mut decrypted);
+        assert_eq!(decrypted.to_vec(), PK8::from(encrypted).build().unwrap().to_bytes());
+    }
+
+    #[test]
+    fn pk8_unused_region_test() {
+        let mut data = *include_bytes!("util/tests/data/Orbeetle.pk8");
+        data[0x4C] = 0x12;
+        data[0xCE..0xDC].copy_from_slice(&[0xAB; 14]);
+        data[0xE9] = 0x34;
+        data[0x16] |= 0b1010_0000;
+
+        let mut pk8 = PK8::from(&data);
+        assert_eq!(0x12, pk8.unk_4c[0]);
+        assert_eq!([0xAB; 14], pk8.unk_ce);
+        assert_eq!(0x34, pk8.unk_e9[0]);
+        assert_eq!(0b101, pk8.unk_16);
+        pk8.ability_number = 2;
+        pk8.nickname = String::from("Bug");
+        let built = pk8.build().unwrap().to_bytes();
+        assert_eq!(0x12, built[0x4C]);
+        assert_eq!([0xAB; 14], built[0xCE..0xDC]);
+        assert_eq!(0x34, built[0xE9]);
+        assert_eq!(0b1010_0000, built[0x16] & 0b1110_0000);
+
+        let rebuilt = PK8::try_from(&built[..]).unwrap();
+        assert_eq!(2, rebuilt.ability_number);
+        assert_eq!("Bug", rebuilt.nickname);
+        assert!(rebuilt.is_checksum_valid());
+    }
+
+    #[test]
+    fn pk8_string_trash_test() {
+        let mut data = *include_bytes!("util/tests/data/Orbeetle.pk8");
+        data[0x58 + 20..0x58 + 22].copy_from_slice(&(b'A' as u16).to_le_bytes());
+        data[0xF8 + 22..0xF8 + 24].copy_from_slice(&(b'B' as u16).to_le_bytes());
+        let checksum = get_chk(&data, SIZE_8STORED);
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+
+        let mut pk8 = PK8::from(&data);
+        assert_eq!("Orbeetle", pk8.nickname);
+        assert_eq!(b'A' as u16, pk8.nickname_trash[10]);
+        assert_eq!(data.to_vec(), pk8.build().unwrap().to_bytes());
+
+        pk8.nickname = String::from("Orbeetleab");
+        let built = pk8.build().unwrap().to_bytes();
+        assert_eq!([0, 0], built[0x58 + 20..0x58 + 22]);
+        assert_eq!(b'B' as u16, to_uint16::<Little>(&built, 0xF8 + 22));
+        assert_eq!("Orbeetleab", PK8::try_from(&built[..]).unwrap().nickname);
+
+        pk8.nickname = String::from("Orb");
+        let built = pk8.build().unwrap().to_bytes();
+        assert_eq!("Orb", PK8::try_from(&built[..]).unwrap().nickname);
+        assert_eq!(b'A' as u16, to_uint16::<Little>(&built, 0x58 + 20));
+    }
+
+    #[test]
+    fn pk8_ref_test() {
+        let data = include_bytes!("util/tests/data/Dracovish.pk8");
+        let dracovish = PK8::from(data);
+        let view = PK8Ref::new(data);
+        assert_eq!(Some(dracovish.species), view.species());
+        assert_eq!(dracovish.pid, view.pid());
+        assert_eq!(Some(dracovish.ability), view.ability());
+        assert_eq!(dracovish.ability_number, view.ability_number());
+        assert_eq!(Some(dracovish.nature), view.nature());
+        assert_eq!(Some(dracovish.gender), view.gender());
+        assert_eq!(dracovish.ev_atk, view.ev(Stat::Atk));
+        assert_eq!(dracovish.iv(Stat::SpA), view.iv(Stat::SpA));
+        assert_eq!(dracovish.nickname, view.nickname());
+        assert_eq!(dracovish.ot_name, view.ot_name());
+        assert_eq!(Some(dracovish.move1), view.move_id(0));
+        assert_eq!(None, view.move_id(4));
+        assert_eq!(Some(dracovish.move_4_pp_ups), view.move_pp_ups(3));
+        assert_eq!(None, view.move_pp(4));
+        assert_eq!(None, view.relearn_move(4));
+        assert_eq!(Some(dracovish.version), view.version());
+        assert_eq!(Some(dracovish.ball), view.ball());
+        assert_eq!(dracovish.ot_gender, view.ot_gender());
+        assert_eq!(dracovish.met_level, view.met_level());
+        assert_eq!(dracovish.stat_spe, view.stat(Stat::Spe));
+        assert_eq!(dracovish.ribbon_champion_kalos, view.ribbon(RibbonIndex::ChampionKalos));
+        assert!(view.is_checksum_valid());
+        assert_eq!(dracovish, PK8::try_from(view).unwrap());
+    }
+
+    #[test]
+    fn pk8_mut_test() {
+        let mut data = *include_bytes!("util/tests/data/Grookey.pk8");
+        let mut view = PK8Mut::new(&mut data);
+        view.set_iv(Stat::HP, 16);
+        view.set_iv(Stat::SpD, 26);
+        view.set_ability_number(4);
+        view.set_gender(Gender::Female);
+        view.set_met_level(7);
+        view.set_ribbon(RibbonIndex::ChampionKalos, Flag::Set);
+        view.set_ribbon(RibbonIndex::MarkSlump, Flag::Set);
+        view.set_move_pp(3, 5).unwrap();
+        assert_eq!(Flag::Set, view.flag(0x34, 0));
+        assert_eq!(Flag::Set, view.flag(0x44, 1));
+        assert_eq!(
+            Err(PkmError::InvalidIndex { index: 4, count: 4 }),
+            view.set_move_id(4, Move::Tackle)
+        );
+        assert!(!view.is_checksum_valid());
+        view.refresh_checksum();
+        assert!(view.is_checksum_valid());
+
+        let grookey = PK8::try_from(view.view()).unwrap();
+        assert_eq!(16, grookey.iv(Stat::HP));
+        assert_eq!(26, grookey.iv(Stat::SpD));
+        assert_eq!(4, grookey.ability_number);
+        assert_eq!(Gender::Female, grookey.gender);
+        assert_eq!(7, grookey.met_level);
+        assert_eq!(Flag::Set, grookey.ribbon_champion_kalos);
+        assert_eq!(Flag::Set, grookey.ribbon_mark_slump);
+        assert_eq!(Flag::Unset, grookey.ribbon_effort);
+        assert_eq!(5, grookey.move4_pp);
+
+        let mut dracovish = PK8::from(include_bytes!("util/tests/data/Dracovish.pk8"));
+        dracovish.refresh_checksum();
+        view.write_entity(&dracovish).unwrap();
+        assert_eq!(Some(Species::Dracovish), view.species());
+        assert_eq!(dracovish, PK8::try_from(view.view()).unwrap());
+    }
+
     #[test]
     fn pk8_calc_checksum_test() {
         let orbeetle = PK8::from(include_bytes!("util/tests/data/Orbeetle.pk8"));
@@ -587,6 +1419,17 @@ mod test {
         assert_eq!(0x6469, dracovish.checksum);
     }
 
+    #[test]
+    fn pk8_is_checksum_valid_test() {
+        let orbeetle = PK8::from(include_bytes!("util/tests/data/Orbeetle.pk8"));
+        let mut dracovish = PK8::from(include_bytes!("util/tests/data/Dracovish.pk8"));
+        assert!(orbeetle.is_checksum_valid());
+        assert!(dracovish.is_checksum_valid());
+
+        dracovish.exp += 1;
+        assert!(!dracovish.is_checksum_valid());
+    }
+
     #[test]
     fn pk8_get_test_1() {
         let dracovish = PK8::from(include_bytes!("util/tests/data/Dracovish.pk8"));
@@ -634,12 +1477,12 @@ mod test {
         assert_eq!(0, dracovish.move_2_pp_ups);
         assert_eq!(0, dracovish.move_3_pp_ups);
         assert_eq!(3, dracovish.move_4_pp_ups);
-        assert_eq!(31, dracovish.iv_hp);
-        assert_eq!(31, dracovish.iv_atk);
-        assert_eq!(31, dracovish.iv_def);
-        assert_eq!(23, dracovish.iv_spa);
-        assert_eq!(2, dracovish.iv_spd);
-        assert_eq!(4, dracovish.iv_spe);
+        assert_eq!(31, dracovish.iv(Stat::HP));
+        assert_eq!(31, dracovish.iv(Stat::Atk));
+        assert_eq!(31, dracovish.iv(Stat::Def));
+        assert_eq!(23, dracovish.iv(Stat::SpA));
+        assert_eq!(2, dracovish.iv(Stat::SpD));
+        assert_eq!(4, dracovish.iv(Stat::Spe));
         assert_eq!(10, dracovish.dynamax_level);
     }
 
@@ -648,14 +1491,254 @@ mod test {
         let mut grookey = PK8::from(include_bytes!("util/tests/data/Grookey.pk8"));
         assert_eq!(0x7FD048F, grookey.iv32);
         assert_eq!(0xAD1E, grookey.checksum);
-        grookey.iv_hp = 16;
-        grookey.iv_atk = 26;
-        grookey.iv_def = 20;
-        grookey.iv_spe = 31;
-        grookey.iv_spa = 19;
-        grookey.iv_spd = 26;
+        grookey.set_iv(Stat::HP, 16);
+        grookey.set_iv(Stat::Atk, 26);
+        grookey.set_iv(Stat::Def, 20);
+        grookey.set_iv(Stat::Spe, 31);
+        grookey.set_iv(Stat::SpA, 19);
+        grookey.set_iv(Stat::SpD, 26);
         grookey.refresh_checksum();
         assert_eq!(0x353FD350, grookey.iv32);
         assert_eq!(0xA921, grookey.checksum);
     }
+
+    #[test]
+    fn pk8_entity_test() {
+        let dracovish = PK8::from(include_bytes!("util/tests/data/Dracovish.pk8"));
+        let orbeetle = PK8::from(include_bytes!("util/tests/data/Orbeetle.pk8"));
+        let grookey = PK8::from(include_bytes!("util/tests/data/Grookey.pk8"));
+
+        let mut entities: [&dyn Entity; 3] = [&dracovish, &orbeetle, &grookey];
+        entities.sort_by(|a, b| a.species().partial_cmp(&b.species()).unwrap());
+        let species: Vec<Species> = entities.iter().map(|e| e.species()).collect();
+        assert_eq!([Species::Grookey, Species::Orbeetle, Species::Dracovish], species[..]);
+
+        let entity: &dyn Entity = &dracovish;
+        assert_eq!(dracovish.nickname, entity.nickname());
+        assert_eq!(dracovish.ot_name, entity.ot_name());
+        assert_eq!(30756, entity.tid());
+        assert_eq!(45312, entity.sid());
+        assert_eq!(0xC730F59, entity.pid());
+        assert_eq!(0xAC731A09, entity.encryption_constant());
+        assert_eq!([31, 31, 31, 4, 23, 2], entity.ivs());
+        assert_eq!([4, 252, 0, 252, 0, 0], entity.evs());
+        assert_eq!(
+            [dracovish.move1, dracovish.move2, dracovish.move3, dracovish.move4],
+            entity.moves()
+        );
+        assert_eq!(dracovish.ball, entity.ball());
+        assert_eq!(dracovish.met_location, entity.met_location());
+        assert_eq!(dracovish.met_level, entity.met_level());
+        assert_eq!((dracovish.met_year, dracovish.met_month, dracovish.met_day), entity.met_date());
+        assert_eq!(dracovish.language, entity.language());
+        assert_eq!(GameVersion::SW, entity.version());
+    }
+
+    #[test]
+    fn pk8_entity_set_test() {
+        let mut grookey = PK8::from(include_bytes!("util/tests/data/Grookey.pk8"));
+        let entity: &mut dyn Entity = &mut grookey;
+        entity.set_ivs([16, 26, 20, 31, 19, 26]);
+        entity.set_evs([1, 2, 3, 4, 5, 6]);
+        entity.set_nickname("Monkey");
+        entity.set_met_date(21, 2, 27);
+        grookey.refresh_checksum();
+        assert_eq!(0x353FD350, grookey.iv32);
+
+        let rebuilt = PK8::try_from(&*grookey.build().unwrap().to_bytes()).unwrap();
+        assert_eq!([16, 26, 20, 31, 19, 26], rebuilt.ivs());
+        assert_eq!([1, 2, 3, 4, 5, 6], rebuilt.evs());
+        assert_eq!("Monkey", rebuilt.nickname());
+        assert_eq!((21, 2, 27), rebuilt.met_date());
+    }
+
+    #[test]
+    fn pk8_shiny_test() {
+        let mut dracovish = PK8::from(include_bytes!("util/tests/data/Dracovish.pk8"));
+        assert_eq!(0x32, dracovish.psv());
+        assert_eq!(0xC92, dracovish.tsv());
+        assert!(!dracovish.is_shiny());
+
+        // 0xC67D ^ 0x0F59 == 30756 ^ 45312
+        dracovish.pid = 0xC67D0F59;
+        assert_eq!(0xC92, dracovish.psv());
+        assert!(dracovish.is_shiny());
+
+        dracovish.sid = 0;
+        assert_eq!(0x782, dracovish.tsv());
+        assert!(!dracovish.is_shiny());
+        dracovish.tid = 45312;
+        dracovish.sid = 30756;
+        assert!(dracovish.is_shiny());
+
+        dracovish.refresh_checksum();
+        let rebuilt = PK8::try_from(&*dracovish.build().unwrap().to_bytes()).unwrap();
+        assert!(rebuilt.is_shiny());
+    }
+
+    #[test]
+    fn pk8_derived_test() {
+        let mut grookey = PK8::from(include_bytes!("util/tests/data/Grookey.pk8"));
+        assert!(!grookey.is_egg());
+        assert!(!grookey.is_nicknamed());
+        assert_eq!(8, grookey.generation());
+        assert!(grookey.is_untraded());
+
+        grookey.set_iv(Stat::SpD, 31);
+        assert_eq!(31, grookey.iv(Stat::SpD));
+        assert!(!grookey.is_egg());
+        assert!(!grookey.is_nicknamed());
+
+        grookey.set_is_egg(true);
+        grookey.set_is_nicknamed(true);
+        grookey.set_iv(Stat::Spe, 40);
+        assert_eq!(31, grookey.iv(Stat::Spe));
+        assert!(grookey.is_egg());
+        assert!(grookey.is_nicknamed());
+        assert_eq!(0b11, grookey.iv32 >> 30);
+
+        grookey.ht_name = "Hop".into();
+        assert!(!grookey.is_untraded());
+        grookey.ht_name.clear();
+        grookey.version = GameVersion::UM;
+        assert_eq!(7, grookey.generation());
+        assert!(!grookey.is_untraded());
+    }
+
+    fn personal_entry(base_stats: [u8; 6], exp_growth: u8) -> PersonalInfo {
+        PersonalInfo { base_stats, exp_growth, ..PersonalInfo::default() }
+    }
+
+    #[test]
+    fn pk8_stats_test() {
+        let data = include_bytes!("util/tests/data/Dracovish.pk8");
+        let mut dracovish = PK8::from(data);
+        let personal = personal_entry([90, 90, 100, 75, 70, 80], 5);
+        assert_eq!(100, dracovish.current_level(&personal));
+        assert_eq!([322, 279, 212, 222, 184, 167], dracovish.get_stats(&personal, 100));
+
+        dracovish.stat_level = 1;
+        dracovish.stat_hp_max = 0;
+        dracovish.stat_hp_current = 0;
+        dracovish.stat_spd = 0;
+        dracovish.reset_party_stats_with(&personal);
+        assert_eq!(&data[..], &dracovish.build().unwrap().to_bytes()[..]);
+
+        dracovish.stat_nature = Nature::Modest;
+        dracovish.ht_spe = Flag::Set;
+        dracovish.reset_party_stats_with(&personal);
+        assert_eq!([31, 31, 31, 31, 23, 2], dracovish.stat_ivs());
+        assert_eq!(4, dracovish.iv(Stat::Spe));
+        assert_eq!([251, 249, 184], [dracovish.stat_atk, dracovish.stat_spe, dracovish.stat_spa]);
+
+        let mut orbeetle = PK8::from(include_bytes!("util/tests/data/Orbeetle.pk8"));
+        let personal = personal_entry([60, 45, 110, 90, 80, 120], 0);
+        orbeetle.exp = 0;
+        orbeetle.reset_party_stats_with(&personal);
+        assert_eq!(1, orbeetle.stat_level);
+        assert_eq!(12, orbeetle.stat_hp_max);
+
+        let data = include_bytes!("util/tests/data/Dracovish.pk8");
+        let personal = personal_entry([90, 90, 100, 75, 70, 80], 5);
+        let mut stored = PK8::try_from(&data[..SIZE_8STORED]).unwrap();
+        assert_eq!(SIZE_8STORED, stored.build().unwrap().to_bytes().len());
+        stored.reset_party_stats_with(&personal);
+        assert!(stored.has_party_stats);
+        assert_eq!(&data[..], &stored.build().unwrap().to_bytes()[..]);
+    }
+
+    #[test]
+    fn pk8_mint_test() {
+        let mut dracovish = PK8::from(include_bytes!("util/tests/data/Dracovish.pk8"));
+        let personal = personal_entry([90, 90, 100, 75, 70, 80], 5);
+        assert!(!dracovish.is_minted());
+
+        dracovish.apply_mint(Nature::Modest);
+        assert!(dracovish.is_minted());
+        assert_eq!(Nature::Mild, dracovish.nature);
+        assert_eq!(Nature::Modest, dracovish.stat_nature_for(8));
+        assert_eq!(Nature::Modest, dracovish.stat_nature_for(9));
+        assert_eq!(Nature::Mild, dracovish.stat_nature_for(7));
+        dracovish.reset_party_stats_with(&personal);
+        assert_eq!([251, 236, 184], [dracovish.stat_atk, dracovish.stat_def, dracovish.stat_spa]);
+
+        let rebuilt = PK8::try_from(&*dracovish.build().unwrap().to_bytes()).unwrap();
+        assert_eq!(Nature::Mild, rebuilt.nature);
+        assert_eq!(Nature::Modest, rebuilt.stat_nature);
+
+        dracovish.remove_mint();
+        assert!(!dracovish.is_minted());
+        dracovish.reset_party_stats_with(&personal);
+        assert_eq!([279, 212, 184], [dracovish.stat_atk, dracovish.stat_def, dracovish.stat_spa]);
+    }
+
+    #[test]
+    fn pk8_shedinja_stats_test() {
+        let mut grookey = PK8::from(include_bytes!("util/tests/data/Grookey.pk8"));
+        grookey.species = Species::Shedinja;
+        let personal = personal_entry([1, 90, 45, 40, 30, 30], 1);
+        grookey.reset_party_stats_with(&personal);
+        assert_eq!(1, grookey.stat_hp_max);
+        assert_eq!(1, grookey.stat_hp_current);
+        assert_eq!(grookey.current_level(&personal), grookey.stat_level);
+    }
+
+    #[test]
+    fn pk8_game_data_test() {
+        let data = include_bytes!("util/tests/data/Dracovish.pk8");
+        let mut dracovish = PK8::from(data);
+        let mut table = vec![PersonalInfo::default(); Species::Dracovish as usize + 1];
+        table[Species::Dracovish as usize] = personal_entry([90, 90, 100, 75, 70, 80], 5);
+        set_game_data(GameData {
+            personal: PersonalTable::new(table, Species::Dracovish as u16),
+            ..GameData::default()
+        });
+        assert_eq!([90, 90, 100, 75, 70, 80], dracovish.personal_info().unwrap().base_stats);
+
+        dracovish.stat_hp_max = 0;
+        dracovish.stat_spd = 0;
+        dracovish.reset_party_stats().unwrap();
+        assert_eq!(&data[..], &dracovish.build().unwrap().to_bytes()[..]);
+
+        dracovish.species = Species::Calyrex;
+        assert_eq!(None, dracovish.personal_info());
+        assert_eq!(
+            Err(PkmError::InvalidField { field: "species", offset: 0x08, value: 898 }),
+            dracovish.reset_party_stats()
+        );
+    }
+
+    #[test]
+    fn pk8_from_stored_test() {
+        let pk8 = include_bytes!("util/tests/data/Orbeetle.pk8");
+        let party = PK8::from(pk8);
+        assert!(party.has_party_stats);
+
+        let mut stored = <[u8; SIZE_8STORED]>::try_from(&pk8[..SIZE_8STORED]).unwrap();
+        let from_stored = PK8::from(&stored);
+        assert!(!from_stored.has_party_stats);
+        assert_eq!(party.nickname, from_stored.nickname);
+        assert_eq!(party.checksum, from_stored.checksum);
+        assert_eq!(0, from_stored.stat_level);
+        assert_eq!(from_stored, PK8::from(&encrypt_array8(&mut stored)));
+    }
+
+    #[test]
+    fn pk8_build_size_test() {
+        let pk8 = include_bytes!("util/tests/data/Orbeetle.pk8");
+        let mut party = PK8::from(pk8);
+        let raw = party.build().unwrap();
+        assert!(raw.is_party());
+        assert_eq!(SIZE_8PARTY, raw.to_bytes().len());
+
+        let stored = <[u8; SIZE_8STORED]>::try_from(&pk8[..SIZE_8STORED]).unwrap();
+        let mut from_stored = PK8::from(&stored);
+        let raw = from_stored.build().unwrap();
+        assert!(!raw.is_party());
+        let rebuilt = <[u8; SIZE_8STORED]>::try_from(&*raw.to_bytes()).unwrap();
+        assert_eq!(from_stored, PK8::from(&rebuilt));
+
+        from_stored.has_party_stats = true;
+        assert_eq!(SIZE_8PARTY, from_stored.build().unwrap().to_bytes().len());
+    }
 }
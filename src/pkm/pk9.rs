@@ -0,0 +1,819 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+use deku::{
+    bitvec::{BitVec, BitView, Msb0}, ctx::Endian, prelude::*
+};
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ability::Ability, ball::Ball, flag::Flag, game_version::GameVersion, gender::Gender, language_id::LanguageID, move_type::MoveType, moves::Move, nature::Nature, species::Species, stat::Stat
+    }, pkm::{
+        strings::string_converter::{get_string7, set_string7b}, util::{
+            pokecrypto::{decrypt_if_encrypted8, get_chk, SIZE_8PARTY, SIZE_8STORED}, species_converter::{get_internal9, get_national9}
+        }
+    }, util::custom_read_write::{read, write}
+};
+
+pub const FORMAT: i32 = 9;
+
+pub const MAX_IV: i32 = 31;
+pub const MAX_EV: i32 = 252;
+pub const OT_LENGTH: usize = 12;
+pub const NICK_LENGTH: usize = 12;
+
+/// Number of TM flags stored in the move record.
+pub const COUNT_RECORD: usize = 200;
+
+/// Tera Type override value indicating that the original type is used.
+pub const TERA_OVERRIDE_NONE: u8 = 19;
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawPK9 {
+    data: [u8; SIZE_8PARTY],
+}
+
+impl RawPK9 {
+    pub fn to_bytes(&self) -> [u8; SIZE_8PARTY] { self.data }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", ctx = "_endian: deku::ctx::Endian")]
+#[repr(C)]
+pub struct PK9 {
+    pub encryption_constant: u32,
+    pub sanity: u16,
+    #[deku(update = "get_chk::<SIZE_8PARTY>(&to_array(self.to_bytes()?)?, SIZE_8STORED)")]
+    pub checksum: u16,
+
+    // Block A
+    #[deku(
+        update = "if get_national9(self.species_id9) == self.species { self.species_id9 } else { get_internal9(self.species) }"
+    )]
+    pub species_id9: u16,
+    #[deku(skip, default = "get_national9(*species_id9)")]
+    pub species: Species,
+    pub held_item: u16,
+    pub tid: u16,
+    pub sid: u16,
+    pub exp: u32,
+    pub ability: Ability,
+    #[deku(bits = 4)]
+    pub unk_16: u8,
+    #[deku(bits = 1)]
+    pub favourite: bool,
+    #[deku(bits = 3)]
+    pub ability_number: u8,
+    pub unk_17: u8,
+    pub mark_value: u16,
+    pub unk_1a: [u8; 2],
+    pub pid: u32,
+
+    pub nature: Nature,
+    pub stat_nature: Nature,
+    #[deku(bits = 4)]
+    pub unk_22: u8,
+    #[deku(bits = 2)]
+    pub gender: Gender,
+    #[deku(bits = 1)]
+    pub flag2: Flag,
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    pub unk_23: u8,
+    pub form: u16,
+    pub ev_hp: u8,
+    pub ev_atk: u8,
+    pub ev_def: u8,
+    pub ev_spe: u8,
+    pub ev_spa: u8,
+    pub ev_spd: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub cnt_sheen: u8,
+    #[deku(bits = 4)]
+    pub pkrs_strain: u8,
+    #[deku(bits = 4)]
+    pub pkrs_days: u8,
+    pub unk_33: u8,
+    #[deku(bits = 1)]
+    pub ribbon_effort: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battler_expert: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battler_skillful: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_training: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_best_friends: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_sinnoh: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_g3_hoenn: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_kalos: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_gorgeous: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_smile: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_snooze: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_relax: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_careless: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_downcast: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_shock: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_alert: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_national: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_country: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_legend: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_record: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_footprint: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_artist: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_gorgeous_royal: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_royal: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_souvenir: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_special: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_birthday: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_event: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_premier: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_classic: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_world: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_earth: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_champion_g6_hoenn: Flag,
+    #[deku(bits = 1)]
+    pub has_battle_memory_ribbon: Flag,
+    #[deku(bits = 1)]
+    pub has_contest_memory_ribbon: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_world: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_national: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_regional: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_battle: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_wishing: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_battle_royale: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_alola: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_toughness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_cleverness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_cuteness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_beauty: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_coolness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_contest_star: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_dusk: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_sleepy_time: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_lunchtime: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_master_rank: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_tower_master: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_champion_galar: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battle_tree_master: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_battle_tree_great: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_sandstorm: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_dry: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_blizzard: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_snowy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_stormy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_rainy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_cloudy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_dawn: Flag,
+    pub ribbon_count_memory_contest: u8,
+    pub ribbon_count_memory_battle: u8,
+    pub unk_3e: [u8; 2],
+
+    // 0x40 Ribbon 1
+    #[deku(bits = 1)]
+    pub ribbon_mark_absent_minded: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_rowdy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_rare: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_uncommon: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_curry: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_fishing: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_destiny: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_misty: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_angry: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_joyful: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_zoned_out: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_intense: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_calmness: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_charismatic: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_excited: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_jittery: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_scowling: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_crafty: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_ferocious: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_intellectual: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_peeved: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_upbeat: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_teary: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_smiley: Flag,
+
+    #[deku(bits = 1)]
+    pub ribbon_mark_thorny: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_humble: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_unsure: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_prideful: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_zero_energy: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_pumped_up: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_flustered: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_kindly: Flag,
+    // 0x44 Ribbon 2
+    #[deku(bits = 1)]
+    pub rib44_7: Flag,
+    #[deku(bits = 1)]
+    pub rib44_6: Flag,
+    #[deku(bits = 1)]
+    pub rib44_5: Flag,
+    #[deku(bits = 1)]
+    pub rib44_4: Flag,
+    #[deku(bits = 1)]
+    pub rib44_3: Flag,
+    #[deku(bits = 1)]
+    pub rib44_2: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_slump: Flag,
+    #[deku(bits = 1)]
+    pub ribbon_mark_vigor: Flag,
+
+    #[deku(bits = 1)]
+    pub rib45_7: Flag,
+    #[deku(bits = 1)]
+    pub rib45_6: Flag,
+    #[deku(bits = 1)]
+    pub rib45_5: Flag,
+    #[deku(bits = 1)]
+    pub rib45_4: Flag,
+    #[deku(bits = 1)]
+    pub rib45_3: Flag,
+    #[deku(bits = 1)]
+    pub rib45_2: Flag,
+    #[deku(bits = 1)]
+    pub rib45_1: Flag,
+    #[deku(bits = 1)]
+    pub rib45_0: Flag,
+
+    #[deku(bits = 1)]
+    pub rib46_7: Flag,
+    #[deku(bits = 1)]
+    pub rib46_6: Flag,
+    #[deku(bits = 1)]
+    pub rib46_5: Flag,
+    #[deku(bits = 1)]
+    pub rib46_4: Flag,
+    #[deku(bits = 1)]
+    pub rib46_3: Flag,
+    #[deku(bits = 1)]
+    pub rib46_2: Flag,
+    #[deku(bits = 1)]
+    pub rib46_1: Flag,
+    #[deku(bits = 1)]
+    pub rib46_0: Flag,
+
+    #[deku(bits = 1)]
+    pub rib47_7: Flag,
+    #[deku(bits = 1)]
+    pub rib47_6: Flag,
+    #[deku(bits = 1)]
+    pub rib47_5: Flag,
+    #[deku(bits = 1)]
+    pub rib47_4: Flag,
+    #[deku(bits = 1)]
+    pub rib47_3: Flag,
+    #[deku(bits = 1)]
+    pub rib47_2: Flag,
+    #[deku(bits = 1)]
+    pub rib47_1: Flag,
+    #[deku(bits = 1)]
+    pub rib47_0: Flag,
+    pub u48: u32,
+    pub unk_4c: [u8; 4],
+    pub height_scalar: u8,
+    pub weight_scalar: u8,
+    pub scale: u8,
+    pub unk_53: [u8; 5],
+    // Block B
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    pub move1: Move,
+    pub move2: Move,
+    pub move3: Move,
+    pub move4: Move,
+    pub move1_pp: u8,
+    pub move2_pp: u8,
+    pub move3_pp: u8,
+    pub move4_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move_2_pp_ups: u8,
+    pub move_3_pp_ups: u8,
+    pub move_4_pp_ups: u8,
+    pub relearn_move1: Move,
+    pub relearn_move2: Move,
+    pub relearn_move3: Move,
+    pub relearn_move4: Move,
+    pub stat_hp_current: u16,
+    pub iv32: u32,
+    pub status_condition: i32,
+    pub tera_type_original: MoveType,
+    /// Tera Type set by a Tera Shard, or [`TERA_OVERRIDE_NONE`].
+    pub tera_type_override: u8,
+    pub unk_96: [u8; 18],
+    // Block C
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ht_name, OT_LENGTH))"
+    )]
+    pub ht_name: String,
+    pub ht_gender: Gender,
+    pub ht_language: LanguageID,
+    pub current_handler: u8,
+    pub unk_c5: u8,
+    pub ht_trainer_id: u16,
+    pub ht_friendship: u8,
+    pub ht_intensity: u8,
+    pub ht_memory: u8,
+    pub ht_feeling: u8,
+    pub ht_text_var: u16,
+    pub unk_ce: [u8; 14],
+    pub fullness: u8,
+    pub enjoyment: u8,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    #[deku(
+        reader = "read::read_game_version(deku::rest)",
+        writer = "write::write_game_version(deku::output, self.battle_version)"
+    )]
+    pub battle_version: GameVersion,
+    pub unk_e0: [u8; 2],
+    // region: u8,
+    // console_region: u8,
+    pub language: LanguageID,
+    pub unk_e3: u8,
+    pub form_argument: u32,
+    pub affixed_ribbon: i8,
+    pub unk_e9: [u8; 15],
+    // Block D
+    #[deku(
+        reader = "read::read_string_custom(deku::rest, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom(deku::output, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    pub ot_friendship: u8,
+    pub ot_intensity: u8,
+    pub ot_memory: u8,
+    pub unk_115: u8,
+    pub ot_text_var: u16,
+    pub ot_feeling: u8,
+    pub egg_year: u8,
+    pub egg_month: u8,
+    pub egg_day: u8,
+    pub met_year: u8,
+    pub met_month: u8,
+    pub met_day: u8,
+    /// Level up to which the entity obeys its current trainer.
+    pub obedience_level: u8,
+    pub egg_location: u16,
+    pub met_location: u16,
+    pub ball: Ball,
+    #[deku(bits = "1")]
+    pub ot_gender: Gender,
+    #[deku(bits = "7")]
+    pub met_level: u8,
+    #[deku(bits = "2")]
+    pub unk_126: u8,
+    #[deku(bits = "1")]
+    pub ht_spe: Flag,
+    #[deku(bits = "1")]
+    pub ht_spd: Flag,
+    #[deku(bits = "1")]
+    pub ht_spa: Flag,
+    #[deku(bits = "1")]
+    pub ht_def: Flag,
+    #[deku(bits = "1")]
+    pub ht_atk: Flag,
+    #[deku(bits = "1")]
+    pub ht_hp: Flag,
+    /// TM flags, one bit per TM starting from TM001.
+    pub raw_move_record: [u8; 25],
+    pub tracker: u64,
+
+    // Party Stats
+    pub stat_level: u8,
+    pub unk_149: u8,
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spe: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    pub unk_156: [u8; 2],
+}
+
+impl PK9 {
+    /// Checks if the entity originated from Pokémon Scarlet or Violet.
+    pub fn is_sv_origin(&self) -> bool { Self::is_sv(self.version) }
+
+    /// Gets the Tera Type the entity currently Terastallizes into.
+    pub fn tera_type(&self) -> MoveType {
+        match self.tera_type_override {
+            TERA_OVERRIDE_NONE => self.tera_type_original,
+            value => MoveType::n(value as i8).unwrap_or(self.tera_type_original),
+        }
+    }
+
+    /// Gets whether the TM at `index` has been learned.
+    pub fn get_move_record_flag(&self, index: usize) -> bool {
+        index < COUNT_RECORD
+            && self.raw_move_record.get(index >> 3).is_some_and(|&b| (b >> (index & 7)) & 1 == 1)
+    }
+
+    /// Sets whether the TM at `index` has been learned.
+    pub fn set_move_record_flag(&mut self, index: usize, value: bool) {
+        if index >= COUNT_RECORD {
+            return;
+        }
+        let b = &mut self.raw_move_record[index >> 3];
+        *b = (*b & !(1 << (index & 7))) | ((value as u8) << (index & 7));
+    }
+
+    /// Gets the IV of a stat.
+    pub fn iv(&self, stat: Stat) -> u8 { ((self.iv32 >> (stat as u32 * 5)) & 0x1F) as u8 }
+    /// Sets the IV of a stat, capping it at [`MAX_IV`].
+    pub fn set_iv(&mut self, stat: Stat, value: u8) {
+        let shift = stat as u32 * 5;
+        let value = value.min(MAX_IV as u8) as u32;
+        self.iv32 = (self.iv32 & !(0x1F << shift)) | (value << shift);
+    }
+    pub fn is_egg(&self) -> bool { (self.iv32 >> 30) & 1 == 1 }
+    pub fn set_is_egg(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 30)) | ((value as u32) << 30);
+    }
+    pub fn is_nicknamed(&self) -> bool { (self.iv32 >> 31) & 1 == 1 }
+    pub fn set_is_nicknamed(&mut self, value: bool) {
+        self.iv32 = (self.iv32 & !(1 << 31)) | ((value as u32) << 31);
+    }
+
+    /// Shiny value of the PID, compared against the [`PK9::tsv`].
+    pub fn psv(&self) -> i32 { ((self.pid >> 16 ^ (self.pid & 0xFFFF)) >> 4) as i32 }
+    /// Shiny value of the trainer IDs.
+    pub fn tsv(&self) -> i32 { ((self.tid ^ self.sid) >> 4) as i32 }
+    pub fn is_shiny(&self) -> bool { self.psv() == self.tsv() }
+
+    pub fn generation(&self) -> i32 { Self::get_generation(self.version, self.met_location) }
+    /// Whether the entity originates from this format and has never been
+    /// handled by another trainer.
+    pub fn is_untraded(&self) -> bool { self.ht_name.is_empty() && FORMAT == self.generation() }
+
+    /// Reads an entity from decrypted party sized data.
+    fn read_bytes(data: &[u8; SIZE_8PARTY]) -> Result<Self, DekuError> {
+        let (_rest, file) = PK9::read(data.view_bits::<Msb0>(), Endian::Little)?;
+        Ok(file)
+    }
+
+    /// Writes the entity into party sized data.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, DekuError> {
+        let mut output = BitVec::new();
+        self.write(&mut output, Endian::Little)?;
+        Ok(output.into_vec())
+    }
+}
+
+impl PKM for PK9 {
+    type RawVariant = RawPK9;
+
+    fn get_string(data: &[u16]) -> String { get_string7(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string7b(data.as_ref(), max_length, self.language, 0, 0, false)
+    }
+
+    fn build(&mut self) -> Result<RawPK9, PkmError> {
+        self.refresh_checksum();
+        Ok(RawPK9 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_8PARTY]> for PK9 {
+    fn from(data: &[u8; SIZE_8PARTY]) -> Self {
+        let mut array = *data;
+        decrypt_if_encrypted8(&mut array);
+        PK9::read_bytes(&array).unwrap()
+    }
+}
+
+impl TryFrom<&[u8]> for PK9 {
+    type Error = PkmError;
+
+    /// Parses stored or party sized data, encrypted or not, rejecting data
+    /// whose stored checksum does not match.
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let mut array = [0u8; SIZE_8PARTY];
+        match data.len() {
+            SIZE_8PARTY => {
+                array = to_array(data.to_vec())?;
+                decrypt_if_encrypted8(&mut array);
+            }
+            SIZE_8STORED => {
+                let mut stored: [u8; SIZE_8STORED] = to_array(data.to_vec())?;
+                decrypt_if_encrypted8(&mut stored);
+                array[..SIZE_8STORED].copy_from_slice(&stored);
+            }
+            actual => {
+                return Err(PkmError::InvalidLength {
+                    expected: vec![SIZE_8STORED, SIZE_8PARTY],
+                    actual,
+                })
+            }
+        }
+
+        let stored = u16::from_le_bytes([array[0x06], array[0x07]]);
+        let calculated = get_chk::<SIZE_8PARTY>(&array, SIZE_8STORED);
+        if stored != calculated {
+            return Err(PkmError::ChecksumMismatch { stored, calculated });
+        }
+        Ok(PK9::read_bytes(&array)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::pkm::util::{pokecrypto::encrypt_array8, testutil::write_string};
+
+    fn sprigatito() -> [u8; SIZE_8PARTY] {
+        let mut data = [0u8; SIZE_8PARTY];
+        data[0x00..0x04].copy_from_slice(&0x9999_0906u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&get_internal9(Species::Sprigatito).to_le_bytes());
+        data[0x0C..0x0E].copy_from_slice(&31415u16.to_le_bytes());
+        data[0x0E..0x10].copy_from_slice(&27182u16.to_le_bytes());
+        data[0x14..0x16].copy_from_slice(&(Ability::Overgrow as u16).to_le_bytes());
+        data[0x16] = 0x08 | 1; // Favourite, first ability
+        data[0x1C..0x20].copy_from_slice(&0x2468_ACE0u32.to_le_bytes());
+        data[0x20] = Nature::Timid as u8;
+        data[0x21] = Nature::Timid as u8;
+        data[0x52] = 128;
+        write_string(&mut data, 0x58, "Sprigatito".encode_utf16(), Endian::Little);
+        data[0x72..0x74].copy_from_slice(&(Move::FlowerTrick as u16).to_le_bytes());
+        data[0x8C..0x90].copy_from_slice(&0x3FFF_FFFFu32.to_le_bytes());
+        data[0x94] = MoveType::Grass as u8;
+        data[0x95] = TERA_OVERRIDE_NONE;
+        data[0xDE] = GameVersion::VL as u8;
+        data[0xE2] = LanguageID::English as u8;
+        write_string(&mut data, 0xF8, "Juliana".encode_utf16(), Endian::Little);
+        data[0x11F] = 20;
+        data[0x122..0x124].copy_from_slice(&8u16.to_le_bytes());
+        data[0x124] = Ball::Poke as u8;
+        data[0x125] = 5;
+        data[0x127] = 0b0000_0001; // TM001
+        data[0x13F] = 0b1000_0000; // TM200
+        data[0x148] = 5;
+        let chk = get_chk::<SIZE_8PARTY>(&data, SIZE_8STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn pk9_get_test() {
+        let sprigatito = PK9::from(&sprigatito());
+        assert_eq!(0x99990906, sprigatito.encryption_constant);
+        assert_eq!(906, sprigatito.species_id9);
+        assert_eq!(Species::Sprigatito, sprigatito.species);
+        assert_eq!(31415, sprigatito.tid);
+        assert_eq!(27182, sprigatito.sid);
+        assert_eq!(Ability::Overgrow, sprigatito.ability);
+        assert!(sprigatito.favourite);
+        assert_eq!(1, sprigatito.ability_number);
+        assert_eq!(Nature::Timid, sprigatito.nature);
+        assert_eq!(128, sprigatito.scale);
+        assert_eq!("Sprigatito", sprigatito.nickname);
+        assert_eq!(Move::FlowerTrick, sprigatito.move1);
+        assert_eq!(31, sprigatito.iv(Stat::SpD));
+        assert_eq!(MoveType::Grass, sprigatito.tera_type_original);
+        assert_eq!(GameVersion::VL, sprigatito.version);
+        assert_eq!(LanguageID::English, sprigatito.language);
+        assert_eq!("Juliana", sprigatito.ot_name);
+        assert_eq!(20, sprigatito.obedience_level);
+        assert_eq!(8, sprigatito.met_location);
+        assert_eq!(5, sprigatito.met_level);
+        assert_eq!(5, sprigatito.stat_level);
+        assert_eq!(9, sprigatito.generation());
+        assert!(sprigatito.is_sv_origin());
+        assert!(sprigatito.is_untraded());
+    }
+
+    #[test]
+    fn pk9_shiny_test() {
+        let mut sprigatito = PK9::from(&sprigatito());
+        assert_eq!((0x888, 0x109), (sprigatito.psv(), sprigatito.tsv()));
+        assert!(!sprigatito.is_shiny());
+
+        sprigatito.pid = 0x1099_0000;
+        assert!(sprigatito.is_shiny());
+        let rebuilt = PK9::from(&sprigatito.build().unwrap().to_bytes());
+        assert!(rebuilt.is_shiny());
+
+        sprigatito.sid ^= 0x10;
+        assert!(!sprigatito.is_shiny());
+    }
+
+    #[test]
+    fn pk9_try_from_test() {
+        let mut data = sprigatito();
+        let party = PK9::try_from(&data[..]).unwrap();
+        assert_eq!(PK9::from(&data), party);
+        assert_eq!(party.nickname, PK9::try_from(&data[..SIZE_8STORED]).unwrap().nickname);
+        assert_eq!(party, PK9::try_from(&encrypt_array8(&mut data)[..]).unwrap());
+
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_8STORED, SIZE_8PARTY],
+                actual: 0x100
+            }),
+            PK9::try_from(&data[..0x100])
+        );
+        data[0x08] ^= 1;
+        assert!(matches!(PK9::try_from(&data[..]), Err(PkmError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn pk9_species_test() {
+        let mut data = sprigatito();
+        data[0x08..0x0A].copy_from_slice(&917u16.to_le_bytes());
+        let chk = get_chk::<SIZE_8PARTY>(&data, SIZE_8STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+
+        let mut dudunsparce = PK9::from(&data);
+        assert_eq!(Species::Dudunsparce, dudunsparce.species);
+        assert_eq!(data, dudunsparce.build().unwrap().to_bytes());
+
+        dudunsparce.species = Species::Tarountula;
+        let tarountula = PK9::from(&dudunsparce.build().unwrap().to_bytes());
+        assert_eq!(918, tarountula.species_id9);
+        assert_eq!(Species::Tarountula, tarountula.species);
+    }
+
+    #[test]
+    fn pk9_enum_range_test() {
+        assert_eq!(1025, Species::Pecharunt as u16);
+        assert_eq!(919, Move::MalignantChain as u16);
+        assert_eq!(310, Ability::PoisonPuppeteer as u16);
+    }
+
+    #[test]
+    fn pk9_tera_type_test() {
+        let mut sprigatito = PK9::from(&sprigatito());
+        assert_eq!(MoveType::Grass, sprigatito.tera_type());
+
+        sprigatito.tera_type_override = MoveType::Fairy as u8;
+        let mut sprigatito = PK9::from(&sprigatito.build().unwrap().to_bytes());
+        assert_eq!(MoveType::Grass, sprigatito.tera_type_original);
+        assert_eq!(MoveType::Fairy, sprigatito.tera_type());
+
+        sprigatito.tera_type_override = MoveType::Stellar as u8;
+        assert_eq!(MoveType::Stellar, sprigatito.tera_type());
+    }
+
+    #[test]
+    fn pk9_move_record_test() {
+        let mut sprigatito = PK9::from(&sprigatito());
+        assert!(sprigatito.get_move_record_flag(0));
+        assert!(sprigatito.get_move_record_flag(199));
+        assert!(!sprigatito.get_move_record_flag(1));
+        assert!(!sprigatito.get_move_record_flag(COUNT_RECORD));
+
+        sprigatito.set_move_record_flag(0, false);
+        sprigatito.set_move_record_flag(170, true);
+        sprigatito.set_move_record_flag(COUNT_RECORD, true);
+        let sprigatito = PK9::from(&sprigatito.build().unwrap().to_bytes());
+        assert!(!sprigatito.get_move_record_flag(0));
+        assert!(sprigatito.get_move_record_flag(170));
+    }
+
+    #[test]
+    fn pk9_build_test() {
+        let mut pk9 = sprigatito();
+        let mut sprigatito = PK9::from(&pk9);
+        assert_eq!(pk9, sprigatito.build().unwrap().to_bytes());
+        assert_eq!(sprigatito, PK9::from(&encrypt_array8(&mut pk9)));
+    }
+
+    #[test]
+    fn pk9_round_trip_test() {
+        let mut data = sprigatito();
+        for offset in [
+            0x17, 0x1A, 0x23, 0x33, 0x3E, 0x4C, 0x53, 0x96, 0xA7, 0xC5, 0xCE, 0xE0, 0xE9, 0x115,
+            0x149, 0x156,
+        ] {
+            data[offset] = 0xA5;
+        }
+        data[0x16] |= 0xF0;
+        data[0x22] |= 0xF0;
+        data[0x126] |= 0b1100_0000;
+        data[0x34] |= 1;
+        data[0x44] |= 0b10;
+        let chk = get_chk::<SIZE_8PARTY>(&data, SIZE_8STORED);
+        data[0x06..0x08].copy_from_slice(&chk.to_le_bytes());
+
+        let mut sprigatito = PK9::from(&data);
+        assert_eq!(0xF, sprigatito.unk_16);
+        assert_eq!(0xA5, sprigatito.unk_96[0]);
+        assert_eq!(Flag::Set, sprigatito.ribbon_champion_kalos);
+        assert_eq!(Flag::Set, sprigatito.ribbon_mark_slump);
+        assert_eq!(data, sprigatito.build().unwrap().to_bytes());
+    }
+}
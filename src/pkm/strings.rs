@@ -2,5 +2,17 @@
 /// encoding formats.
 pub mod string_converter;
 
+/// Logic for converting a string to and from the Generation 1/2 character
+/// encodings.
+pub mod string_converter12;
+
+/// Logic for converting a string to and from the Generation 3 character
+/// encodings.
+pub mod string_converter3;
+
+/// Logic for converting a string to and from the Generation 4 character
+/// encoding.
+pub mod string_converter4;
+
 /// String related resources.
 pub(crate) mod resources;
@@ -1,2 +1,11 @@
+/// Gen 1/2 Character Tables
+pub(crate) mod char_12;
+
+/// Gen 3 Character Tables
+pub(crate) mod char_3;
+
+/// Gen 4 Character Tables
+pub(crate) mod char_4;
+
 /// Gen 7 Chinese Character Tables
 pub(crate) mod char_zh;
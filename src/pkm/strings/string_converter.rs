@@ -41,7 +41,7 @@ fn sanitize_glyph(c: char) -> char {
 
 fn unsanitize_glyph(c: char, generation: u32, full_width: bool) -> char {
     match c {
-        '\'' if generation >= 6 => '’',            // Farfetch'd
+        '\'' if generation >= 6 => '’',              // Farfetch'd
         '\u{2640}' if generation <= 5 => '\u{246E}', // ♀ (gen5)
         '\u{2642}' if generation <= 5 => '\u{246D}', // ♂ (gen5)
         '\u{2640}' if generation >= 6 && !full_width => '\u{E08F}', // ♀ (gen6+)
@@ -58,15 +58,21 @@ fn remap_chinese_glyphs(c: char) -> char {
 }
 
 fn convert_string_g7_zh<S: AsRef<str>>(input: S, language: LanguageID) -> String {
-    let mut input_chars = input.as_ref().chars();
+    let input = input.as_ref();
     // CHS and CHT have the same display name.
-    let is_traditional = input_chars.any(|c| G7_CHT.contains_key(&c) && !G7_CHS.contains_key(&c))
+    let is_traditional = input.chars().any(|c| G7_CHT.contains_key(&c) && !G7_CHS.contains_key(&c))
         || (language == LanguageID::ChineseT
-            && !input_chars.any(|c| G7_CHT.contains_key(&c) ^ G7_CHS.contains_key(&c)));
+            && !input.chars().any(|c| G7_CHT.contains_key(&c) ^ G7_CHS.contains_key(&c)));
     let table = [&G7_CHS, &G7_CHT][is_traditional as usize];
 
-    input_chars
-        .map(|c| table.get(&c).map_or(c, |&index| char::from(index as u8 + GEN7_ZH_OFS as u8)))
+    input
+        .chars()
+        .map(|c| {
+            table
+                .get(&c)
+                .and_then(|&index| char::from_u32(index as u32 + GEN7_ZH_OFS as u32))
+                .unwrap_or(c)
+        })
         .collect::<String>()
 }
 
@@ -76,11 +82,9 @@ fn is_g7chs_char(index: usize) -> bool {
             && index < (SM_ZH_CHARTABLE_SIZE as usize * 2) + USUM_CHS_SIZE as usize)
 }
 
-fn sanitize_string(data: &[u16]) -> String {
+fn sanitize_string(data: &[u16]) -> impl Iterator<Item = char> + '_ {
     decode_utf16(data.iter().take_while(|&&x| x != 0).copied())
         .map(|r| r.map_or(REPLACEMENT_CHARACTER, sanitize_glyph))
-        .map(remap_chinese_glyphs)
-        .collect::<String>()
 }
 
 fn unsanitize_string<S: AsRef<str>>(input: S, generation: u32) -> String {
@@ -93,7 +97,44 @@ fn unsanitize_string<S: AsRef<str>>(input: S, generation: u32) -> String {
     input.chars().map(|c| unsanitize_glyph(c, generation, full_width)).collect()
 }
 
-pub fn get_string7(data: &[u16]) -> String { sanitize_string(&data) }
+fn encode_string(input: &str, max_length: usize, pad_to: usize, pad_with: u16) -> Vec<u16> {
+    let mut result = input.encode_utf16().take(max_length).collect::<Vec<u16>>();
+    // Pad to max_length if necessary
+    result.extend([0].iter().cycle().take(max_length - result.len()));
+    // Null terminator
+    result.extend(iter::once(0));
+    // Pad remaining if requested.
+    let delta = pad_to.saturating_sub(max_length + 1);
+    result.extend([pad_with].iter().cycle().take(delta));
+    result
+}
+
+/// Gen 4/5 string terminator.
+const TERMINATOR_FFFF: u16 = 0xFFFF;
+
+pub fn get_string5(data: &[u16]) -> String {
+    decode_utf16(data.iter().take_while(|&&x| x != TERMINATOR_FFFF && x != 0).copied())
+        .map(|r| r.map_or(REPLACEMENT_CHARACTER, sanitize_glyph))
+        .collect()
+}
+
+pub fn set_string5(data: &str, max_length: usize) -> Vec<u16> {
+    let unsanitized = unsanitize_string(data, 5);
+    let mut result = unsanitized.encode_utf16().take(max_length).collect::<Vec<u16>>();
+    result.push(TERMINATOR_FFFF);
+    result.resize(max_length + 1, 0);
+    result
+}
+
+pub fn get_string6(data: &[u16]) -> String { sanitize_string(data).collect() }
+
+pub fn set_string6(data: &str, max_length: usize) -> Vec<u16> {
+    encode_string(&unsanitize_string(data, 6), max_length, 0, 0)
+}
+
+pub fn get_string7(data: &[u16]) -> String {
+    sanitize_string(data).map(remap_chinese_glyphs).collect()
+}
 
 pub fn set_string7b(
     data: &str,
@@ -108,15 +149,7 @@ pub fn set_string7b(
         unsanitized = convert_string_g7_zh(data, language);
     }
     unsanitized = unsanitize_string(unsanitized, 7);
-    let mut result = unsanitized.encode_utf16().take(max_length).collect::<Vec<u16>>();
-    // Pad to max_length if necessary
-    result.extend([0].iter().cycle().take(max_length - result.len()));
-    // Null terminator
-    result.extend(iter::once(0));
-    // Pad remaining if requested.
-    let delta = pad_to.saturating_sub(max_length + 1);
-    result.extend([pad_with].iter().cycle().take(delta));
-    result
+    encode_string(&unsanitized, max_length, pad_to, pad_with)
 }
 
 #[cfg(test)]
@@ -165,6 +198,23 @@ mod test {
         assert_eq!(set_string("多龍梅西亞"), [0x591A, 0x9F8D, 0x6885, 0x897F, 0x4E9E])
     }
 
+    #[test]
+    fn set_string7b_chinese_test() {
+        // Bulbasaur
+        let encoded = set_string7b("妙蛙种子", 12, LanguageID::ChineseS, 0, 0, true);
+        assert_eq!(encoded[..5], [0xE801, 0xE802, 0xE803, 0xE804, 0]);
+        assert_eq!("妙蛙种子", get_string7(&encoded));
+    }
+
+    #[test]
+    fn string5_test() {
+        let encoded = set_string5("Nidoran♀", 10);
+        assert_eq!(11, encoded.len());
+        assert_eq!(encoded[..9], [0x4e, 0x69, 0x64, 0x6f, 0x72, 0x61, 0x6e, 0x246e, 0xFFFF]);
+        assert_eq!("Nidoran♀", get_string5(&encoded));
+        assert_eq!("Zorua", get_string5(&[0x5A, 0x6F, 0x72, 0x75, 0x61, 0xFFFF, 0x41, 0x41]));
+    }
+
     #[test]
     fn unsanitize_string_test() {
         assert_eq!(unsanitize_string("Nidoran♀", 5), "Nidoran\u{246E}");
@@ -0,0 +1,85 @@
+use alloc::{string::String, vec::Vec};
+use conquer_once::spin::Lazy;
+use hashbrown::HashMap;
+
+use crate::pkm::strings::resources::char_12::*;
+
+static G12_EN_VALUES: Lazy<HashMap<u8, char>> = Lazy::new(|| G12_EN.iter().copied().collect());
+
+static G12_EN_CODES: Lazy<HashMap<char, u8>> =
+    Lazy::new(|| G12_EN.iter().map(|&(code, c)| (c, code)).collect());
+
+static G12_JP_VALUES: Lazy<HashMap<u8, char>> = Lazy::new(|| G12_JP.iter().copied().collect());
+
+static G12_JP_CODES: Lazy<HashMap<char, u8>> =
+    Lazy::new(|| G12_JP.iter().map(|&(code, c)| (c, code)).collect());
+
+fn unsanitize_glyph(c: char) -> char {
+    match c {
+        '’' => '\'',  // Farfetch'd
+        'ヘ' => 'へ', // Shared glyph
+        'リ' => 'り', // Shared glyph
+        _ => c,
+    }
+}
+
+/// Converts Generation 1/2 encoded characters to a string.
+///
+/// # Arguments
+///
+/// * `data` - Encoded character values, terminated by `0x50`.
+/// * `japanese` - Whether the Japanese character table is used.
+pub fn get_string1(data: &[u8], japanese: bool) -> String {
+    let table = if japanese { &*G12_JP_VALUES } else { &*G12_EN_VALUES };
+    data.iter().take_while(|&&x| x != G12_TERMINATOR).filter_map(|x| table.get(x)).collect()
+}
+
+/// Converts a string to Generation 1/2 encoded characters.
+///
+/// Characters without a Generation 1/2 glyph are dropped. The result is
+/// terminated and padded with `0x50` up to `pad_to` bytes.
+///
+/// # Arguments
+///
+/// * `data` - String to encode.
+/// * `max_length` - Maximum number of characters to encode.
+/// * `pad_to` - Total size of the encoded buffer.
+/// * `japanese` - Whether the Japanese character table is used.
+pub fn set_string1(data: &str, max_length: usize, pad_to: usize, japanese: bool) -> Vec<u8> {
+    let table = if japanese { &*G12_JP_CODES } else { &*G12_EN_CODES };
+    let mut result = data
+        .chars()
+        .map(unsanitize_glyph)
+        .filter_map(|c| table.get(&c).copied())
+        .take(max_length.min(pad_to.saturating_sub(1)))
+        .collect::<Vec<u8>>();
+    result.resize(pad_to, G12_TERMINATOR);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_string1_test() {
+        assert_eq!("RED", get_string1(&[0x91, 0x84, 0x83, 0x50, 0x50], false));
+        assert_eq!(
+            "Pikachu",
+            get_string1(&[0x8F, 0xA8, 0xAA, 0xA0, 0xA2, 0xA7, 0xB4, 0x50], false)
+        );
+        assert_eq!("ピカチュウ", get_string1(&[0x41, 0x85, 0x90, 0xAE, 0x82, 0x50], true));
+    }
+
+    #[test]
+    fn set_string1_test() {
+        let encoded = set_string1("Farfetch’d", 10, 11, false);
+        assert_eq!(11, encoded.len());
+        assert_eq!(0xE0, encoded[8]);
+        assert_eq!(G12_TERMINATOR, encoded[10]);
+        assert_eq!("Farfetch'd", get_string1(&encoded, false));
+        assert_eq!([0x91, 0x84, 0x83, 0x50, 0x50, 0x50], set_string1("RED", 7, 6, false)[..]);
+        assert_eq!([0x41, 0x85, 0x90, 0xAE, 0x82, 0x50], set_string1("ピカチュウ", 5, 6, true)[..]);
+        assert_eq!("へり", get_string1(&set_string1("ヘリ", 5, 6, true), true));
+    }
+}
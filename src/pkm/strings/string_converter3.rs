@@ -0,0 +1,137 @@
+use alloc::{string::String, vec::Vec};
+use conquer_once::spin::Lazy;
+use core::char::{decode_utf16, REPLACEMENT_CHARACTER};
+use hashbrown::HashMap;
+
+use crate::pkm::strings::resources::char_3::*;
+
+static G3_EN_VALUES: Lazy<HashMap<u8, char>> = Lazy::new(|| G3_EN.iter().copied().collect());
+
+static G3_EN_CODES: Lazy<HashMap<char, u8>> =
+    Lazy::new(|| G3_EN.iter().map(|&(code, c)| (c, code)).collect());
+
+static G3_JP_VALUES: Lazy<HashMap<u8, char>> = Lazy::new(|| G3_JP.iter().copied().collect());
+
+static G3_JP_CODES: Lazy<HashMap<char, u8>> =
+    Lazy::new(|| G3_JP.iter().map(|&(code, c)| (c, code)).collect());
+
+fn sanitize_glyph(c: char) -> char {
+    match c {
+        '’' => '\'', // Farfetch'd
+        _ => c,
+    }
+}
+
+fn unsanitize_glyph(c: char) -> char {
+    match c {
+        '\'' => '’', // Farfetch'd
+        _ => c,
+    }
+}
+
+/// Converts Generation 3 encoded characters to a string.
+///
+/// # Arguments
+///
+/// * `data` - Encoded character values, terminated by `0xFF`.
+/// * `japanese` - Whether the Japanese character table is used.
+pub fn get_string3(data: &[u8], japanese: bool) -> String {
+    let table = if japanese { &*G3_JP_VALUES } else { &*G3_EN_VALUES };
+    data.iter()
+        .take_while(|&&x| x != G3_TERMINATOR)
+        .filter_map(|x| table.get(x))
+        .map(|&c| sanitize_glyph(c))
+        .collect()
+}
+
+/// Converts a string to Generation 3 encoded characters.
+///
+/// Characters without a Generation 3 glyph are dropped. The result is
+/// terminated and padded with `0xFF` up to `pad_to` bytes.
+///
+/// # Arguments
+///
+/// * `data` - String to encode.
+/// * `max_length` - Maximum number of characters to encode.
+/// * `pad_to` - Total size of the encoded buffer.
+/// * `japanese` - Whether the Japanese character table is used.
+pub fn set_string3(data: &str, max_length: usize, pad_to: usize, japanese: bool) -> Vec<u8> {
+    let table = if japanese { &*G3_JP_CODES } else { &*G3_EN_CODES };
+    let mut result = data
+        .chars()
+        .map(unsanitize_glyph)
+        .filter_map(|c| table.get(&c).copied())
+        .take(max_length.min(pad_to))
+        .collect::<Vec<u8>>();
+    result.resize(pad_to, G3_TERMINATOR);
+    result
+}
+
+/// Converts Colosseum/XD big endian UTF-16 characters to a string.
+///
+/// # Arguments
+///
+/// * `data` - Character values, terminated by `0x0000`.
+pub fn get_string3_gc(data: &[u16]) -> String {
+    decode_utf16(data.iter().take_while(|&&x| x != 0).copied())
+        .map(|r| r.map_or(REPLACEMENT_CHARACTER, sanitize_glyph))
+        .collect()
+}
+
+/// Converts a string to Colosseum/XD UTF-16 characters, terminated and
+/// padded with `0x0000` up to `max_length + 1` characters.
+///
+/// # Arguments
+///
+/// * `data` - String to encode.
+/// * `max_length` - Maximum number of characters, excluding the terminator.
+pub fn set_string3_gc(data: &str, max_length: usize) -> Vec<u16> {
+    let mut result = data
+        .chars()
+        .map(unsanitize_glyph)
+        .collect::<String>()
+        .encode_utf16()
+        .take(max_length)
+        .collect::<Vec<u16>>();
+    result.resize(max_length + 1, 0);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_string3_test() {
+        assert_eq!(
+            "TORCHIC",
+            get_string3(&[0xCE, 0xC9, 0xCC, 0xBD, 0xC2, 0xC3, 0xBD, 0xFF], false)
+        );
+        assert_eq!("May", get_string3(&[0xC7, 0xD5, 0xED, 0xFF, 0xC7], false));
+        assert_eq!("アチャモ", get_string3(&[0x51, 0x61, 0x84, 0x73, 0xFF, 0xFF], true));
+    }
+
+    #[test]
+    fn set_string3_test() {
+        let encoded = set_string3("Farfetch'd", 10, 10, false);
+        assert_eq!(10, encoded.len());
+        assert_eq!(0xB4, encoded[8]);
+        assert_eq!("Farfetch'd", get_string3(&encoded, false));
+        assert_eq!([0xBB, 0xBC, 0xFF, 0xFF], set_string3("AB", 7, 4, false)[..]);
+        assert_eq!([0x51, 0x61, 0x84, 0x73, 0xFF, 0xFF], set_string3("アチャモ", 5, 6, true)[..]);
+        assert_eq!(
+            5,
+            set_string3("ミズゴロウだよ", 5, 10, true).iter().take_while(|&&c| c != 0xFF).count()
+        );
+    }
+
+    #[test]
+    fn string3_gc_test() {
+        let encoded = set_string3_gc("Farfetch'd", 10);
+        assert_eq!(11, encoded.len());
+        assert_eq!(0x2019, encoded[8]);
+        assert_eq!(0, encoded[10]);
+        assert_eq!("Farfetch'd", get_string3_gc(&encoded));
+        assert_eq!("ダークルギア", get_string3_gc(&set_string3_gc("ダークルギア", 10)));
+    }
+}
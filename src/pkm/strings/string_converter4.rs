@@ -0,0 +1,86 @@
+use alloc::{string::String, vec::Vec};
+use conquer_once::spin::Lazy;
+use hashbrown::HashMap;
+
+use crate::pkm::strings::resources::char_4::*;
+
+static G4_VALUES: Lazy<HashMap<u16, char>> = Lazy::new(|| G4_CHARS.iter().copied().collect());
+
+static G4_CODES: Lazy<HashMap<char, u16>> =
+    Lazy::new(|| G4_CHARS.iter().map(|&(code, c)| (c, code)).collect());
+
+fn sanitize_glyph(c: char) -> char {
+    match c {
+        '’' => '\'', // Farfetch'd
+        _ => c,
+    }
+}
+
+fn unsanitize_glyph(c: char) -> char {
+    match c {
+        '\'' => '’', // Farfetch'd
+        _ => c,
+    }
+}
+
+/// Converts Generation 4 encoded characters to a string.
+///
+/// # Arguments
+///
+/// * `data` - Encoded character values, terminated by `0xFFFF`.
+pub fn get_string4(data: &[u16]) -> String {
+    data.iter()
+        .take_while(|&&x| x != G4_TERMINATOR && x != 0)
+        .filter_map(|x| G4_VALUES.get(x))
+        .map(|&c| sanitize_glyph(c))
+        .collect()
+}
+
+/// Converts a string to Generation 4 encoded characters.
+///
+/// Characters without a Generation 4 glyph are dropped.
+///
+/// # Arguments
+///
+/// * `data` - String to encode.
+/// * `max_length` - Maximum number of characters, excluding the terminator.
+pub fn set_string4(data: &str, max_length: usize) -> Vec<u16> {
+    let mut result = data
+        .chars()
+        .map(unsanitize_glyph)
+        .filter_map(|c| G4_CODES.get(&c).copied())
+        .take(max_length)
+        .collect::<Vec<u16>>();
+    result.push(G4_TERMINATOR);
+    result.resize(max_length + 1, 0);
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get_string4_test() {
+        assert_eq!(
+            "Cyndaquil",
+            get_string4(&[0x12D, 0x15D, 0x152, 0x148, 0x145, 0x155, 0x159, 0x14D, 0x150, 0xFFFF])
+        );
+        assert_eq!(
+            "Nidoran♀",
+            get_string4(&[0x138, 0x14D, 0x148, 0x153, 0x156, 0x145, 0x152, 0x1BC, 0xFFFF, 0x12B])
+        );
+        assert_eq!("ヒノアラシ", get_string4(&[0x83, 0x7F, 0x53, 0x9A, 0x68, 0xFFFF]));
+    }
+
+    #[test]
+    fn set_string4_test() {
+        let encoded = set_string4("Farfetch'd", 10);
+        assert_eq!(11, encoded.len());
+        assert_eq!(0x1B3, encoded[8]);
+        assert_eq!(G4_TERMINATOR, encoded[10]);
+        assert_eq!("Farfetch'd", get_string4(&encoded));
+        assert_eq!([0x12B, 0x12C, G4_TERMINATOR, 0, 0], set_string4("AB", 4)[..]);
+        assert_eq!("ポッチャマ", get_string4(&set_string4("ポッチャマ", 10)));
+    }
+}
@@ -1,5 +1,15 @@
 /// A module that handles conversions from EXP to levels, and vice versa
 pub mod experience;
 
+/// Stat calculation from base stats, IVs, EVs, level and nature.
+pub mod stats;
+
 /// Logic related to Encrypting and Decrypting Pokémon entity data.
 pub mod pokecrypto;
+
+/// Logic for converting between internal and National Dex species indexes.
+pub mod species_converter;
+
+/// Helpers shared by the entity format tests.
+#[cfg(test)]
+pub(crate) mod testutil;
@@ -1,5 +1,8 @@
 use bitconv::{endian::Little, to_uint16, to_uint32};
 
+use crate::error::{check_entries, PkmError};
+use alloc::vec;
+
 pub const SIZE_1ULIST: usize = 69;
 pub const SIZE_1JLIST: usize = 59;
 pub const SIZE_1PARTY: usize = 44;
@@ -15,6 +18,7 @@ pub const SIZE_3XSTORED: usize = 196;
 pub const SIZE_3PARTY: usize = 100;
 pub const SIZE_3STORED: usize = 80;
 pub const SIZE_3BLOCK: usize = 12;
+pub const SIZE_3HEADER: usize = 32;
 
 pub const SIZE_4PARTY: usize = 236;
 pub const SIZE_4STORED: usize = 136;
@@ -34,6 +38,10 @@ pub const SIZE_8STORED: usize = 8 + (4 * SIZE_8BLOCK); // 0x148
 pub const SIZE_8PARTY: usize = SIZE_8STORED + 0x10; // 0x158
 pub const SIZE_8BLOCK: usize = 80; // 0x50
 
+pub const SIZE_8ASTORED: usize = 8 + (4 * SIZE_8ABLOCK); // 0x168
+pub const SIZE_8APARTY: usize = SIZE_8ASTORED + 0x10; // 0x178
+pub const SIZE_8ABLOCK: usize = 88; // 0x58
+
 /// Positions for shuffling.
 const BLOCK_POSITION: [u8; 128] = [
     0, 1, 2, 3, //
@@ -86,9 +94,18 @@ const BLOCK_POSITION_INVERT: [u8; 32] = [
 /// * `block_size` - Size of shuffling chunks
 #[inline]
 pub fn shuffle_array<const N: usize>(data: &[u8; N], sv: u32, block_size: usize) -> [u8; N] {
+    shuffle_blocks(data, sv, 8, block_size)
+}
+
+#[inline]
+fn shuffle_blocks<const N: usize>(
+    data: &[u8; N],
+    sv: u32,
+    start: usize,
+    block_size: usize,
+) -> [u8; N] {
     let mut sdata = *data;
     let index: u32 = sv * 4;
-    let start: usize = 8;
     for block in 0..4 {
         let ofs: u32 = BLOCK_POSITION[(index + block) as usize] as u32;
         let sdata_start = start + block_size * block as usize;
@@ -100,32 +117,245 @@ pub fn shuffle_array<const N: usize>(data: &[u8; N], sv: u32, block_size: usize)
     sdata
 }
 
+/// Shuffles the four blocks of a pkm byte array in place, swapping blocks
+/// instead of copying the whole array.
+#[inline]
+fn shuffle_in_place(data: &mut [u8], sv: u32, block_size: usize) {
+    let index = sv as usize * 4;
+    // Current position of each original block, and original block at each position.
+    let mut position = [0, 1, 2, 3];
+    let mut block_at = [0, 1, 2, 3];
+    for block in 0..4 {
+        let source = position[BLOCK_POSITION[index + block] as usize];
+        if source != block {
+            let (head, tail) = data[8..].split_at_mut(block_size * source);
+            head[block_size * block..][..block_size].swap_with_slice(&mut tail[..block_size]);
+            let moved = block_at[block];
+            block_at.swap(block, source);
+            position[moved] = source;
+            position[block_at[block]] = block;
+        }
+    }
+}
+
+/// Decrypts a pkm byte array made of four shuffled blocks of `block_size`
+/// bytes, keyed by the encryption constant.
+///
+/// # Arguments
+///
+/// * `ekm` - Encrypted Pokémon data, either stored or party sized.
+/// * `block_size` - Size of each shuffled block
+#[inline]
+fn decrypt_array_blocks<const N: usize>(ekm: &mut [u8; N], block_size: usize) -> [u8; N] {
+    let pv: u32 = to_uint32::<Little>(ekm, 0);
+    let sv = pv >> 13 & 31;
+    crypt_pkm(ekm, pv, block_size);
+    shuffle_array::<N>(ekm, sv, block_size)
+}
+
+/// Encrypts a pkm byte array made of four blocks of `block_size` bytes, keyed
+/// by the encryption constant.
+///
+/// # Arguments
+///
+/// * `pkm` - Decrypted Pokémon data, either stored or party sized.
+/// * `block_size` - Size of each shuffled block
+#[inline]
+fn encrypt_array_blocks<const N: usize>(pkm: &mut [u8; N], block_size: usize) -> [u8; N] {
+    let pv: u32 = to_uint32::<Little>(pkm, 0);
+    let sv = pv >> 13 & 31;
+    let mut ekm = shuffle_array::<N>(pkm, BLOCK_POSITION_INVERT[sv as usize] as u32, block_size);
+    crypt_pkm(&mut ekm, pv, block_size);
+    ekm
+}
+
 /// Decrypts a Gen8 pkm byte array.
 ///
 /// # Arguments
 ///
-/// * `ekm` - Encrypted Pokémon data
-pub fn decrypt_array8(ekm: &mut [u8; 344]) -> [u8; 344] {
+/// * `ekm` - Encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_array8<const N: usize>(ekm: &mut [u8; N]) -> [u8; N] {
+    decrypt_array_blocks(ekm, SIZE_8BLOCK)
+}
+
+/// Encrypts a Gen8 pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Decrypted Pokémon data, either stored or party sized.
+pub fn encrypt_array8<const N: usize>(pkm: &mut [u8; N]) -> [u8; N] {
+    encrypt_array_blocks(pkm, SIZE_8BLOCK)
+}
+
+/// Decrypts a Gen8 pkm byte array in place, without copying it.
+///
+/// # Arguments
+///
+/// * `ekm` - Encrypted Pokémon data, either stored or party sized.
+///
+/// # Errors
+///
+/// Returns [`PkmError::InvalidLength`] if `ekm` is not a Gen8 size.
+pub fn decrypt_in_place8(ekm: &mut [u8]) -> Result<(), PkmError> {
+    check_size8(ekm.len())?;
     let pv: u32 = to_uint32::<Little>(ekm, 0);
     let sv = pv >> 13 & 31;
     crypt_pkm(ekm, pv, SIZE_8BLOCK);
-    shuffle_array::<SIZE_8PARTY>(ekm, sv, SIZE_8BLOCK)
+    shuffle_in_place(ekm, sv, SIZE_8BLOCK);
+    Ok(())
 }
 
-/// Decrypts a Gen8 pkm byte array.
+/// Encrypts a Gen8 pkm byte array in place, without copying it.
 ///
 /// # Arguments
 ///
-/// * `pkm` - Decrypted Pokémon data
-pub fn encrypt_array8(pkm: &mut [u8; 344]) -> [u8; 344] {
+/// * `pkm` - Decrypted Pokémon data, either stored or party sized.
+///
+/// # Errors
+///
+/// Returns [`PkmError::InvalidLength`] if `pkm` is not a Gen8 size.
+pub fn encrypt_in_place8(pkm: &mut [u8]) -> Result<(), PkmError> {
+    check_size8(pkm.len())?;
     let pv: u32 = to_uint32::<Little>(pkm, 0);
     let sv = pv >> 13 & 31;
-    let mut ekm =
-        shuffle_array::<SIZE_8PARTY>(pkm, BLOCK_POSITION_INVERT[sv as usize] as u32, SIZE_8BLOCK);
-    crypt_pkm(&mut ekm, pv, SIZE_8BLOCK);
+    shuffle_in_place(pkm, BLOCK_POSITION_INVERT[sv as usize] as u32, SIZE_8BLOCK);
+    crypt_pkm(pkm, pv, SIZE_8BLOCK);
+    Ok(())
+}
+
+/// Decrypts every slot of a contiguous Gen8 box buffer in place.
+///
+/// # Arguments
+///
+/// * `data` - Encrypted box data, made of slots of `slot_size` bytes.
+/// * `slot_size` - Either [`SIZE_8STORED`] or [`SIZE_8PARTY`].
+///
+/// # Errors
+///
+/// Returns [`PkmError::InvalidLength`] without touching `data` if
+/// `slot_size` is not a Gen8 size or does not evenly divide `data`.
+pub fn decrypt_box8(data: &mut [u8], slot_size: usize) -> Result<(), PkmError> {
+    check_box8(data, slot_size)?;
+    data.chunks_exact_mut(slot_size).try_for_each(decrypt_in_place8)
+}
+
+/// Encrypts every slot of a contiguous Gen8 box buffer in place.
+///
+/// # Arguments
+///
+/// * `data` - Decrypted box data, made of slots of `slot_size` bytes.
+/// * `slot_size` - Either [`SIZE_8STORED`] or [`SIZE_8PARTY`].
+///
+/// # Errors
+///
+/// Returns [`PkmError::InvalidLength`] without touching `data` if
+/// `slot_size` is not a Gen8 size or does not evenly divide `data`.
+pub fn encrypt_box8(data: &mut [u8], slot_size: usize) -> Result<(), PkmError> {
+    check_box8(data, slot_size)?;
+    data.chunks_exact_mut(slot_size).try_for_each(encrypt_in_place8)
+}
+
+#[inline]
+fn check_size8(actual: usize) -> Result<(), PkmError> {
+    match actual {
+        SIZE_8STORED | SIZE_8PARTY => Ok(()),
+        _ => Err(PkmError::InvalidLength { expected: vec![SIZE_8STORED, SIZE_8PARTY], actual }),
+    }
+}
+
+#[inline]
+fn check_box8(data: &[u8], slot_size: usize) -> Result<(), PkmError> {
+    check_size8(slot_size)?;
+    check_entries(data.len(), slot_size)
+}
+
+/// Decrypts a Legends: Arceus pkm byte array.
+///
+/// # Arguments
+///
+/// * `ekm` - Encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_array8a<const N: usize>(ekm: &mut [u8; N]) -> [u8; N] {
+    decrypt_array_blocks(ekm, SIZE_8ABLOCK)
+}
+
+/// Encrypts a Legends: Arceus pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Decrypted Pokémon data, either stored or party sized.
+pub fn encrypt_array8a<const N: usize>(pkm: &mut [u8; N]) -> [u8; N] {
+    encrypt_array_blocks(pkm, SIZE_8ABLOCK)
+}
+
+/// Decrypts a Gen3 pkm byte array.
+///
+/// # Arguments
+///
+/// * `ekm` - Encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_array3<const N: usize>(ekm: &mut [u8; N]) -> [u8; N] {
+    let pv: u32 = to_uint32::<Little>(ekm, 0);
+    crypt_array3(ekm);
+    shuffle_blocks::<N>(ekm, pv % 24, SIZE_3HEADER, SIZE_3BLOCK)
+}
+
+/// Encrypts a Gen3 pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Decrypted Pokémon data, either stored or party sized.
+pub fn encrypt_array3<const N: usize>(pkm: &mut [u8; N]) -> [u8; N] {
+    let pv: u32 = to_uint32::<Little>(pkm, 0);
+    let sv = BLOCK_POSITION_INVERT[(pv % 24) as usize] as u32;
+    let mut ekm = shuffle_blocks::<N>(pkm, sv, SIZE_3HEADER, SIZE_3BLOCK);
+    crypt_array3(&mut ekm);
     ekm
 }
 
+/// Decrypts a Gen4/Gen5 pkm byte array.
+///
+/// # Arguments
+///
+/// * `ekm` - Encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_array45<const N: usize>(ekm: &mut [u8; N]) -> [u8; N] {
+    let pv: u32 = to_uint32::<Little>(ekm, 0);
+    let chk: u32 = to_uint16::<Little>(ekm, 6) as u32;
+    let sv = pv >> 13 & 31;
+    crypt_pkm45(ekm, pv, chk, SIZE_4BLOCK);
+    shuffle_array::<N>(ekm, sv, SIZE_4BLOCK)
+}
+
+/// Encrypts a Gen4/Gen5 pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Decrypted Pokémon data, either stored or party sized.
+pub fn encrypt_array45<const N: usize>(pkm: &mut [u8; N]) -> [u8; N] {
+    let pv: u32 = to_uint32::<Little>(pkm, 0);
+    let chk: u32 = to_uint16::<Little>(pkm, 6) as u32;
+    let sv = pv >> 13 & 31;
+    let mut ekm = shuffle_array::<N>(pkm, BLOCK_POSITION_INVERT[sv as usize] as u32, SIZE_4BLOCK);
+    crypt_pkm45(&mut ekm, pv, chk, SIZE_4BLOCK);
+    ekm
+}
+
+/// Decrypts a Gen6/Gen7 pkm byte array.
+///
+/// # Arguments
+///
+/// * `ekm` - Encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_array67<const N: usize>(ekm: &mut [u8; N]) -> [u8; N] {
+    decrypt_array_blocks(ekm, SIZE_6BLOCK)
+}
+
+/// Encrypts a Gen6/Gen7 pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Decrypted Pokémon data, either stored or party sized.
+pub fn encrypt_array67<const N: usize>(pkm: &mut [u8; N]) -> [u8; N] {
+    encrypt_array_blocks(pkm, SIZE_6BLOCK)
+}
+
 #[inline]
 fn crypt_pkm(data: &mut [u8], pv: u32, block_size: usize) {
     let start = 8;
@@ -137,23 +367,32 @@ fn crypt_pkm(data: &mut [u8], pv: u32, block_size: usize) {
 }
 
 #[inline]
-pub fn crypt_array(data: &mut [u8], mut seed: u32, start: usize, end: usize) {
-    let mut i = start;
-    while {
-        crypt(data, &mut seed, i);
-        i += 2;
-        crypt(data, &mut seed, i);
-        i += 2;
+fn crypt_array3(data: &mut [u8]) {
+    let seed = to_uint32::<Little>(data, 0) ^ to_uint32::<Little>(data, 4);
+    let end = SIZE_3HEADER + (4 * SIZE_3BLOCK);
+    for chunk in data[SIZE_3HEADER..end].chunks_mut(4) {
+        let value = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]) ^ seed;
+        chunk.copy_from_slice(&value.to_le_bytes());
+    }
+}
 
-        i < end
-    } {}
+#[inline]
+fn crypt_pkm45(data: &mut [u8], pv: u32, chk: u32, block_size: usize) {
+    let start = 8;
+    let end = (4 * block_size) + start;
+    crypt_array(data, chk, start, end); // Blocks
+    if data.len() > end {
+        crypt_array(data, pv, end, data.len()); // Party Stats
+    }
 }
 
 #[inline]
-fn crypt(data: &mut [u8], seed: &mut u32, i: usize) {
-    *seed = 0x41C64E6Du32.wrapping_mul(*seed) + 0x00006073;
-    data[i] ^= (*seed >> 16) as u8;
-    data[i + 1] ^= (*seed >> 24) as u8;
+pub fn crypt_array(data: &mut [u8], mut seed: u32, start: usize, end: usize) {
+    for pair in data[start..end].chunks_exact_mut(2) {
+        seed = 0x41C64E6Du32.wrapping_mul(seed).wrapping_add(0x00006073);
+        pair[0] ^= (seed >> 16) as u8;
+        pair[1] ^= (seed >> 24) as u8;
+    }
 }
 
 /// Gets the checksum of a 232 byte array.
@@ -167,20 +406,96 @@ pub fn get_chk<const N: usize>(data: &[u8; N], party_start: usize) -> u16 {
         .fold(0, |chk, x| u16::wrapping_add(chk, u16::from_le_bytes([x[0], x[1]])))
 }
 
-/// Decrypts a Gen8 pkm byte array.
+/// Gets the checksum of a Gen3 pkm byte array.
+///
+/// # Arguments
+///
+/// * `data` - Decrypted Pokémon data.
+pub fn get_chk3<const N: usize>(data: &[u8; N]) -> u16 {
+    data[SIZE_3HEADER..SIZE_3STORED]
+        .chunks(2)
+        .fold(0, |chk, x| u16::wrapping_add(chk, u16::from_le_bytes([x[0], x[1]])))
+}
+
+/// Decrypts a Gen3 pkm byte array if its stored checksum does not match the
+/// data.
 ///
 /// # Arguments
 ///
 /// * `pkm` - Possibly encrypted Pokémon data.
-pub fn decrypt_if_encrypted8(pkm: &mut [u8; 344]) {
+pub fn decrypt_if_encrypted3<const N: usize>(pkm: &mut [u8; N]) {
+    if get_chk3(pkm) != to_uint16::<Little>(pkm, 0x1C) {
+        *pkm = decrypt_array3(pkm);
+    }
+}
+
+/// Decrypts a Gen8 pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Possibly encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_if_encrypted8<const N: usize>(pkm: &mut [u8; N]) {
     if to_uint16::<Little>(pkm, 0x70) != 0 || to_uint16::<Little>(pkm, 0xC0) != 0 {
         *pkm = decrypt_array8(pkm);
     }
 }
 
+/// Decrypts a Legends: Arceus pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Possibly encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_if_encrypted8a<const N: usize>(pkm: &mut [u8; N]) {
+    if to_uint16::<Little>(pkm, 0x78) != 0 || to_uint16::<Little>(pkm, 0xD0) != 0 {
+        *pkm = decrypt_array8a(pkm);
+    }
+}
+
+/// Decrypts a Gen4/Gen5 pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Possibly encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_if_encrypted45<const N: usize>(pkm: &mut [u8; N]) {
+    if to_uint32::<Little>(pkm, 0x64) != 0 {
+        *pkm = decrypt_array45(pkm);
+    }
+}
+
+/// Decrypts a Gen6/Gen7 pkm byte array.
+///
+/// # Arguments
+///
+/// * `pkm` - Possibly encrypted Pokémon data, either stored or party sized.
+pub fn decrypt_if_encrypted67<const N: usize>(pkm: &mut [u8; N]) {
+    if to_uint16::<Little>(pkm, 0xC8) != 0 || to_uint16::<Little>(pkm, 0x58) != 0 {
+        *pkm = decrypt_array67(pkm);
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use core::convert::TryFrom;
+
+    /// Checks a decrypted and encrypted pair against the crypt functions of a
+    /// format, both party sized (`N`) and stored sized (`M`).
+    fn check_crypt_pair<const N: usize, const M: usize>(
+        pkm: &[u8; N],
+        ekm: &[u8; N],
+        decrypt: fn(&mut [u8; N]) -> [u8; N],
+        encrypt: fn(&mut [u8; N]) -> [u8; N],
+        decrypt_stored: fn(&mut [u8; M]) -> [u8; M],
+        encrypt_stored: fn(&mut [u8; M]) -> [u8; M],
+    ) {
+        assert_eq!(*ekm, encrypt(&mut { *pkm }));
+        assert_eq!(*pkm, decrypt(&mut { *ekm }));
+
+        let stored = <[u8; M]>::try_from(&pkm[..M]).unwrap();
+        let encrypted_stored = <[u8; M]>::try_from(&ekm[..M]).unwrap();
+        assert_eq!(encrypted_stored, encrypt_stored(&mut { stored }));
+        assert_eq!(stored, decrypt_stored(&mut { encrypted_stored }));
+    }
 
     #[test]
     fn get_chk8_test() {
@@ -197,6 +512,175 @@ mod test {
         assert_eq!(pk8, decrypt_array8(&mut ek8));
     }
 
+    #[test]
+    fn array8_stored_test() {
+        let pk8 = include_bytes!("tests/data/Orbeetle.pk8");
+        let mut stored = [0u8; SIZE_8STORED];
+        stored.copy_from_slice(&pk8[..SIZE_8STORED]);
+
+        let mut ek8 = encrypt_array8(&mut stored.clone());
+        assert_ne!(stored, ek8);
+        assert_eq!(stored, decrypt_array8(&mut ek8.clone()));
+        decrypt_if_encrypted8(&mut ek8);
+        assert_eq!(stored, ek8);
+    }
+
+    #[test]
+    fn array3_test() {
+        let pk3 = include_bytes!("tests/data/Sample.pk3");
+        let ek3 = include_bytes!("tests/data/Sample.ek3");
+        check_crypt_pair::<SIZE_3PARTY, SIZE_3STORED>(
+            pk3,
+            ek3,
+            decrypt_array3,
+            encrypt_array3,
+            decrypt_array3,
+            encrypt_array3,
+        );
+
+        let mut data = *ek3;
+        decrypt_if_encrypted3(&mut data);
+        assert_eq!(pk3, &data);
+        decrypt_if_encrypted3(&mut data);
+        assert_eq!(pk3, &data);
+    }
+
+    #[test]
+    fn array45_test() {
+        let pk4 = include_bytes!("tests/data/Sample.pk4");
+        let ek4 = include_bytes!("tests/data/Sample.ek4");
+        check_crypt_pair::<SIZE_4PARTY, SIZE_4STORED>(
+            pk4,
+            ek4,
+            decrypt_array45,
+            encrypt_array45,
+            decrypt_array45,
+            encrypt_array45,
+        );
+
+        let pk5 = include_bytes!("tests/data/Sample.pk5");
+        let ek5 = include_bytes!("tests/data/Sample.ek5");
+        check_crypt_pair::<SIZE_5PARTY, SIZE_5STORED>(
+            pk5,
+            ek5,
+            decrypt_array45,
+            encrypt_array45,
+            decrypt_array45,
+            encrypt_array45,
+        );
+
+        let mut data = *ek5;
+        decrypt_if_encrypted45(&mut data);
+        assert_eq!(pk5, &data);
+        decrypt_if_encrypted45(&mut data);
+        assert_eq!(pk5, &data);
+    }
+
+    #[test]
+    fn array67_test() {
+        for (pkm, ekm) in [
+            (include_bytes!("tests/data/Sample.pk6"), include_bytes!("tests/data/Sample.ek6")),
+            (include_bytes!("tests/data/Sample.pk7"), include_bytes!("tests/data/Sample.ek7")),
+        ] {
+            check_crypt_pair::<SIZE_6PARTY, SIZE_6STORED>(
+                pkm,
+                ekm,
+                decrypt_array67,
+                encrypt_array67,
+                decrypt_array67,
+                encrypt_array67,
+            );
+
+            let mut data = *ekm;
+            decrypt_if_encrypted67(&mut data);
+            assert_eq!(pkm, &data);
+            decrypt_if_encrypted67(&mut data);
+            assert_eq!(pkm, &data);
+        }
+    }
+
+    #[test]
+    fn array8a_test() {
+        let pa8 = include_bytes!("tests/data/Sample.pa8");
+        let ea8 = include_bytes!("tests/data/Sample.ea8");
+        check_crypt_pair::<SIZE_8APARTY, SIZE_8ASTORED>(
+            pa8,
+            ea8,
+            decrypt_array8a,
+            encrypt_array8a,
+            decrypt_array8a,
+            encrypt_array8a,
+        );
+
+        let mut data = *ea8;
+        decrypt_if_encrypted8a(&mut data);
+        assert_eq!(pa8, &data);
+        decrypt_if_encrypted8a(&mut data);
+        assert_eq!(pa8, &data);
+    }
+
+    #[test]
+    fn in_place8_test() {
+        let pk8 = include_bytes!("tests/data/Orbeetle.pk8");
+        let ek8 = include_bytes!("tests/data/Orbeetle.ek8");
+
+        let mut data = *ek8;
+        decrypt_in_place8(&mut data).unwrap();
+        assert_eq!(pk8, &data);
+        encrypt_in_place8(&mut data).unwrap();
+        assert_eq!(ek8, &data);
+
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![SIZE_8STORED, SIZE_8PARTY], actual: 3 }),
+            decrypt_in_place8(&mut data[..3])
+        );
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![SIZE_8STORED, SIZE_8PARTY], actual: 0 }),
+            encrypt_in_place8(&mut [])
+        );
+    }
+
+    #[test]
+    fn box8_test() {
+        let stored = &include_bytes!("tests/data/Orbeetle.pk8")[..SIZE_8STORED];
+        let mut decrypted = stored.repeat(30);
+        for (i, slot) in decrypted.chunks_exact_mut(SIZE_8STORED).enumerate() {
+            slot[0..4].copy_from_slice(&(i as u32 * 0x2000).to_le_bytes()); // every shuffle order
+        }
+
+        let mut data = decrypted.clone();
+        encrypt_box8(&mut data, SIZE_8STORED).unwrap();
+        for (slot, expected) in
+            data.chunks_exact(SIZE_8STORED).zip(decrypted.chunks_exact(SIZE_8STORED))
+        {
+            let mut array = [0u8; SIZE_8STORED];
+            array.copy_from_slice(expected);
+            assert_eq!(encrypt_array8(&mut array)[..], *slot);
+        }
+        decrypt_box8(&mut data, SIZE_8STORED).unwrap();
+        assert_eq!(decrypted, data);
+    }
+
+    #[test]
+    fn box8_size_test() {
+        let mut data = [0u8; SIZE_8STORED * 2 + 1];
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_8STORED, SIZE_8PARTY],
+                actual: SIZE_8STORED + 1
+            }),
+            decrypt_box8(&mut data, SIZE_8STORED + 1)
+        );
+        assert_eq!(
+            Err(PkmError::InvalidLength {
+                expected: vec![SIZE_8STORED * 2, SIZE_8STORED * 3],
+                actual: SIZE_8STORED * 2 + 1
+            }),
+            encrypt_box8(&mut data, SIZE_8STORED)
+        );
+        assert_eq!([0u8; SIZE_8STORED * 2 + 1], data);
+    }
+
     #[test]
     fn decrypt_if_encrypted_test() {
         let pk8 = include_bytes!("tests/data/Orbeetle.pk8").clone();
@@ -0,0 +1,501 @@
+use crate::game::enums::species::Species;
+
+/// National species for each internal Generation 1 species index, starting at
+/// 1. Unused indexes map to [`Species::None`].
+const G1_SPECIES: [Species; 190] = [
+    Species::Rhydon,
+    Species::Kangaskhan,
+    Species::NidoranM,
+    Species::Clefairy,
+    Species::Spearow,
+    Species::Voltorb,
+    Species::Nidoking,
+    Species::Slowbro,
+    Species::Ivysaur,
+    Species::Exeggutor,
+    Species::Lickitung,
+    Species::Exeggcute,
+    Species::Grimer,
+    Species::Gengar,
+    Species::NidoranF,
+    Species::Nidoqueen,
+    Species::Cubone,
+    Species::Rhyhorn,
+    Species::Lapras,
+    Species::Arcanine,
+    Species::Mew,
+    Species::Gyarados,
+    Species::Shellder,
+    Species::Tentacool,
+    Species::Gastly,
+    Species::Scyther,
+    Species::Staryu,
+    Species::Blastoise,
+    Species::Pinsir,
+    Species::Tangela,
+    Species::None,
+    Species::None,
+    Species::Growlithe,
+    Species::Onix,
+    Species::Fearow,
+    Species::Pidgey,
+    Species::Slowpoke,
+    Species::Kadabra,
+    Species::Graveler,
+    Species::Chansey,
+    Species::Machoke,
+    Species::MrMime,
+    Species::Hitmonlee,
+    Species::Hitmonchan,
+    Species::Arbok,
+    Species::Parasect,
+    Species::Psyduck,
+    Species::Drowzee,
+    Species::Golem,
+    Species::None,
+    Species::Magmar,
+    Species::None,
+    Species::Electabuzz,
+    Species::Magneton,
+    Species::Koffing,
+    Species::None,
+    Species::Mankey,
+    Species::Seel,
+    Species::Diglett,
+    Species::Tauros,
+    Species::None,
+    Species::None,
+    Species::None,
+    Species::Farfetchd,
+    Species::Venonat,
+    Species::Dragonite,
+    Species::None,
+    Species::None,
+    Species::None,
+    Species::Doduo,
+    Species::Poliwag,
+    Species::Jynx,
+    Species::Moltres,
+    Species::Articuno,
+    Species::Zapdos,
+    Species::Ditto,
+    Species::Meowth,
+    Species::Krabby,
+    Species::None,
+    Species::None,
+    Species::None,
+    Species::Vulpix,
+    Species::Ninetales,
+    Species::Pikachu,
+    Species::Raichu,
+    Species::None,
+    Species::None,
+    Species::Dratini,
+    Species::Dragonair,
+    Species::Kabuto,
+    Species::Kabutops,
+    Species::Horsea,
+    Species::Seadra,
+    Species::None,
+    Species::None,
+    Species::Sandshrew,
+    Species::Sandslash,
+    Species::Omanyte,
+    Species::Omastar,
+    Species::Jigglypuff,
+    Species::Wigglytuff,
+    Species::Eevee,
+    Species::Flareon,
+    Species::Jolteon,
+    Species::Vaporeon,
+    Species::Machop,
+    Species::Zubat,
+    Species::Ekans,
+    Species::Paras,
+    Species::Poliwhirl,
+    Species::Poliwrath,
+    Species::Weedle,
+    Species::Kakuna,
+    Species::Beedrill,
+    Species::None,
+    Species::Dodrio,
+    Species::Primeape,
+    Species::Dugtrio,
+    Species::Venomoth,
+    Species::Dewgong,
+    Species::None,
+    Species::None,
+    Species::Caterpie,
+    Species::Metapod,
+    Species::Butterfree,
+    Species::Machamp,
+    Species::None,
+    Species::Golduck,
+    Species::Hypno,
+    Species::Golbat,
+    Species::Mewtwo,
+    Species::Snorlax,
+    Species::Magikarp,
+    Species::None,
+    Species::None,
+    Species::Muk,
+    Species::None,
+    Species::Kingler,
+    Species::Cloyster,
+    Species::None,
+    Species::Electrode,
+    Species::Clefable,
+    Species::Weezing,
+    Species::Persian,
+    Species::Marowak,
+    Species::None,
+    Species::Haunter,
+    Species::Abra,
+    Species::Alakazam,
+    Species::Pidgeotto,
+    Species::Pidgeot,
+    Species::Starmie,
+    Species::Bulbasaur,
+    Species::Venusaur,
+    Species::Tentacruel,
+    Species::None,
+    Species::Goldeen,
+    Species::Seaking,
+    Species::None,
+    Species::None,
+    Species::None,
+    Species::None,
+    Species::Ponyta,
+    Species::Rapidash,
+    Species::Rattata,
+    Species::Raticate,
+    Species::Nidorino,
+    Species::Nidorina,
+    Species::Geodude,
+    Species::Porygon,
+    Species::Aerodactyl,
+    Species::None,
+    Species::Magnemite,
+    Species::None,
+    Species::None,
+    Species::Charmander,
+    Species::Squirtle,
+    Species::Charmeleon,
+    Species::Wartortle,
+    Species::Charizard,
+    Species::None,
+    Species::None,
+    Species::None,
+    Species::None,
+    Species::Oddish,
+    Species::Gloom,
+    Species::Vileplume,
+    Species::Bellsprout,
+    Species::Weepinbell,
+    Species::Victreebel,
+];
+
+/// First internal Generation 3 species index used by a Hoenn species.
+const G3_HOENN_START: u16 = 277;
+
+/// National species for each internal Generation 3 species index, starting at
+/// [`G3_HOENN_START`]. Indexes 252-276 are unused placeholders.
+const G3_HOENN_SPECIES: [Species; 135] = [
+    Species::Treecko,
+    Species::Grovyle,
+    Species::Sceptile,
+    Species::Torchic,
+    Species::Combusken,
+    Species::Blaziken,
+    Species::Mudkip,
+    Species::Marshtomp,
+    Species::Swampert,
+    Species::Poochyena,
+    Species::Mightyena,
+    Species::Zigzagoon,
+    Species::Linoone,
+    Species::Wurmple,
+    Species::Silcoon,
+    Species::Beautifly,
+    Species::Cascoon,
+    Species::Dustox,
+    Species::Lotad,
+    Species::Lombre,
+    Species::Ludicolo,
+    Species::Seedot,
+    Species::Nuzleaf,
+    Species::Shiftry,
+    Species::Nincada,
+    Species::Ninjask,
+    Species::Shedinja,
+    Species::Taillow,
+    Species::Swellow,
+    Species::Shroomish,
+    Species::Breloom,
+    Species::Spinda,
+    Species::Wingull,
+    Species::Pelipper,
+    Species::Surskit,
+    Species::Masquerain,
+    Species::Wailmer,
+    Species::Wailord,
+    Species::Skitty,
+    Species::Delcatty,
+    Species::Kecleon,
+    Species::Baltoy,
+    Species::Claydol,
+    Species::Nosepass,
+    Species::Torkoal,
+    Species::Sableye,
+    Species::Barboach,
+    Species::Whiscash,
+    Species::Luvdisc,
+    Species::Corphish,
+    Species::Crawdaunt,
+    Species::Feebas,
+    Species::Milotic,
+    Species::Carvanha,
+    Species::Sharpedo,
+    Species::Trapinch,
+    Species::Vibrava,
+    Species::Flygon,
+    Species::Makuhita,
+    Species::Hariyama,
+    Species::Electrike,
+    Species::Manectric,
+    Species::Numel,
+    Species::Camerupt,
+    Species::Spheal,
+    Species::Sealeo,
+    Species::Walrein,
+    Species::Cacnea,
+    Species::Cacturne,
+    Species::Snorunt,
+    Species::Glalie,
+    Species::Lunatone,
+    Species::Solrock,
+    Species::Azurill,
+    Species::Spoink,
+    Species::Grumpig,
+    Species::Plusle,
+    Species::Minun,
+    Species::Mawile,
+    Species::Meditite,
+    Species::Medicham,
+    Species::Swablu,
+    Species::Altaria,
+    Species::Wynaut,
+    Species::Duskull,
+    Species::Dusclops,
+    Species::Roselia,
+    Species::Slakoth,
+    Species::Vigoroth,
+    Species::Slaking,
+    Species::Gulpin,
+    Species::Swalot,
+    Species::Tropius,
+    Species::Whismur,
+    Species::Loudred,
+    Species::Exploud,
+    Species::Clamperl,
+    Species::Huntail,
+    Species::Gorebyss,
+    Species::Absol,
+    Species::Shuppet,
+    Species::Banette,
+    Species::Seviper,
+    Species::Zangoose,
+    Species::Relicanth,
+    Species::Aron,
+    Species::Lairon,
+    Species::Aggron,
+    Species::Castform,
+    Species::Volbeat,
+    Species::Illumise,
+    Species::Lileep,
+    Species::Cradily,
+    Species::Anorith,
+    Species::Armaldo,
+    Species::Ralts,
+    Species::Kirlia,
+    Species::Gardevoir,
+    Species::Bagon,
+    Species::Shelgon,
+    Species::Salamence,
+    Species::Beldum,
+    Species::Metang,
+    Species::Metagross,
+    Species::Regirock,
+    Species::Regice,
+    Species::Registeel,
+    Species::Kyogre,
+    Species::Groudon,
+    Species::Rayquaza,
+    Species::Latias,
+    Species::Latios,
+    Species::Jirachi,
+    Species::Deoxys,
+    Species::Chimecho,
+];
+
+/// First Generation 9 species index where the internal and National Dex
+/// indexes differ. Indexes past the end of the tables are aligned again.
+const G9_UNALIGNED_START: u16 = 917;
+
+/// Offset to add to an internal Generation 9 species index, starting at
+/// [`G9_UNALIGNED_START`], to get its National Dex species.
+const G9_INTERNAL_TO_NATIONAL: [i8; 94] = [
+    65, -1, -1, -1, -1, 31, 31, 47, 47, 29, 29, 53, 31, 31, 46, 44, 30, 30, -7, -7, -7, 13, 13, -2,
+    -2, 23, 23, 24, -21, -21, 27, 27, 47, 47, 47, 26, 14, -33, -33, -33, -17, -17, 3, -29, 12, -12,
+    -31, -31, -31, 3, 3, -24, -24, -44, -44, -30, -30, -28, -28, 23, 23, 6, 7, 29, 8, 3, 4, 4, 20,
+    4, 23, 6, 3, 3, 4, -1, 13, 9, 7, 5, 7, 9, 9, -43, -43, -43, -68, -68, -68, -58, -58, -25, -29,
+    -31,
+];
+
+/// Offset to add to a National Dex species, starting at
+/// [`G9_UNALIGNED_START`], to get its internal Generation 9 species index.
+const G9_NATIONAL_TO_INTERNAL: [i8; 94] = [
+    1, 1, 1, 1, 33, 33, 33, 21, 21, 44, 44, 7, 7, 7, 29, 31, 31, 31, 68, 68, 68, 2, 2, 17, 17, 30,
+    30, 24, 24, 28, 28, 58, 58, 12, -13, -13, -31, -31, -29, -29, 43, 43, 43, -31, -31, -3, -30,
+    -30, -23, -23, -14, -24, -3, -3, -47, -47, -12, -27, -27, -44, -46, -26, 31, 29, -53, -65, 25,
+    -6, -3, -7, -4, -4, -8, -4, 1, -3, -3, -6, -4, -47, -47, -47, -23, -23, -5, -7, -9, -7, -20,
+    -13, -9, -9, -29, -23,
+];
+
+/// Converts an internal Generation 1 species index to its National Dex species.
+///
+/// # Arguments
+///
+/// * `raw` - Species index as stored in Generation 1 entity data.
+pub fn get_g1_species(raw: u8) -> Species {
+    match raw {
+        0 => Species::None,
+        _ => G1_SPECIES.get(raw as usize - 1).copied().unwrap_or_default(),
+    }
+}
+
+/// Converts a National Dex species to its internal Generation 1 species index.
+///
+/// # Arguments
+///
+/// * `species` - Species to convert. Species introduced after Generation 1 map
+///   to 0.
+pub fn set_g1_species(species: Species) -> u8 {
+    match species {
+        Species::None => 0,
+        _ => G1_SPECIES.iter().position(|&s| s == species).map_or(0, |i| i as u8 + 1),
+    }
+}
+
+/// Converts an internal Generation 3 species index to its National Dex species.
+///
+/// # Arguments
+///
+/// * `raw` - Species index as stored in Generation 3 entity data.
+pub fn get_g4_species(raw: u16) -> Species {
+    match raw {
+        0..=251 => Species::n(raw).unwrap_or_default(),
+        _ if raw >= G3_HOENN_START => {
+            G3_HOENN_SPECIES.get((raw - G3_HOENN_START) as usize).copied().unwrap_or_default()
+        }
+        _ => Species::None,
+    }
+}
+
+/// Converts a National Dex species to its internal Generation 3 species index.
+///
+/// # Arguments
+///
+/// * `species` - Species to convert. Species introduced after Generation 3 map
+///   to 0.
+pub fn get_g3_species(species: Species) -> u16 {
+    match species as u16 {
+        raw @ 0..=251 => raw,
+        _ => G3_HOENN_SPECIES
+            .iter()
+            .position(|&s| s == species)
+            .map_or(0, |i| i as u16 + G3_HOENN_START),
+    }
+}
+
+/// Converts an internal Generation 9 species index to its National Dex species.
+///
+/// # Arguments
+///
+/// * `raw` - Species index as stored in Generation 9 entity data.
+pub fn get_national9(raw: u16) -> Species {
+    Species::n(shift_g9_species(raw, &G9_INTERNAL_TO_NATIONAL)).unwrap_or_default()
+}
+
+/// Converts a National Dex species to its internal Generation 9 species index.
+///
+/// # Arguments
+///
+/// * `species` - Species to convert.
+pub fn get_internal9(species: Species) -> u16 {
+    shift_g9_species(species as u16, &G9_NATIONAL_TO_INTERNAL)
+}
+
+fn shift_g9_species(value: u16, table: &[i8]) -> u16 {
+    match value.checked_sub(G9_UNALIGNED_START).and_then(|i| table.get(i as usize)) {
+        Some(&shift) => (value as i32 + shift as i32) as u16,
+        None => value,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn g1_species_test() {
+        assert_eq!(Species::Rhydon, get_g1_species(1));
+        assert_eq!(Species::Bulbasaur, get_g1_species(153));
+        assert_eq!(Species::Victreebel, get_g1_species(190));
+        assert_eq!(Species::None, get_g1_species(31));
+        assert_eq!(Species::None, get_g1_species(191));
+
+        assert_eq!(153, set_g1_species(Species::Bulbasaur));
+        assert_eq!(0, set_g1_species(Species::Chikorita));
+        for species in 1..=151 {
+            let species = Species::n(species).unwrap();
+            assert_eq!(species, get_g1_species(set_g1_species(species)));
+        }
+    }
+
+    #[test]
+    fn g3_species_test() {
+        assert_eq!(Species::Bulbasaur, get_g4_species(1));
+        assert_eq!(Species::Celebi, get_g4_species(251));
+        assert_eq!(Species::None, get_g4_species(260));
+        assert_eq!(Species::Treecko, get_g4_species(277));
+        assert_eq!(Species::Chimecho, get_g4_species(411));
+        assert_eq!(Species::None, get_g4_species(412));
+
+        assert_eq!(277, get_g3_species(Species::Treecko));
+        assert_eq!(411, get_g3_species(Species::Chimecho));
+        assert_eq!(0, get_g3_species(Species::Turtwig));
+        for raw in (1..=251).chain(277..=411) {
+            assert_eq!(raw, get_g3_species(get_g4_species(raw)));
+        }
+    }
+
+    #[test]
+    fn g9_species_test() {
+        assert_eq!(Species::Sprigatito, get_national9(906));
+        assert_eq!(Species::Dudunsparce, get_national9(917));
+        assert_eq!(Species::Tarountula, get_national9(918));
+        assert_eq!(Species::Smoliv, get_national9(935));
+        assert_eq!(Species::Arboliva, get_national9(937));
+        assert_eq!(Species::Dipplin, get_national9(1011));
+        assert_eq!(Species::None, get_national9(0xFFFF));
+
+        assert_eq!(906, get_internal9(Species::Sprigatito));
+        assert_eq!(917, get_internal9(Species::Dudunsparce));
+        assert_eq!(935, get_internal9(Species::Smoliv));
+        assert_eq!(1011, get_internal9(Species::Dipplin));
+        for raw in 1..=1025 {
+            assert_eq!(raw, get_internal9(get_national9(raw)));
+        }
+    }
+}
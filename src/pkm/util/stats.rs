@@ -0,0 +1,122 @@
+use crate::game::enums::{nature::Nature, stat::Stat};
+
+/// Gets the HP stat of an entity.
+///
+/// # Arguments
+///
+/// * `base` - Base HP of the species
+/// * `iv` - HP IV, or 31 if hyper trained
+/// * `ev` - HP EVs
+/// * `level` - Current level
+///
+/// # Example
+///
+/// ```
+/// use pkhexcore::pkm::util::stats::get_hp;
+///
+/// assert_eq!(322, get_hp(90, 31, 4, 100));
+/// ```
+pub fn get_hp(base: u8, iv: u8, ev: u8, level: u8) -> u16 {
+    get_raw_stat(base, iv, ev, level) + level as u16 + 10
+}
+
+/// Gets a stat other than HP of an entity.
+///
+/// # Arguments
+///
+/// * `base` - Base stat of the species
+/// * `iv` - IV of the stat, or 31 if hyper trained
+/// * `ev` - EVs of the stat
+/// * `level` - Current level
+/// * `amp` - Nature amplification of the stat, as given by [`Nature::stat_amp`]
+///
+/// # Example
+///
+/// ```
+/// use pkhexcore::pkm::util::stats::get_stat;
+///
+/// assert_eq!(279, get_stat(90, 31, 252, 100, 0));
+/// assert_eq!(184, get_stat(70, 23, 0, 100, 1));
+/// ```
+pub fn get_stat(base: u8, iv: u8, ev: u8, level: u8, amp: i8) -> u16 {
+    let stat = get_raw_stat(base, iv, ev, level) + 5;
+    stat * (10 + amp as i16) as u16 / 10
+}
+
+/// Gets all six stats of an entity, ordered HP, Atk, Def, Spe, SpA, SpD.
+///
+/// # Arguments
+///
+/// * `base_stats` - Base stats of the species
+/// * `ivs` - IVs, with hyper trained stats already raised to 31
+/// * `evs` - EVs
+/// * `level` - Current level
+/// * `nature` - Nature used for stat computation
+///
+/// # Example
+///
+/// ```
+/// use pkhexcore::{game::enums::nature::Nature, pkm::util::stats::get_stats};
+///
+/// let stats = get_stats(
+///     [90, 90, 100, 75, 70, 80],
+///     [31, 31, 31, 4, 23, 2],
+///     [4, 252, 0, 252, 0, 0],
+///     100,
+///     Nature::Mild,
+/// );
+/// assert_eq!([322, 279, 212, 222, 184, 167], stats);
+/// ```
+pub fn get_stats(
+    base_stats: [u8; 6],
+    ivs: [u8; 6],
+    evs: [u8; 6],
+    level: u8,
+    nature: Nature,
+) -> [u16; 6] {
+    let mut stats = [get_hp(base_stats[0], ivs[0], evs[0], level); 6];
+    for stat in 1..6 {
+        let amp = nature.stat_amp(Stat::n(stat as u8).unwrap());
+        stats[stat] = get_stat(base_stats[stat], ivs[stat], evs[stat], level, amp);
+    }
+    stats
+}
+
+/// Computed in `u32` as the product overflows `u16` for high base stats past
+/// level 100, while the result never exceeds 1540.
+fn get_raw_stat(base: u8, iv: u8, ev: u8, level: u8) -> u16 {
+    ((2 * base as u32 + iv as u32 + ev as u32 / 4) * level as u32 / 100) as u16
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_get_stats() {
+        let orbeetle = get_stats(
+            [60, 45, 110, 90, 80, 120],
+            [31, 31, 31, 21, 31, 31],
+            [0; 6],
+            60,
+            Nature::Sassy,
+        );
+        assert_eq!([160, 77, 155, 112, 119, 183], orbeetle);
+
+        let grookey = get_stats(
+            [50, 65, 50, 65, 40, 40],
+            [15, 4, 1, 26, 31, 3],
+            [2, 0, 1, 1, 0, 1],
+            7,
+            Nature::Lax,
+        );
+        assert_eq!([25, 14, 13, 15, 12, 9], grookey);
+    }
+
+    #[test]
+    fn test_get_stats_past_level_100() {
+        assert_eq!(1805, get_hp(255, 31, 252, 255));
+        assert_eq!(1699, get_stat(255, 31, 252, 255, 1));
+        assert_eq!(777, get_hp(255, 31, 252, 109));
+    }
+}
@@ -0,0 +1,18 @@
+use deku::ctx::Endian;
+
+/// Writes encoded string characters into an entity buffer, stopping at the
+/// first terminator.
+pub(crate) fn write_string<I: IntoIterator<Item = u16>>(
+    data: &mut [u8],
+    offset: usize,
+    chars: I,
+    endian: Endian,
+) {
+    for (i, c) in chars.into_iter().take_while(|&c| c != 0).enumerate() {
+        let bytes = match endian {
+            Endian::Little => c.to_le_bytes(),
+            Endian::Big => c.to_be_bytes(),
+        };
+        data[offset + (i * 2)..][..2].copy_from_slice(&bytes);
+    }
+}
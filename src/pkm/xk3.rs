@@ -0,0 +1,314 @@
+#![allow(non_snake_case)]
+use crate::pkm::PKM;
+use alloc::{format, string::String, vec::Vec};
+use deku::prelude::*;
+
+use crate::{
+    error::{to_array, PkmError}, game::enums::{
+        ball::Ball, game_version::GameVersion, gender::Gender, language_id::LanguageID, moves::Move, nature::Nature, species::Species
+    }, pkm::{
+        ck3::PURIFIED, strings::string_converter3::{get_string3_gc, set_string3_gc}, util::{
+            pokecrypto::SIZE_3XSTORED, species_converter::{get_g3_species, get_g4_species}
+        }
+    }, util::custom_read_write::{read, write}
+};
+
+pub use crate::pkm::ck3::{FORMAT, MAX_EV, MAX_IV, NICK_LENGTH, OT_LENGTH};
+
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct RawXK3 {
+    data: [u8; SIZE_3XSTORED],
+}
+
+impl RawXK3 {
+    pub fn to_bytes(&self) -> [u8; SIZE_3XSTORED] { self.data }
+}
+
+/// Generation 3 entity as stored by Pokémon XD: Gale of Darkness.
+#[derive(Debug, Default, Clone, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "big")]
+#[repr(C)]
+pub struct XK3 {
+    #[deku(update = "get_g3_species(self.species)")]
+    pub species_id3: u16,
+    #[deku(skip, default = "get_g4_species(*species_id3)")]
+    pub species: Species,
+    /// Item index in the Generation 3 item list.
+    pub held_item: u16,
+    pub stat_hp_current: u16,
+    pub ot_friendship: u16,
+    pub met_location: u16,
+    pub unk_0a: [u8; 4],
+    pub met_level: u8,
+    pub ball: Ball,
+    pub ot_gender: Gender,
+    pub stat_level: u8,
+    pub cnt_sheen: u8,
+    pub pkrs_strain: u8,
+    pub mark_value: u8,
+    pub pkrs_days: i8,
+    pub unk_16: [u8; 7],
+    #[deku(bits = 1)]
+    pub is_egg: bool,
+    #[deku(bits = 1)]
+    pub ability_bit: bool,
+    #[deku(bits = 1)]
+    pub invalid: bool,
+    #[deku(bits = 4)]
+    pub unk_1d: u8,
+    /// Obedience flag for event-only species.
+    #[deku(bits = 1)]
+    pub fateful_encounter: bool,
+    pub unk_1e: u16,
+    pub exp: u32,
+    pub sid: u16,
+    pub tid: u16,
+    pub pid: u32,
+    pub unk_2c: [u8; 8],
+    #[deku(
+        reader = "read::read_gc_version(deku::rest)",
+        writer = "write::write_gc_version(deku::output, self.version)"
+    )]
+    pub version: GameVersion,
+    pub current_region: u8,
+    pub original_region: u8,
+    #[deku(
+        reader = "read::read_language_gc(deku::rest)",
+        writer = "write::write_language_gc(deku::output, self.language)"
+    )]
+    pub language: LanguageID,
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, OT_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.ot_name, OT_LENGTH))"
+    )]
+    pub ot_name: String,
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.nickname, NICK_LENGTH))"
+    )]
+    pub nickname: String,
+    #[deku(
+        reader = "read::read_string_custom_endian(deku::rest, deku::ctx::Endian::Big, NICK_LENGTH + 1, Self::get_string)",
+        writer = "write::write_string_custom_endian(deku::output, deku::ctx::Endian::Big, self.set_string(&self.nickname_copy, NICK_LENGTH))"
+    )]
+    pub nickname_copy: String,
+    pub unk_7a: u16,
+    #[deku(bits = 1)]
+    pub ribbon_champion_g3: bool,
+    #[deku(bits = 1)]
+    pub ribbon_winning: bool,
+    #[deku(bits = 1)]
+    pub ribbon_victory: bool,
+    #[deku(bits = 1)]
+    pub ribbon_artist: bool,
+    #[deku(bits = 1)]
+    pub ribbon_effort: bool,
+    #[deku(bits = 1)]
+    pub ribbon_champion_battle: bool,
+    #[deku(bits = 1)]
+    pub ribbon_champion_regional: bool,
+    #[deku(bits = 1)]
+    pub ribbon_champion_national: bool,
+    #[deku(bits = 1)]
+    pub ribbon_country: bool,
+    #[deku(bits = 1)]
+    pub ribbon_national: bool,
+    #[deku(bits = 1)]
+    pub ribbon_earth: bool,
+    #[deku(bits = 1)]
+    pub ribbon_world: bool,
+    #[deku(bits = 4)]
+    pub unused_7d: u8,
+    pub unk_7e: u16,
+
+    // Moves
+    pub move1: Move,
+    pub move1_pp: u8,
+    pub move_1_pp_ups: u8,
+    pub move2: Move,
+    pub move2_pp: u8,
+    pub move_2_pp_ups: u8,
+    pub move3: Move,
+    pub move3_pp: u8,
+    pub move_3_pp_ups: u8,
+    pub move4: Move,
+    pub move4_pp: u8,
+    pub move_4_pp_ups: u8,
+
+    // Party Stats
+    pub stat_hp_max: u16,
+    pub stat_atk: u16,
+    pub stat_def: u16,
+    pub stat_spa: u16,
+    pub stat_spd: u16,
+    pub stat_spe: u16,
+
+    // EVs, IVs & Contest Stats
+    pub ev_hp: u16,
+    pub ev_atk: u16,
+    pub ev_def: u16,
+    pub ev_spa: u16,
+    pub ev_spd: u16,
+    pub ev_spe: u16,
+    pub iv_hp: u8,
+    pub iv_atk: u8,
+    pub iv_def: u8,
+    pub iv_spa: u8,
+    pub iv_spd: u8,
+    pub iv_spe: u8,
+    pub cnt_cool: u8,
+    pub cnt_beauty: u8,
+    pub cnt_cute: u8,
+    pub cnt_smart: u8,
+    pub cnt_tough: u8,
+    pub ribbon_count_g3_cool: u8,
+    pub ribbon_count_g3_beauty: u8,
+    pub ribbon_count_g3_cute: u8,
+    pub ribbon_count_g3_smart: u8,
+    pub ribbon_count_g3_tough: u8,
+
+    // Shadow Data
+    pub unk_b8: u16,
+    /// Index into the save file's shadow table; 0 if never a Shadow Pokémon.
+    pub shadow_id: u16,
+    pub unk_bc: [u8; 8],
+    /// Heart gauge, counting down to [`PURIFIED`].
+    ///
+    /// XD keeps this in the save file's shadow table rather than the entity,
+    /// so it defaults to 0 and must be populated by the caller.
+    #[deku(skip)]
+    pub purification: i32,
+    #[deku(skip, default = "Self::get_generation(*version, *met_location)")]
+    pub generation: i32,
+}
+
+impl XK3 {
+    /// Gets the nature, which Generation 3 derives from the PID.
+    pub fn nature(&self) -> Nature { Nature::n((self.pid % 25) as u8).unwrap() }
+
+    /// Checks if the entity is a Shadow Pokémon that has not been purified.
+    pub fn is_shadow(&self) -> bool { self.shadow_id != 0 && self.purification != PURIFIED }
+
+    /// Checks if the entity originated from Pokémon Colosseum or XD.
+    pub fn is_cxd_origin(&self) -> bool { self.version == GameVersion::CXD }
+}
+
+impl PKM for XK3 {
+    type RawVariant = RawXK3;
+
+    fn get_string(data: &[u16]) -> String { get_string3_gc(data) }
+
+    fn set_string<S: AsRef<str>>(&self, data: S, max_length: usize) -> Vec<u16> {
+        set_string3_gc(data.as_ref(), max_length)
+    }
+
+    fn build(&mut self) -> Result<RawXK3, PkmError> {
+        self.refresh_checksum();
+        Ok(RawXK3 { data: to_array(self.to_bytes()?)? })
+    }
+}
+
+impl From<&[u8; SIZE_3XSTORED]> for XK3 {
+    fn from(data: &[u8; SIZE_3XSTORED]) -> Self {
+        let (_rest, file) = XK3::from_bytes((data.as_ref(), 0)).unwrap();
+        file
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        game::enums::{gc_version::GCVersion, language_gc::LanguageGC}, pkm::util::testutil::write_string
+    };
+    use core::convert::TryFrom;
+    use deku::ctx::Endian;
+
+    fn teddiursa() -> [u8; SIZE_3XSTORED] {
+        let mut data = [0u8; SIZE_3XSTORED];
+        data[0x00..0x02].copy_from_slice(&216u16.to_be_bytes()); // Teddiursa
+        data[0x04..0x06].copy_from_slice(&40u16.to_be_bytes());
+        data[0x06..0x08].copy_from_slice(&70u16.to_be_bytes());
+        data[0x08..0x0A].copy_from_slice(&76u16.to_be_bytes());
+        data[0x0E] = 11;
+        data[0x0F] = Ball::Poke as u8;
+        data[0x11] = 11;
+        data[0x1D] = 0b0100_0001; // Ability bit, Fateful Encounter
+        data[0x24..0x26].copy_from_slice(&54321u16.to_be_bytes());
+        data[0x26..0x28].copy_from_slice(&12345u16.to_be_bytes());
+        data[0x28..0x2C].copy_from_slice(&0x0BAD_CAFEu32.to_be_bytes());
+        data[0x34] = GCVersion::CXD as u8;
+        data[0x37] = LanguageGC::Italian as u8;
+        write_string(&mut data, 0x38, set_string3_gc("Michael", 10), Endian::Big);
+        write_string(&mut data, 0x4E, set_string3_gc("Teddiursa", 10), Endian::Big);
+        write_string(&mut data, 0x64, set_string3_gc("Teddiursa", 10), Endian::Big);
+        data[0x7C] = 0b1000_0000;
+        data[0x7D] = 0b0001_0000;
+        data[0x80..0x82].copy_from_slice(&(Move::Scratch as u16).to_be_bytes());
+        data[0x82] = 35;
+        data[0x90..0x92].copy_from_slice(&40u16.to_be_bytes());
+        data[0x9C..0x9E].copy_from_slice(&252u16.to_be_bytes());
+        data[0xA8] = 31;
+        data[0xAD] = 30;
+        data[0xBA..0xBC].copy_from_slice(&1u16.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn xk3_get_test() {
+        let teddiursa = XK3::from(&teddiursa());
+        assert_eq!(Species::Teddiursa, teddiursa.species);
+        assert_eq!(40, teddiursa.stat_hp_current);
+        assert_eq!(70, teddiursa.ot_friendship);
+        assert_eq!(76, teddiursa.met_location);
+        assert_eq!(11, teddiursa.met_level);
+        assert!(!teddiursa.is_egg);
+        assert!(teddiursa.ability_bit);
+        assert!(teddiursa.fateful_encounter);
+        assert_eq!((54321, 12345), (teddiursa.sid, teddiursa.tid));
+        assert_eq!(0x0BAD_CAFE, teddiursa.pid);
+        assert_eq!(GameVersion::CXD, teddiursa.version);
+        assert_eq!(LanguageID::Italian, teddiursa.language);
+        assert_eq!("Michael", teddiursa.ot_name);
+        assert_eq!("Teddiursa", teddiursa.nickname);
+        assert!(teddiursa.ribbon_champion_g3);
+        assert!(teddiursa.ribbon_world);
+        assert!(!teddiursa.ribbon_earth);
+        assert_eq!(Move::Scratch, teddiursa.move1);
+        assert_eq!(35, teddiursa.move1_pp);
+        assert_eq!(40, teddiursa.stat_hp_max);
+        assert_eq!(252, teddiursa.ev_hp);
+        assert_eq!((31, 30), (teddiursa.iv_hp, teddiursa.iv_spe));
+        assert_eq!(1, teddiursa.shadow_id);
+        assert!(teddiursa.is_shadow());
+        assert_eq!(3, teddiursa.generation);
+    }
+
+    #[test]
+    fn xk3_round_trip_test() {
+        let data = teddiursa();
+        let mut teddiursa = XK3::from(&data);
+        assert_eq!(data, teddiursa.build().unwrap().to_bytes());
+
+        teddiursa.purification = PURIFIED;
+        assert!(!teddiursa.is_shadow());
+        teddiursa.species = Species::Ursaring;
+        teddiursa.version = GameVersion::FR;
+        let rebuilt = teddiursa.build().unwrap().to_bytes();
+        assert_eq!(217, u16::from_be_bytes([rebuilt[0], rebuilt[1]]));
+        assert_eq!(GCVersion::FR as u8, rebuilt[0x34]);
+        assert_eq!(data[0x38..], rebuilt[0x38..]);
+    }
+
+    #[test]
+    fn xk3_unknown_origin_test() {
+        let mut data = teddiursa();
+        data[0x34] = 0x0C;
+        assert!(matches!(XK3::try_from(&data[..]), Err(DekuError::Parse(_))));
+
+        let mut data = teddiursa();
+        data[0x37] = 0x08;
+        assert!(matches!(XK3::try_from(&data[..]), Err(DekuError::Parse(_))));
+    }
+}
@@ -12,3 +12,6 @@ pub mod custom_read_write;
 
 /// Big Endian operation related helpers.
 pub mod bigendian;
+
+/// GARC archive reader.
+pub mod garc;
@@ -22,6 +22,18 @@ pub(crate) fn int32_to_bcd<const N: usize>(mut input: i32) -> [u8; N] {
     result
 }
 
+/// Returns a 16-bit unsigned integer converted from two bytes at a specified
+/// position in a big endian byte array.
+pub(crate) fn to_uint16(input: &[u8], offset: usize) -> u16 {
+    u16::from_be_bytes([input[offset], input[offset + 1]])
+}
+
+/// Returns a 32-bit unsigned integer converted from four bytes at a specified
+/// position in a big endian byte array.
+pub(crate) fn to_uint32(input: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([input[offset], input[offset + 1], input[offset + 2], input[offset + 3]])
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -32,6 +44,14 @@ mod test {
         assert_eq!(2010, bcd_to_int32(&buffer, 0, 2));
     }
 
+    #[test]
+    fn to_uint_test() {
+        let buffer = [0x12, 0x34, 0x56, 0x78];
+        assert_eq!(0x1234, to_uint16(&buffer, 0));
+        assert_eq!(0x5678, to_uint16(&buffer, 2));
+        assert_eq!(0x12345678, to_uint32(&buffer, 0));
+    }
+
     #[test]
     fn int32_to_bcd_test() {
         assert_eq!([32, 16], int32_to_bcd::<2>(2010));
@@ -1,7 +1,10 @@
-use crate::game::enums::game_version::GameVersion;
-use alloc::{string::String, vec::Vec};
+use crate::game::enums::{
+    ability::Ability, game_version::GameVersion, gc_version::{get_cxd_version_id, get_g3_version_id, GCVersion}, language_gc::{get_gc_lang_id, get_main_lang_id, LanguageGC}, language_id::LanguageID, moves::Move
+};
+use alloc::{format, string::String, vec::Vec};
+use core::convert::TryFrom;
 use deku::{
-    bitvec::{BitSlice, BitVec, Msb0}, ctx::Limit, prelude::*
+    bitvec::{BitSlice, BitVec, Msb0}, ctx::{Endian, Limit}, prelude::*
 };
 
 pub(crate) mod read {
@@ -18,11 +21,69 @@ pub(crate) mod read {
         Ok((rest, f(&value)))
     }
 
+    pub(crate) fn read_string_custom_endian(
+        rest: &BitSlice<Msb0, u8>,
+        endian: Endian,
+        byte_count: usize,
+        f: fn(&[u16]) -> String,
+    ) -> Result<(&BitSlice<Msb0, u8>, String), DekuError> {
+        let (rest, value) = Vec::<u16>::read(rest, (Limit::new_count(byte_count), endian))?;
+        Ok((rest, f(&value)))
+    }
+
+    pub(crate) fn read_string_u8<F: Fn(&[u8]) -> String>(
+        rest: &BitSlice<Msb0, u8>,
+        byte_count: usize,
+        f: F,
+    ) -> Result<(&BitSlice<Msb0, u8>, String), DekuError> {
+        let (rest, value) = Vec::<u8>::read(rest, Limit::new_count(byte_count))?;
+        Ok((rest, f(&value)))
+    }
+
     pub(crate) fn read_game_version(
         rest: &BitSlice<Msb0, u8>,
     ) -> Result<(&BitSlice<Msb0, u8>, GameVersion), DekuError> {
         let (rest, value) = u8::read(rest, ())?;
-        Ok((rest, GameVersion::n(value as i32).unwrap()))
+        let version = GameVersion::n(value as i32).ok_or_else(|| {
+            DekuError::Parse(format!("cannot parse GameVersion value: {}", value))
+        })?;
+        Ok((rest, version))
+    }
+
+    pub(crate) fn read_gc_version(
+        rest: &BitSlice<Msb0, u8>,
+    ) -> Result<(&BitSlice<Msb0, u8>, GameVersion), DekuError> {
+        let (rest, value) = u8::read(rest, ())?;
+        let version = GCVersion::n(value)
+            .ok_or_else(|| DekuError::Parse(format!("cannot parse GCVersion value: {}", value)))?;
+        Ok((rest, get_g3_version_id(version)))
+    }
+
+    pub(crate) fn read_language_gc(
+        rest: &BitSlice<Msb0, u8>,
+    ) -> Result<(&BitSlice<Msb0, u8>, LanguageID), DekuError> {
+        let (rest, value) = u8::read(rest, ())?;
+        let language = LanguageGC::n(value)
+            .ok_or_else(|| DekuError::Parse(format!("cannot parse LanguageGC value: {}", value)))?;
+        Ok((rest, get_main_lang_id(language)))
+    }
+
+    pub(crate) fn read_move_u8(
+        rest: &BitSlice<Msb0, u8>,
+    ) -> Result<(&BitSlice<Msb0, u8>, Move), DekuError> {
+        let (rest, value) = u8::read(rest, ())?;
+        let move_id = Move::n(value as u16)
+            .ok_or_else(|| DekuError::Parse(format!("cannot parse Move value: {}", value)))?;
+        Ok((rest, move_id))
+    }
+
+    pub(crate) fn read_ability_u8(
+        rest: &BitSlice<Msb0, u8>,
+    ) -> Result<(&BitSlice<Msb0, u8>, Ability), DekuError> {
+        let (rest, value) = u8::read(rest, ())?;
+        let ability = Ability::n(value as u16)
+            .ok_or_else(|| DekuError::Parse(format!("cannot parse Ability value: {}", value)))?;
+        Ok((rest, ability))
     }
 }
 
@@ -36,6 +97,36 @@ pub(crate) mod write {
         field.write(output, ())
     }
 
+    /// Writes a string over the buffer it was read from, keeping the trash
+    /// that follows its terminator.
+    pub(crate) fn write_string_trash(
+        output: &mut BitVec<Msb0, u8>,
+        mut field: Vec<u16>,
+        trash: &[u16],
+    ) -> Result<(), DekuError> {
+        if let Some(end) = field.iter().position(|&c| c == 0) {
+            for (c, &t) in field.iter_mut().zip(trash).skip(end + 1) {
+                *c = t;
+            }
+        }
+        field.write(output, ())
+    }
+
+    pub(crate) fn write_string_custom_endian(
+        output: &mut BitVec<Msb0, u8>,
+        endian: Endian,
+        field: Vec<u16>,
+    ) -> Result<(), DekuError> {
+        field.write(output, endian)
+    }
+
+    pub(crate) fn write_string_u8(
+        output: &mut BitVec<Msb0, u8>,
+        field: Vec<u8>,
+    ) -> Result<(), DekuError> {
+        field.write(output, ())
+    }
+
     pub(crate) fn write_game_version(
         output: &mut BitVec<Msb0, u8>,
         field: GameVersion,
@@ -43,4 +134,41 @@ pub(crate) mod write {
         let value = field as u8;
         value.write(output, ())
     }
+
+    pub(crate) fn write_gc_version(
+        output: &mut BitVec<Msb0, u8>,
+        field: GameVersion,
+    ) -> Result<(), DekuError> {
+        (get_cxd_version_id(field) as u8).write(output, ())
+    }
+
+    pub(crate) fn write_language_gc(
+        output: &mut BitVec<Msb0, u8>,
+        field: LanguageID,
+    ) -> Result<(), DekuError> {
+        (get_gc_lang_id(field) as u8).write(output, ())
+    }
+
+    pub(crate) fn write_move_u8(
+        output: &mut BitVec<Msb0, u8>,
+        field: Move,
+    ) -> Result<(), DekuError> {
+        let value = u8::try_from(field as u16).map_err(|_| {
+            DekuError::InvalidParam(format!("Move value does not fit in a byte: {}", field as u16))
+        })?;
+        value.write(output, ())
+    }
+
+    pub(crate) fn write_ability_u8(
+        output: &mut BitVec<Msb0, u8>,
+        field: Ability,
+    ) -> Result<(), DekuError> {
+        let value = u8::try_from(field as u16).map_err(|_| {
+            DekuError::InvalidParam(format!(
+                "Ability value does not fit in a byte: {}",
+                field as u16
+            ))
+        })?;
+        value.write(output, ())
+    }
 }
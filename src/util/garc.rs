@@ -0,0 +1,171 @@
+use crate::error::PkmError;
+use alloc::{vec, vec::Vec};
+use core::convert::TryInto;
+
+const MAGIC_GARC: &[u8; 4] = b"CRAG";
+const MAGIC_FATO: &[u8; 4] = b"OTAF";
+const MAGIC_FATB: &[u8; 4] = b"BTAF";
+
+/// Read-only view of a GARC archive, the container the Gen 6 and Gen 7 games
+/// pack their data files in.
+///
+/// Only the first subfile of each entry is exposed, which is the only one
+/// present in the archives holding personal, learnset and evolution data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Garc<'a> {
+    data: &'a [u8],
+    files: Vec<(usize, usize)>,
+}
+
+impl<'a> Garc<'a> {
+    /// Reads the file table of an archive.
+    pub fn new(data: &'a [u8]) -> Result<Self, PkmError> {
+        check_magic(data, 0, MAGIC_GARC)?;
+        let header_size = read_u32(data, 0x04)? as usize;
+        let data_offset = read_u32(data, 0x10)? as usize;
+
+        let fato = header_size;
+        check_magic(data, fato, MAGIC_FATO)?;
+        let fato_size = read_u32(data, fato + 0x04)? as usize;
+        let count = read_u16(data, fato + 0x08)? as usize;
+
+        let fatb = fato + fato_size;
+        check_magic(data, fatb, MAGIC_FATB)?;
+        let fatb_entries = fatb + 0x0C;
+
+        let mut files = Vec::with_capacity(count);
+        for i in 0..count {
+            let entry = fatb_entries + read_u32(data, fato + 0x0C + i * 4)? as usize;
+            let vector = read_u32(data, entry)?;
+            if vector == 0 {
+                return Err(PkmError::InvalidField { field: "vector", offset: entry, value: 0 });
+            }
+            let start = data_offset + read_u32(data, entry + 0x04)? as usize;
+            let length = read_u32(data, entry + 0x0C)? as usize;
+            if start + length > data.len() {
+                return Err(PkmError::InvalidField {
+                    field: "file length",
+                    offset: entry + 0x0C,
+                    value: length as u32,
+                });
+            }
+            files.push((start, length));
+        }
+        Ok(Garc { data, files })
+    }
+
+    /// Number of files in the archive.
+    pub fn len(&self) -> usize { self.files.len() }
+    pub fn is_empty(&self) -> bool { self.files.is_empty() }
+
+    /// Gets the contents of a file.
+    pub fn file(&self, index: usize) -> Option<&'a [u8]> {
+        let &(start, length) = self.files.get(index)?;
+        Some(&self.data[start..start + length])
+    }
+
+    /// Iterates over the contents of every file, in order.
+    pub fn files(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+        self.files.iter().map(move |&(start, length)| &self.data[start..start + length])
+    }
+}
+
+fn check_magic(data: &[u8], offset: usize, magic: &[u8; 4]) -> Result<(), PkmError> {
+    let value = read_u32(data, offset)?;
+    if value.to_le_bytes() != *magic {
+        return Err(PkmError::InvalidField { field: "magic", offset, value });
+    }
+    Ok(())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, PkmError> {
+    match data.get(offset..offset + 2) {
+        Some(bytes) => Ok(u16::from_le_bytes(bytes.try_into().unwrap())),
+        None => Err(PkmError::InvalidLength { expected: vec![offset + 2], actual: data.len() }),
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, PkmError> {
+    match data.get(offset..offset + 4) {
+        Some(bytes) => Ok(u32::from_le_bytes(bytes.try_into().unwrap())),
+        None => Err(PkmError::InvalidLength { expected: vec![offset + 4], actual: data.len() }),
+    }
+}
+
+/// Packs files into a version 4 GARC archive.
+#[cfg(test)]
+pub(crate) fn pack(files: &[&[u8]]) -> Vec<u8> {
+    let count = files.len();
+    let fato_size = 0x0C + count * 4;
+    let fatb_size = 0x0C + count * 0x10;
+    let data_offset = 0x1C + fato_size + fatb_size + 0x0C;
+
+    let mut fato = Vec::new();
+    let mut fatb = Vec::new();
+    let mut fimb = Vec::new();
+    for (i, file) in files.iter().enumerate() {
+        let start = fimb.len() as u32;
+        fimb.extend_from_slice(file);
+        let end = fimb.len() as u32;
+        fimb.resize((fimb.len() + 3) & !3, 0xFF);
+        fato.extend_from_slice(&(i as u32 * 0x10).to_le_bytes());
+        for value in [1, start, end, end - start] {
+            fatb.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    let largest = files.iter().map(|file| file.len()).max().unwrap_or(0);
+    let mut data = Vec::new();
+    data.extend_from_slice(MAGIC_GARC);
+    for value in [0x1C, 0x0400_FEFF, 4, data_offset, data_offset + fimb.len(), largest] {
+        data.extend_from_slice(&(value as u32).to_le_bytes());
+    }
+    data.extend_from_slice(MAGIC_FATO);
+    data.extend_from_slice(&(fato_size as u32).to_le_bytes());
+    data.extend_from_slice(&(count as u16).to_le_bytes());
+    data.extend_from_slice(&0xFFFFu16.to_le_bytes());
+    data.extend_from_slice(&fato);
+    data.extend_from_slice(MAGIC_FATB);
+    data.extend_from_slice(&(fatb_size as u32).to_le_bytes());
+    data.extend_from_slice(&(count as u32).to_le_bytes());
+    data.extend_from_slice(&fatb);
+    data.extend_from_slice(b"BMIF");
+    data.extend_from_slice(&0x0Cu32.to_le_bytes());
+    data.extend_from_slice(&(fimb.len() as u32).to_le_bytes());
+    data.extend_from_slice(&fimb);
+    data
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn garc_test() {
+        let files: [&[u8]; 3] = [&[1, 2, 3], &[], &[4, 5, 6, 7, 8]];
+        let data = pack(&files);
+        let garc = Garc::new(&data).unwrap();
+        assert_eq!(3, garc.len());
+        assert_eq!(Some(&[1, 2, 3][..]), garc.file(0));
+        assert_eq!(Some(&[][..]), garc.file(1));
+        assert_eq!(Some(&[4, 5, 6, 7, 8][..]), garc.file(2));
+        assert_eq!(None, garc.file(3));
+        assert_eq!(files.to_vec(), garc.files().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn garc_error_test() {
+        let mut data = pack(&[&[1, 2, 3]]);
+        assert_eq!(
+            Err(PkmError::InvalidLength { expected: vec![0x24], actual: 0x20 }),
+            Garc::new(&data[..0x20])
+        );
+        data.truncate(data.len() - 4);
+        assert!(matches!(
+            Garc::new(&data),
+            Err(PkmError::InvalidField { field: "file length", .. })
+        ));
+        data[0] = b'X';
+        assert!(matches!(Garc::new(&data), Err(PkmError::InvalidField { field: "magic", .. })));
+    }
+}